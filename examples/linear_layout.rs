@@ -8,7 +8,7 @@ use embedded_graphics::{
     prelude::*,
     text::Text,
 };
-use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+use embedded_layout::prelude::*;
 
 fn main() -> Result<(), core::convert::Infallible> {
     let mut display: SimulatorDisplay<BinaryColor> = SimulatorDisplay::new(Size::new(64, 48));
@@ -4,11 +4,15 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{format_ident, quote};
 use syn::{
-    self, parse_macro_input, Data, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, GenericParam,
-    LitInt, TypeParamBound,
+    self, parse_macro_input, Data, DeriveInput, Field, Fields, FieldsNamed, FieldsUnnamed,
+    GenericParam, LitInt, TypeParamBound,
 };
 
-#[proc_macro_derive(ViewGroup)]
+/// Implements `ViewGroup` for structs with named fields and enums.
+///
+/// A single field of a struct can be marked with `#[view_group(reference)]`. For such structs, an
+/// `align_all_to_reference` method is generated that aligns every other field to the marked one.
+#[proc_macro_derive(ViewGroup, attributes(view_group))]
 pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
@@ -16,6 +20,8 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
     let empty_vg_instance_mut =
         quote!(unsafe { &mut embedded_layout::view_group::EMPTY_VIEW_GROUP });
 
+    let mut reference_field = None;
+
     let (
         field_count_impl,
         index_impl,
@@ -38,6 +44,13 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
                 .map(|f| f.ident.clone().unwrap())
                 .collect::<Vec<_>>();
 
+            for field in fields.named.iter().filter(|f| is_reference_field(f)) {
+                if reference_field.is_some() {
+                    panic!("Only one field can be marked with #[view_group(reference)]");
+                }
+                reference_field = field.ident.clone();
+            }
+
             let field_count = format!("{}", field_names.len());
             let field_count = LitInt::new(&field_count, Span::call_site());
 
@@ -139,6 +152,10 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
             enum_data.variants.iter().for_each(|variant| {
                 let variant_name = &variant.ident;
 
+                if variant.fields.iter().any(is_reference_field) {
+                    panic!("#[view_group(reference)] is only supported on struct fields");
+                }
+
                 let (
                     enum_field_count,
                     enum_translate,
@@ -521,14 +538,69 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
         quote!()
     };
 
+    let gen_reference_impl = if let Some(reference) = reference_field {
+        let aligned_fields = match &ast.data {
+            Data::Struct(struct_data) => struct_data
+                .fields
+                .iter()
+                .filter_map(|f| f.ident.as_ref())
+                .filter(|f| **f != reference)
+                .map(|f| quote!(self.#f.align_to_mut(&reference, horizontal, vertical);))
+                .collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Aligns every field to the field marked with `#[view_group(reference)]`.
+                #[inline]
+                pub fn align_all_to_reference<H, V>(&mut self, horizontal: H, vertical: V)
+                where
+                    H: embedded_layout::align::HorizontalAlignment,
+                    V: embedded_layout::align::VerticalAlignment,
+                {
+                    use embedded_layout::align::Align;
+
+                    let reference = embedded_layout::View::bounds(&self.#reference);
+                    #(#aligned_fields)*
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
     let generated = quote! {
         #gen_view_group
         #gen_drawable_impl
+        #gen_reference_impl
     };
 
     TokenStream::from(generated)
 }
 
+fn is_reference_field(field: &Field) -> bool {
+    let mut is_reference = false;
+
+    for attr in field.attrs.iter() {
+        if !attr.path().is_ident("view_group") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("reference") {
+                is_reference = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported view_group attribute"))
+            }
+        })
+        .unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    is_reference
+}
+
 fn is_type_param(ident: &'static str) -> impl Fn(&TypeParamBound) -> bool {
     move |f: &TypeParamBound| -> bool {
         if let TypeParamBound::Trait(tpb) = f {
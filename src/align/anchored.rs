@@ -0,0 +1,88 @@
+use crate::{
+    align::{Align, HorizontalAlignment, VerticalAlignment},
+    View,
+};
+
+/// A reference view to align any number of views to
+///
+/// `Anchored` keeps a shared reference to the anchor, so the anchor is never moved by the
+/// alignment operations.
+///
+/// # Example
+///
+/// ```
+/// use embedded_layout::{align::Anchored, prelude::*};
+/// use embedded_graphics::{prelude::*, primitives::Rectangle};
+///
+/// let screen = Rectangle::new(Point::zero(), Size::new(64, 32));
+/// let anchor = Anchored::from(&screen);
+///
+/// let title = anchor.place(
+///     Rectangle::new(Point::zero(), Size::new(20, 8)),
+///     horizontal::Center,
+///     vertical::Top,
+/// );
+/// let footer = anchor.place(
+///     Rectangle::new(Point::zero(), Size::new(64, 8)),
+///     horizontal::Left,
+///     vertical::Bottom,
+/// );
+///
+/// assert_eq!(Point::new(22, 0), title.top_left);
+/// assert_eq!(Point::new(0, 24), footer.top_left);
+/// ```
+pub struct Anchored<'a, R: ?Sized> {
+    reference: &'a R,
+}
+
+impl<'a, R> Anchored<'a, R>
+where
+    R: View + ?Sized,
+{
+    /// Returns the anchor.
+    #[inline]
+    pub fn reference(&self) -> &'a R {
+        self.reference
+    }
+
+    /// Return `view` aligned to the anchor using the alignment parameters as rules
+    #[inline]
+    pub fn place<T, H, V>(&self, view: T, horizontal: H, vertical: V) -> T
+    where
+        T: View,
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        view.align_to(&self.reference.bounds(), horizontal, vertical)
+    }
+
+    /// Align `view` to the anchor using the alignment parameters as rules
+    #[inline]
+    pub fn place_mut<'v, T, H, V>(&self, view: &'v mut T, horizontal: H, vertical: V) -> &'v mut T
+    where
+        T: View,
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        view.align_to_mut(&self.reference.bounds(), horizontal, vertical)
+    }
+}
+
+impl<'a, R> From<&'a R> for Anchored<'a, R>
+where
+    R: View + ?Sized,
+{
+    #[inline]
+    fn from(reference: &'a R) -> Self {
+        Self { reference }
+    }
+}
+
+impl<R: ?Sized> Clone for Anchored<'_, R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: ?Sized> Copy for Anchored<'_, R> {}
@@ -0,0 +1,173 @@
+use embedded_graphics::{geometry::AnchorPoint, prelude::Point, primitives::Rectangle};
+
+/// A pair of anchor points to align two views by, e.g. "center right to center left"
+///
+/// Aligning a view using [`Align::align_anchor`] moves the view so that its `self_anchor`
+/// is on the `ref_anchor` of the reference, moved by `offset`. Unlike the [`horizontal`] and
+/// [`vertical`] alignments, this positions the view along both axes with a single value.
+///
+/// Anchor points are pixels of the bounding boxes, so two views with coinciding anchors overlap
+/// by one pixel on the edge. The presets, e.g. [`DockAnchors::ABOVE_LEFT`], place the view next
+/// to the reference instead, without any overlap.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{align::DockAnchors, prelude::*};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let button = Rectangle::new(Point::new(20, 20), Size::new(30, 10));
+///
+/// let tooltip = Rectangle::new(Point::zero(), Size::new(40, 8))
+///     .align_anchor(&button, DockAnchors::ABOVE_LEFT);
+///
+/// assert_eq!(Point::new(20, 12), tooltip.top_left);
+/// ```
+///
+/// [`Align::align_anchor`]: crate::align::Align::align_anchor
+/// [`horizontal`]: crate::align::horizontal
+/// [`vertical`]: crate::align::vertical
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DockAnchors {
+    /// The point of the aligned view
+    pub self_anchor: AnchorPoint,
+
+    /// The point of the reference view
+    pub ref_anchor: AnchorPoint,
+
+    /// Additional translation applied after the anchors are matched
+    pub offset: Point,
+}
+
+impl DockAnchors {
+    /// Above the reference, left edges aligned
+    pub const ABOVE_LEFT: Self =
+        Self::new(AnchorPoint::BottomLeft, AnchorPoint::TopLeft).with_offset(Point::new(0, -1));
+
+    /// Above the reference, centered horizontally
+    pub const ABOVE: Self =
+        Self::new(AnchorPoint::BottomCenter, AnchorPoint::TopCenter).with_offset(Point::new(0, -1));
+
+    /// Above the reference, right edges aligned
+    pub const ABOVE_RIGHT: Self =
+        Self::new(AnchorPoint::BottomRight, AnchorPoint::TopRight).with_offset(Point::new(0, -1));
+
+    /// Below the reference, left edges aligned
+    pub const BELOW_LEFT: Self =
+        Self::new(AnchorPoint::TopLeft, AnchorPoint::BottomLeft).with_offset(Point::new(0, 1));
+
+    /// Below the reference, centered horizontally
+    pub const BELOW: Self =
+        Self::new(AnchorPoint::TopCenter, AnchorPoint::BottomCenter).with_offset(Point::new(0, 1));
+
+    /// Below the reference, right edges aligned
+    pub const BELOW_RIGHT: Self =
+        Self::new(AnchorPoint::TopRight, AnchorPoint::BottomRight).with_offset(Point::new(0, 1));
+
+    /// Left of the reference, top edges aligned
+    pub const LEFT_TOP: Self =
+        Self::new(AnchorPoint::TopRight, AnchorPoint::TopLeft).with_offset(Point::new(-1, 0));
+
+    /// Left of the reference, centered vertically
+    pub const LEFT: Self =
+        Self::new(AnchorPoint::CenterRight, AnchorPoint::CenterLeft).with_offset(Point::new(-1, 0));
+
+    /// Left of the reference, bottom edges aligned
+    pub const LEFT_BOTTOM: Self =
+        Self::new(AnchorPoint::BottomRight, AnchorPoint::BottomLeft).with_offset(Point::new(-1, 0));
+
+    /// Right of the reference, top edges aligned
+    pub const RIGHT_TOP: Self =
+        Self::new(AnchorPoint::TopLeft, AnchorPoint::TopRight).with_offset(Point::new(1, 0));
+
+    /// Right of the reference, centered vertically
+    pub const RIGHT: Self =
+        Self::new(AnchorPoint::CenterLeft, AnchorPoint::CenterRight).with_offset(Point::new(1, 0));
+
+    /// Right of the reference, bottom edges aligned
+    pub const RIGHT_BOTTOM: Self =
+        Self::new(AnchorPoint::BottomLeft, AnchorPoint::BottomRight).with_offset(Point::new(1, 0));
+
+    /// Create a new anchor pair, placing `self_anchor` exactly on `ref_anchor`.
+    #[inline]
+    pub const fn new(self_anchor: AnchorPoint, ref_anchor: AnchorPoint) -> Self {
+        Self {
+            self_anchor,
+            ref_anchor,
+            offset: Point::zero(),
+        }
+    }
+
+    /// Returns a copy of the anchor pair with the given additional translation.
+    #[inline]
+    pub const fn with_offset(self, offset: Point) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Returns the translation that moves `view` to its position relative to `reference`.
+    #[inline]
+    pub fn translation(&self, view: Rectangle, reference: Rectangle) -> Point {
+        reference.anchor_point(self.ref_anchor) - view.anchor_point(self.self_anchor) + self.offset
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    fn reference() -> Rectangle {
+        Rectangle::new(Point::new(10, -5), Size::new(21, 10))
+    }
+
+    fn view() -> Rectangle {
+        Rectangle::new(Point::new(-40, 7), Size::new(6, 5))
+    }
+
+    #[test]
+    fn anchors_coincide() {
+        for (self_anchor, ref_anchor) in [
+            (AnchorPoint::CenterRight, AnchorPoint::CenterLeft),
+            (AnchorPoint::BottomLeft, AnchorPoint::TopRight),
+            (AnchorPoint::Center, AnchorPoint::BottomCenter),
+        ] {
+            let anchors = DockAnchors::new(self_anchor, ref_anchor);
+            let aligned = view().align_anchor(&reference(), anchors);
+
+            assert_eq!(
+                reference().anchor_point(ref_anchor),
+                aligned.anchor_point(self_anchor)
+            );
+            assert_eq!(view().size, aligned.size);
+        }
+    }
+
+    #[test]
+    fn offset_is_added() {
+        let anchors = DockAnchors::new(AnchorPoint::TopLeft, AnchorPoint::BottomRight)
+            .with_offset(Point::new(2, -3));
+
+        assert_eq!(
+            Point::new(32, 1),
+            view().align_anchor(&reference(), anchors).top_left
+        );
+    }
+
+    #[test]
+    fn presets_touch_without_overlap() {
+        let reference = reference();
+        let above = view().align_anchor(&reference, DockAnchors::ABOVE_LEFT);
+        let right = view().align_anchor(&reference, DockAnchors::RIGHT_BOTTOM);
+        let below = view().align_anchor(&reference, DockAnchors::BELOW);
+        let left = view().align_anchor(&reference, DockAnchors::LEFT);
+
+        assert_eq!(Point::new(10, -10), above.top_left);
+        assert_eq!(Point::new(31, 0), right.top_left);
+        assert_eq!(Point::new(18, 5), below.top_left);
+        assert_eq!(Point::new(4, -3), left.top_left);
+
+        for view in [above, right, below, left] {
+            assert!(view.intersection(&reference).is_zero_sized());
+        }
+    }
+}
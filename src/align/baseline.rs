@@ -0,0 +1,183 @@
+use embedded_graphics::{
+    mono_font::MonoTextStyle,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    text::{Baseline, Text},
+};
+
+use crate::{utils::rounding::half, View};
+
+/// A view that has a text baseline.
+///
+/// The baseline is the row of pixels that the letters of a text sit on. Views that don't contain
+/// text can implement this trait without overriding [`baseline`], which then returns the bottom
+/// row of the view.
+///
+/// [`baseline`]: HasBaseline::baseline
+pub trait HasBaseline: View {
+    /// Returns the Y coordinate of the baseline.
+    #[inline]
+    fn baseline(&self) -> i32 {
+        bottom_row(self.bounds())
+    }
+}
+
+impl<C> HasBaseline for Text<'_, MonoTextStyle<'_, C>>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn baseline(&self) -> i32 {
+        let font = self.character_style.font;
+        let glyph_height = font.character_size.height.saturating_sub(1) as i32;
+
+        let top = self.position.y
+            - match self.text_style.baseline {
+                Baseline::Top => 0,
+                Baseline::Bottom => glyph_height,
+                Baseline::Middle => half(glyph_height),
+                Baseline::Alphabetic => font.baseline as i32,
+            };
+
+        top + font.baseline as i32
+    }
+}
+
+/// Move views down so that their baselines land on a grid.
+///
+/// The grid lines are `pitch` pixels apart, and one of them is at `origin_y`. Each view is moved to
+/// the nearest grid line at or below its current baseline, its X coordinate is not changed. Use
+/// this to line up texts that use different fonts, e.g. labels and values of a settings screen.
+///
+/// A `pitch` of 0 leaves the views unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{align::{snap_to_baseline_grid, HasBaseline}, prelude::*};
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::{FONT_6X9, FONT_9X15}, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// let label_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let value_style = MonoTextStyle::new(&FONT_9X15, BinaryColor::On);
+///
+/// let mut texts = [
+///     Text::new("Volume", Point::new(0, 5), label_style),
+///     Text::new("11", Point::new(40, 8), value_style),
+/// ];
+///
+/// snap_to_baseline_grid(&mut texts, 12, 0);
+///
+/// assert_eq!(12, texts[0].baseline());
+/// assert_eq!(12, texts[1].baseline());
+/// ```
+#[inline]
+pub fn snap_to_baseline_grid<V>(views: &mut [V], pitch: u32, origin_y: i32)
+where
+    V: HasBaseline,
+{
+    for view in views {
+        let offset = grid_offset(view.baseline(), pitch, origin_y);
+        view.translate_mut(Point::new(0, offset));
+    }
+}
+
+/// Returns the distance from `y` to the nearest grid line at or below it.
+pub(crate) fn grid_offset(y: i32, pitch: u32, origin_y: i32) -> i32 {
+    if pitch == 0 {
+        return 0;
+    }
+
+    let pitch = pitch as i32;
+    (pitch - (y - origin_y).rem_euclid(pitch)) % pitch
+}
+
+/// Returns the Y coordinate of the bottom row of `bounds`.
+pub(crate) fn bottom_row(bounds: Rectangle) -> i32 {
+    bounds.top_left.y + bounds.size.height as i32 - 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mono_font::ascii::{FONT_6X9, FONT_9X15},
+        pixelcolor::BinaryColor,
+        prelude::Size,
+    };
+
+    impl HasBaseline for Rectangle {}
+
+    #[test]
+    fn mono_text_baseline_is_independent_of_text_baseline() {
+        let style = MonoTextStyle::new(&FONT_9X15, BinaryColor::On);
+        let alphabetic = Text::new("Ag", Point::new(0, 20), style);
+
+        for baseline in [Baseline::Top, Baseline::Middle, Baseline::Bottom] {
+            let mut text = Text::with_baseline("Ag", Point::zero(), style, baseline);
+            text.translate_mut(alphabetic.bounds().top_left - text.bounds().top_left);
+
+            assert_eq!(20, text.baseline());
+        }
+    }
+
+    #[test]
+    fn snap_mixed_fonts_to_grid() {
+        let small = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let large = MonoTextStyle::new(&FONT_9X15, BinaryColor::On);
+
+        let mut texts = [
+            Text::new("Label", Point::new(0, 7), small),
+            Text::new("Value", Point::new(30, 14), large),
+            Text::with_baseline("Top", Point::new(60, 25), large, Baseline::Top),
+            Text::new("Exact", Point::new(90, 27), small),
+        ];
+
+        snap_to_baseline_grid(&mut texts, 12, 3);
+
+        assert_eq!(
+            [15, 15, 39, 27],
+            [
+                texts[0].baseline(),
+                texts[1].baseline(),
+                texts[2].baseline(),
+                texts[3].baseline()
+            ]
+        );
+        assert_eq!(
+            [0, 30, 60, 90],
+            [
+                texts[0].position.x,
+                texts[1].position.x,
+                texts[2].position.x,
+                texts[3].position.x
+            ]
+        );
+    }
+
+    #[test]
+    fn bottom_edge_fallback() {
+        let mut views = [
+            Rectangle::new(Point::new(0, -10), Size::new(5, 5)),
+            Rectangle::new(Point::new(0, 4), Size::new(5, 1)),
+        ];
+
+        snap_to_baseline_grid(&mut views, 8, 0);
+
+        assert_eq!(0, views[0].baseline());
+        assert_eq!(8, views[1].baseline());
+    }
+
+    #[test]
+    fn zero_pitch_is_ignored() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let mut texts = [Text::new("Label", Point::new(0, 7), style)];
+
+        snap_to_baseline_grid(&mut texts, 0, 0);
+
+        assert_eq!(Point::new(0, 7), texts[0].position);
+    }
+}
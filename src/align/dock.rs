@@ -0,0 +1,188 @@
+use embedded_graphics::{prelude::Point, primitives::Rectangle};
+
+use crate::{
+    align::{horizontal, vertical, Alignment},
+    utils::rotation::Rotation,
+};
+
+/// An edge of the screen (or of any other reference view).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScreenEdge {
+    /// The top edge
+    Top,
+
+    /// The bottom edge
+    Bottom,
+
+    /// The left edge
+    Left,
+
+    /// The right edge
+    Right,
+}
+
+impl ScreenEdge {
+    /// Returns the physical edge that appears as `self` on a display rotated by `rotation`.
+    #[inline]
+    pub fn rotate(self, rotation: Option<Rotation>) -> Self {
+        match rotation {
+            Some(rotation) => Self::from_direction(rotation.rotate_vector(self.direction())),
+            None => self,
+        }
+    }
+
+    /// Unit vector pointing towards the edge.
+    pub(crate) const fn direction(self) -> Point {
+        match self {
+            ScreenEdge::Top => Point::new(0, -1),
+            ScreenEdge::Bottom => Point::new(0, 1),
+            ScreenEdge::Left => Point::new(-1, 0),
+            ScreenEdge::Right => Point::new(1, 0),
+        }
+    }
+
+    /// Unit vector pointing along the edge, from its start to its end.
+    const fn cross_direction(self) -> Point {
+        match self {
+            ScreenEdge::Top | ScreenEdge::Bottom => Point::new(1, 0),
+            ScreenEdge::Left | ScreenEdge::Right => Point::new(0, 1),
+        }
+    }
+
+    const fn from_direction(direction: Point) -> Self {
+        match (direction.x, direction.y) {
+            (0, y) if y < 0 => ScreenEdge::Top,
+            (0, _) => ScreenEdge::Bottom,
+            (x, _) if x < 0 => ScreenEdge::Left,
+            _ => ScreenEdge::Right,
+        }
+    }
+}
+
+/// Returns the edge of `area` that is closest to `point`.
+///
+/// Distances are measured to the edges as line segments, so points outside of `area` are assigned
+/// to the edge they are facing.
+pub(crate) fn nearest_edge(point: Point, area: Rectangle) -> ScreenEdge {
+    let left = area.top_left.x;
+    let top = area.top_left.y;
+    let right = left + area.size.width as i32;
+    let bottom = top + area.size.height as i32;
+
+    // Distance of `value` from the `start..=end` range
+    let overshoot = |value: i32, start: i32, end: i32| (start - value).max(value - end).max(0);
+    let distance = |along: i32, across: i32| along * along + across * across;
+
+    let dx = overshoot(point.x, left, right);
+    let dy = overshoot(point.y, top, bottom);
+
+    [
+        (ScreenEdge::Left, distance(point.x - left, dy)),
+        (ScreenEdge::Right, distance(right - point.x, dy)),
+        (ScreenEdge::Top, distance(point.y - top, dx)),
+        (ScreenEdge::Bottom, distance(bottom - point.y, dx)),
+    ]
+    .iter()
+    .fold(
+        (ScreenEdge::Left, i32::MAX),
+        |nearest, &(edge, distance)| {
+            if distance < nearest.1 {
+                (edge, distance)
+            } else {
+                nearest
+            }
+        },
+    )
+    .0
+}
+
+/// Position along an axis, relative to the direction of the axis.
+///
+/// `Start` is the left or top end of an unrotated display. The default position is `Start`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlignmentPosition {
+    /// The beginning of the axis
+    Start,
+
+    /// The middle of the axis
+    Center,
+
+    /// The end of the axis
+    End,
+}
+
+impl Default for AlignmentPosition {
+    #[inline]
+    fn default() -> Self {
+        AlignmentPosition::Start
+    }
+}
+
+impl AlignmentPosition {
+    /// Returns the opposite position, i.e. `End` for `Start` and vice versa.
+    #[inline]
+    pub const fn flip(self) -> Self {
+        match self {
+            AlignmentPosition::Start => AlignmentPosition::End,
+            AlignmentPosition::Center => AlignmentPosition::Center,
+            AlignmentPosition::End => AlignmentPosition::Start,
+        }
+    }
+
+    pub(crate) fn align_horizontal(self, what: Rectangle, reference: Rectangle) -> i32 {
+        match self {
+            AlignmentPosition::Start => horizontal::Left.align(what, reference),
+            AlignmentPosition::Center => horizontal::Center.align(what, reference),
+            AlignmentPosition::End => horizontal::Right.align(what, reference),
+        }
+    }
+
+    pub(crate) fn align_vertical(self, what: Rectangle, reference: Rectangle) -> i32 {
+        match self {
+            AlignmentPosition::Start => vertical::Top.align(what, reference),
+            AlignmentPosition::Center => vertical::Center.align(what, reference),
+            AlignmentPosition::End => vertical::Bottom.align(what, reference),
+        }
+    }
+}
+
+/// Returns the translation that docks `what` to the logical `edge` of `reference`.
+pub(crate) fn dock_offset(
+    what: Rectangle,
+    reference: Rectangle,
+    edge: ScreenEdge,
+    cross: AlignmentPosition,
+    rotation: Option<Rotation>,
+) -> Point {
+    let physical_edge = edge.rotate(rotation);
+
+    // The logical start of the edge may be at the physical end after rotation.
+    let cross_direction = match rotation {
+        Some(rotation) => rotation.rotate_vector(edge.cross_direction()),
+        None => edge.cross_direction(),
+    };
+    let cross = if cross_direction.x < 0 || cross_direction.y < 0 {
+        cross.flip()
+    } else {
+        cross
+    };
+
+    match physical_edge {
+        ScreenEdge::Top => Point::new(
+            cross.align_horizontal(what, reference),
+            AlignmentPosition::Start.align_vertical(what, reference),
+        ),
+        ScreenEdge::Bottom => Point::new(
+            cross.align_horizontal(what, reference),
+            AlignmentPosition::End.align_vertical(what, reference),
+        ),
+        ScreenEdge::Left => Point::new(
+            AlignmentPosition::Start.align_horizontal(what, reference),
+            cross.align_vertical(what, reference),
+        ),
+        ScreenEdge::Right => Point::new(
+            AlignmentPosition::End.align_horizontal(what, reference),
+            cross.align_vertical(what, reference),
+        ),
+    }
+}
@@ -0,0 +1,171 @@
+use embedded_graphics::{
+    prelude::{Point, Transform},
+    primitives::Rectangle,
+};
+
+use crate::align::{AlignmentPosition, HorizontalAlignment, VerticalAlignment};
+
+/// Returns `target` aligned to `reference` using the alignment parameters as rules.
+///
+/// This is the geometry behind [`Align::align_to`], for code that only has rectangles, e.g.
+/// computed regions or dirty areas, and no views.
+///
+/// ```rust
+/// # use embedded_layout::{align::aligned, prelude::*};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let reference = Rectangle::new(Point::new(10, 10), Size::new(20, 20));
+/// let target = Rectangle::new(Point::zero(), Size::new(5, 5));
+///
+/// let moved = aligned(target, reference, horizontal::LeftToRight, vertical::Bottom);
+/// assert_eq!(Point::new(30, 25), moved.top_left);
+/// ```
+///
+/// [`Align::align_to`]: crate::align::Align::align_to
+#[inline]
+pub fn aligned<H, V>(
+    target: Rectangle,
+    reference: Rectangle,
+    horizontal: H,
+    vertical: V,
+) -> Rectangle
+where
+    H: HorizontalAlignment,
+    V: VerticalAlignment,
+{
+    let h = horizontal.align(target, reference);
+    let v = vertical.align(target, reference);
+
+    target.translate(Point::new(h, v))
+}
+
+/// Returns `target` placed inside of `bounds` at the given positions along both axes.
+///
+/// If `target` is larger than `bounds`, it overhangs `bounds` the same way as with the
+/// corresponding [`horizontal`] and [`vertical`] alignments.
+///
+/// [`horizontal`]: crate::align::horizontal
+/// [`vertical`]: crate::align::vertical
+#[inline]
+pub fn aligned_within(
+    target: Rectangle,
+    bounds: Rectangle,
+    horizontal: AlignmentPosition,
+    vertical: AlignmentPosition,
+) -> Rectangle {
+    let h = horizontal.align_horizontal(target, bounds);
+    let v = vertical.align_vertical(target, bounds);
+
+    target.translate(Point::new(h, v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    const POSITIONS: [AlignmentPosition; 3] = [
+        AlignmentPosition::Start,
+        AlignmentPosition::Center,
+        AlignmentPosition::End,
+    ];
+
+    fn rects() -> [(Rectangle, Rectangle); 3] {
+        [
+            (
+                Rectangle::new(Point::new(-7, 3), Size::new(5, 4)),
+                Rectangle::new(Point::new(10, 10), Size::new(20, 11)),
+            ),
+            (
+                Rectangle::new(Point::new(100, -3), Size::new(30, 2)),
+                Rectangle::new(Point::new(-10, -10), Size::new(9, 9)),
+            ),
+            (
+                Rectangle::new(Point::zero(), Size::zero()),
+                Rectangle::new(Point::new(1, 2), Size::new(3, 4)),
+            ),
+        ]
+    }
+
+    /// Aligns `target` using the view based API.
+    fn align_view(
+        target: Rectangle,
+        reference: Rectangle,
+        h: AlignmentPosition,
+        v: AlignmentPosition,
+    ) -> Rectangle {
+        let target = match h {
+            AlignmentPosition::Start => {
+                target.align_to(&reference, horizontal::Left, vertical::NoAlignment)
+            }
+            AlignmentPosition::Center => {
+                target.align_to(&reference, horizontal::Center, vertical::NoAlignment)
+            }
+            AlignmentPosition::End => {
+                target.align_to(&reference, horizontal::Right, vertical::NoAlignment)
+            }
+        };
+
+        match v {
+            AlignmentPosition::Start => {
+                target.align_to(&reference, horizontal::NoAlignment, vertical::Top)
+            }
+            AlignmentPosition::Center => {
+                target.align_to(&reference, horizontal::NoAlignment, vertical::Center)
+            }
+            AlignmentPosition::End => {
+                target.align_to(&reference, horizontal::NoAlignment, vertical::Bottom)
+            }
+        }
+    }
+
+    #[test]
+    fn aligned_within_matches_align_to() {
+        for (target, bounds) in rects() {
+            for h in POSITIONS {
+                for v in POSITIONS {
+                    assert_eq!(
+                        align_view(target, bounds, h, v),
+                        aligned_within(target, bounds, h, v),
+                        "{:?} {:?}",
+                        h,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn aligned_matches_align_to() {
+        for (target, reference) in rects() {
+            assert_eq!(
+                target.align_to(&reference, horizontal::LeftToRight, vertical::BottomToTop),
+                aligned(
+                    target,
+                    reference,
+                    horizontal::LeftToRight,
+                    vertical::BottomToTop
+                )
+            );
+            assert_eq!(
+                target.align_to(&reference, horizontal::RightToLeft, vertical::TopToBottom),
+                aligned(
+                    target,
+                    reference,
+                    horizontal::RightToLeft,
+                    vertical::TopToBottom
+                )
+            );
+            assert_eq!(
+                target.align_to(&reference, horizontal::Center, vertical::Center),
+                horizontal::Center.apply(vertical::Center.apply(target, reference), reference)
+            );
+            assert_eq!(
+                target,
+                horizontal::NoAlignment
+                    .apply(vertical::NoAlignment.apply(target, reference), reference)
+            );
+        }
+    }
+}
@@ -18,17 +18,58 @@
 //! and two alignment parameters. The second [`View`] will not be translated by the alignment
 //! operation.
 //!
+//! For pointer-style widgets, the bounding box center is not always the visual center of a shape.
+//! [`align_centers_to`] aligns two objects using their [`ShapeCenter`] instead, e.g. the centroid
+//! of a triangle.
+//!
+//! To align several views to the same reference, wrap the reference in an [`Anchored`].
+//! [`align_anchor`] places a view using a single pair of anchor points, e.g. the bottom left
+//! corner of a tooltip next to the top left corner of a button, see [`DockAnchors`].
+//!
+//! Plain rectangles, e.g. computed regions, can be aligned without the [`View`] machinery using
+//! [`aligned`] and [`aligned_within`].
+//!
+//! Texts using different fonts can be lined up on a shared baseline grid using
+//! [`snap_to_baseline_grid`].
+//!
 //! [`horizontal`]: crate::align::horizontal
 //! [`vertical`]: crate::align::vertical
+//! [`align_centers_to`]: crate::align::Align::align_centers_to
 //! [`align_*`]: crate::align::Align
 //! [`align_to`]: crate::align::Align::align_to
 //! [`align_to_mut`]: crate::align::Align::align_to_mut
-use crate::prelude::*;
-use embedded_graphics::{prelude::Point, primitives::Rectangle};
+//! [`align_anchor`]: crate::align::Align::align_anchor
+use crate::{
+    prelude::*,
+    utils::{rotation::Rotation, rounding::div_floor},
+    view_group::{ViewGroup, ViewGroupHelper},
+};
+use embedded_graphics::{
+    prelude::{Point, Size, Transform},
+    primitives::{
+        Arc, Circle, Ellipse, Line, Polyline, Rectangle, RoundedRectangle, Sector, Styled,
+        StyledDimensions, Triangle,
+    },
+    text::Text,
+};
 
+mod anchored;
+mod anchors;
+mod baseline;
+mod dock;
+mod geometry;
 pub mod horizontal;
+mod rounded;
 pub mod vertical;
 
+pub use anchored::Anchored;
+pub use anchors::DockAnchors;
+pub(crate) use baseline::{bottom_row, grid_offset};
+pub use baseline::{snap_to_baseline_grid, HasBaseline};
+pub use dock::{AlignmentPosition, ScreenEdge};
+pub use geometry::{aligned, aligned_within};
+pub use rounded::{align_inside_rounded, rounded_content_box};
+
 /// This trait enables alignment operations for [`View`] objects
 ///
 /// This trait is blanket-implemented for all objects that implement [`View`].
@@ -51,6 +92,147 @@ pub trait Align {
     where
         H: HorizontalAlignment,
         V: VerticalAlignment;
+
+    /// Return the object aligned to an other one, measuring the object with the given style
+    ///
+    /// Unlike [`align_to`](Align::align_to), this method uses the styled bounding box of the
+    /// object, so a stroke drawn outside of the primitive is taken into account.
+    fn align_to_styled<S, H, V>(
+        self,
+        reference: &impl View,
+        style: &S,
+        horizontal: H,
+        vertical: V,
+    ) -> Self
+    where
+        Self: StyledDimensions<S>,
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Align the object to an other one, measuring the object with the given style
+    ///
+    /// Unlike [`align_to_mut`](Align::align_to_mut), this method uses the styled bounding box of
+    /// the object, so a stroke drawn outside of the primitive is taken into account.
+    fn align_to_styled_mut<S, H, V>(
+        &mut self,
+        reference: &impl View,
+        style: &S,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        Self: StyledDimensions<S>,
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Return the object moved so that one of its anchor points is on an anchor point of
+    /// `reference`
+    ///
+    /// See [`DockAnchors`] for more information.
+    fn align_anchor(self, reference: &impl View, anchors: DockAnchors) -> Self;
+
+    /// Move the object so that one of its anchor points is on an anchor point of `reference`
+    ///
+    /// See [`DockAnchors`] for more information.
+    fn align_anchor_mut(&mut self, reference: &impl View, anchors: DockAnchors) -> &mut Self;
+
+    /// Return the object docked to an edge of `screen`
+    ///
+    /// The object is moved to the inside of the given `edge`, and aligned along the edge according
+    /// to `cross`. `edge` and `cross` are interpreted on a display rotated by `rotation`, i.e.
+    /// docking to the top of a display rotated by a quarter turn moves the object to the right
+    /// edge of `screen`.
+    fn dock_to_edge(
+        self,
+        screen: &impl View,
+        edge: ScreenEdge,
+        cross: AlignmentPosition,
+        rotation: Option<Rotation>,
+    ) -> Self;
+
+    /// Dock the object to an edge of `screen`
+    ///
+    /// See [`dock_to_edge`](Align::dock_to_edge) for more information.
+    fn dock_to_edge_mut(
+        &mut self,
+        screen: &impl View,
+        edge: ScreenEdge,
+        cross: AlignmentPosition,
+        rotation: Option<Rotation>,
+    ) -> &mut Self;
+
+    /// Return the object moved so that its shape center matches the shape center of `reference`
+    ///
+    /// See [`ShapeCenter`] for more information.
+    fn align_centers_to(self, reference: &impl ShapeCenter) -> Self
+    where
+        Self: ShapeCenter;
+
+    /// Move the object so that its shape center matches the shape center of `reference`
+    ///
+    /// See [`ShapeCenter`] for more information.
+    fn align_centers_to_mut(&mut self, reference: &impl ShapeCenter) -> &mut Self
+    where
+        Self: ShapeCenter;
+
+    /// Return the object aligned to the centroid of the views in `group`
+    ///
+    /// The centroid is the average of the centers of the views, so unlike the center of the
+    /// bounding box, it is not pulled towards a single outlier. The object is aligned to a zero
+    /// sized rectangle at the centroid. An empty view group leaves the object unchanged.
+    fn align_to_centroid<H, V>(self, group: &impl ViewGroup, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Align the object to the centroid of the views in `group`
+    ///
+    /// See [`align_to_centroid`](Align::align_to_centroid) for more information.
+    fn align_to_centroid_mut<H, V>(
+        &mut self,
+        group: &impl ViewGroup,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Return the object aligned to the union of the bounding boxes of the views in `group`
+    ///
+    /// Unlike [`align_to_centroid`](Align::align_to_centroid), this uses the combined extent of
+    /// the views, so the views don't need to be wrapped into a single [`View`] first. An empty
+    /// view group leaves the object unchanged.
+    fn align_to_union<H, V>(self, group: &impl ViewGroup, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Align the object to the union of the bounding boxes of the views in `group`
+    ///
+    /// See [`align_to_union`](Align::align_to_union) for more information.
+    fn align_to_union_mut<H, V>(
+        &mut self,
+        group: &impl ViewGroup,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Return the object moved outside of the edge of `reference` that its center is closest to
+    ///
+    /// The object is placed next to the edge, without overlapping `reference`, and centered along
+    /// it. E.g. an object closest to the left edge is aligned using [`horizontal::RightToLeft`]
+    /// and [`vertical::Center`]. If the center is equally close to multiple edges, the first one
+    /// of left, right, top and bottom is used.
+    fn snap_to_nearest_edge(self, reference: &impl View) -> Self;
+
+    /// Move the object outside of the edge of `reference` that its center is closest to
+    ///
+    /// See [`snap_to_nearest_edge`](Align::snap_to_nearest_edge) for more information.
+    fn snap_to_nearest_edge_mut(&mut self, reference: &impl View) -> &mut Self;
 }
 
 impl<T> Align for T
@@ -74,12 +256,293 @@ where
         V: VerticalAlignment,
     {
         let self_bounds = self.bounds();
-        let reference_bounds = reference.bounds();
+        let moved = aligned(self_bounds, reference.bounds(), horizontal, vertical);
+
+        self.translate_mut(moved.top_left - self_bounds.top_left)
+    }
+
+    #[inline]
+    fn align_to_styled<S, H, V>(
+        mut self,
+        reference: &impl View,
+        style: &S,
+        horizontal: H,
+        vertical: V,
+    ) -> Self
+    where
+        Self: StyledDimensions<S>,
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_to_styled_mut(reference, style, horizontal, vertical);
+        self
+    }
 
-        let h = horizontal.align(self_bounds, reference_bounds);
-        let v = vertical.align(self_bounds, reference_bounds);
+    #[inline]
+    fn align_to_styled_mut<S, H, V>(
+        &mut self,
+        reference: &impl View,
+        style: &S,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        Self: StyledDimensions<S>,
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        let self_bounds = self.styled_bounding_box(style);
+        let moved = aligned(self_bounds, reference.bounds(), horizontal, vertical);
+
+        self.translate_mut(moved.top_left - self_bounds.top_left)
+    }
 
-        self.translate_mut(Point::new(h, v))
+    #[inline]
+    fn align_anchor(mut self, reference: &impl View, anchors: DockAnchors) -> Self {
+        self.align_anchor_mut(reference, anchors);
+        self
+    }
+
+    #[inline]
+    fn align_anchor_mut(&mut self, reference: &impl View, anchors: DockAnchors) -> &mut Self {
+        let by = anchors.translation(self.bounds(), reference.bounds());
+        self.translate_mut(by)
+    }
+
+    #[inline]
+    fn dock_to_edge(
+        mut self,
+        screen: &impl View,
+        edge: ScreenEdge,
+        cross: AlignmentPosition,
+        rotation: Option<Rotation>,
+    ) -> Self {
+        self.dock_to_edge_mut(screen, edge, cross, rotation);
+        self
+    }
+
+    #[inline]
+    fn dock_to_edge_mut(
+        &mut self,
+        screen: &impl View,
+        edge: ScreenEdge,
+        cross: AlignmentPosition,
+        rotation: Option<Rotation>,
+    ) -> &mut Self {
+        let by = dock::dock_offset(self.bounds(), screen.bounds(), edge, cross, rotation);
+        self.translate_mut(by)
+    }
+
+    #[inline]
+    fn align_centers_to(mut self, reference: &impl ShapeCenter) -> Self
+    where
+        Self: ShapeCenter,
+    {
+        self.align_centers_to_mut(reference);
+        self
+    }
+
+    #[inline]
+    fn align_centers_to_mut(&mut self, reference: &impl ShapeCenter) -> &mut Self
+    where
+        Self: ShapeCenter,
+    {
+        let by = reference.shape_center() - self.shape_center();
+        self.translate_mut(by)
+    }
+
+    #[inline]
+    fn snap_to_nearest_edge(mut self, reference: &impl View) -> Self {
+        self.snap_to_nearest_edge_mut(reference);
+        self
+    }
+
+    #[inline]
+    fn snap_to_nearest_edge_mut(&mut self, reference: &impl View) -> &mut Self {
+        let bounds = reference.bounds();
+        match dock::nearest_edge(self.bounds().center(), bounds) {
+            ScreenEdge::Left => {
+                self.align_to_mut(&bounds, horizontal::RightToLeft, vertical::Center)
+            }
+            ScreenEdge::Right => {
+                self.align_to_mut(&bounds, horizontal::LeftToRight, vertical::Center)
+            }
+            ScreenEdge::Top => {
+                self.align_to_mut(&bounds, horizontal::Center, vertical::BottomToTop)
+            }
+            ScreenEdge::Bottom => {
+                self.align_to_mut(&bounds, horizontal::Center, vertical::TopToBottom)
+            }
+        }
+    }
+
+    #[inline]
+    fn align_to_centroid<H, V>(mut self, group: &impl ViewGroup, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_to_centroid_mut(group, horizontal, vertical);
+        self
+    }
+
+    #[inline]
+    fn align_to_centroid_mut<H, V>(
+        &mut self,
+        group: &impl ViewGroup,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        let count = group.len() as i64;
+        if count == 0 {
+            return self;
+        }
+
+        let (x, y) = (0..group.len())
+            .map(|idx| group.bounds_of(idx).center())
+            .fold((0, 0), |(x, y), center| {
+                (x + i64::from(center.x), y + i64::from(center.y))
+            });
+        let centroid = Point::new(x.div_euclid(count) as i32, y.div_euclid(count) as i32);
+
+        self.align_to_mut(
+            &Rectangle::new(centroid, Size::zero()),
+            horizontal,
+            vertical,
+        )
+    }
+
+    #[inline]
+    fn align_to_union<H, V>(mut self, group: &impl ViewGroup, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_to_union_mut(group, horizontal, vertical);
+        self
+    }
+
+    #[inline]
+    fn align_to_union_mut<H, V>(
+        &mut self,
+        group: &impl ViewGroup,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        if group.len() == 0 {
+            return self;
+        }
+
+        self.align_to_mut(&ViewGroupHelper::bounds(group), horizontal, vertical)
+    }
+}
+
+/// Align the primitive of a styled object to an other one using the alignment parameters as rules
+///
+/// The result is the same as calling [`align_to_mut`] on `styled`, i.e. the styled bounding box is
+/// aligned, but only the primitive is translated. This is useful when a styled object is stored
+/// inside another view, and only a mutable reference to it is available, e.g. through the
+/// `inner_mut` methods of the [decorator views].
+///
+/// [`align_to_mut`]: Align::align_to_mut
+/// [decorator views]: crate::views
+#[inline]
+pub fn align_primitive_to<T, S, H, V>(
+    styled: &mut Styled<T, S>,
+    reference: &impl View,
+    horizontal: H,
+    vertical: V,
+) where
+    T: Transform + StyledDimensions<S>,
+    H: HorizontalAlignment,
+    V: VerticalAlignment,
+{
+    let self_bounds = styled.primitive.styled_bounding_box(&styled.style);
+    let moved = aligned(self_bounds, reference.bounds(), horizontal, vertical);
+
+    styled
+        .primitive
+        .translate_mut(moved.top_left - self_bounds.top_left);
+}
+
+/// Return `circle` centered on `reference`, including the stroke of `style`
+///
+/// This is a shorthand for [`align_to_styled`] with centered alignments. The stroke of a
+/// [`Circle`] is not part of its bounding box, so [`align_to`] may round the position of a circle
+/// with a thick stroke differently than the position of the drawn shape.
+///
+/// [`align_to_styled`]: Align::align_to_styled
+/// [`align_to`]: Align::align_to
+#[inline]
+pub fn center_circle_on<S>(circle: Circle, style: &S, reference: &impl View) -> Circle
+where
+    Circle: StyledDimensions<S>,
+{
+    circle.align_to_styled(reference, style, horizontal::Center, vertical::Center)
+}
+
+/// The visual center of a shape
+///
+/// By default, the shape center is the center of the bounding box. Shapes where a better center
+/// exists override [`shape_center`](ShapeCenter::shape_center), e.g. the center of a [`Triangle`]
+/// is its centroid and the center of a [`Line`] is its midpoint.
+///
+/// Used by [`Align::align_centers_to`].
+pub trait ShapeCenter: View {
+    /// Returns the visual center of the shape
+    #[inline]
+    fn shape_center(&self) -> Point {
+        self.bounds().center()
+    }
+}
+
+impl ShapeCenter for Rectangle {}
+impl ShapeCenter for RoundedRectangle {}
+impl ShapeCenter for Ellipse {}
+impl ShapeCenter for Arc {}
+impl ShapeCenter for Sector {}
+impl ShapeCenter for Polyline<'_> {}
+impl<S> ShapeCenter for Text<'_, S> where Self: View {}
+
+impl ShapeCenter for Circle {
+    #[inline]
+    fn shape_center(&self) -> Point {
+        self.center()
+    }
+}
+
+impl ShapeCenter for Line {
+    #[inline]
+    fn shape_center(&self) -> Point {
+        self.midpoint()
+    }
+}
+
+impl ShapeCenter for Triangle {
+    #[inline]
+    fn shape_center(&self) -> Point {
+        let [p1, p2, p3] = self.vertices;
+        let sum = p1 + p2 + p3;
+        Point::new(div_floor(sum.x, 3), div_floor(sum.y, 3))
+    }
+}
+
+impl<T, S> ShapeCenter for Styled<T, S>
+where
+    T: ShapeCenter,
+    Self: View,
+{
+    #[inline]
+    fn shape_center(&self) -> Point {
+        self.primitive.shape_center()
     }
 }
 
@@ -104,22 +567,274 @@ pub trait Alignment: Copy + Clone + Default {
 
 /// Implement this trait for horizontal alignment operations
 ///
-/// This trait does not provide any functionality other than that of [`Alignment`], but marks
-/// implementors to be used as horizontal alignmenent operations.
+/// This trait marks implementors to be used as horizontal alignmenent operations.
 ///
 /// For a list of available horizontal alignments, see the [`horizontal`] module.
 ///
 /// [`horizontal`]: crate::align::horizontal
-pub trait HorizontalAlignment: Alignment {}
+pub trait HorizontalAlignment: Alignment {
+    /// Returns `target` moved horizontally to be aligned to `reference`
+    #[inline]
+    fn apply(&self, target: Rectangle, reference: Rectangle) -> Rectangle {
+        aligned(target, reference, *self, vertical::NoAlignment)
+    }
+}
 
 /// Implement this trait for vertical alignment operations
 ///
 /// Vertical alignment assumes lower coordinate values are higher up on the display.
 ///
-/// This trait does not provide any functionality other than that of [`Alignment`], but marks
-/// implementors to be used as vertical alignmenent operations.
+/// This trait marks implementors to be used as vertical alignmenent operations.
 ///
 /// For a list of available vertical alignments, see the [`vertical`] module.
 ///
 /// [`vertical`]: crate::align::vertical
-pub trait VerticalAlignment: Alignment {}
+pub trait VerticalAlignment: Alignment {
+    /// Returns `target` moved vertically to be aligned to `reference`
+    #[inline]
+    fn apply(&self, target: Rectangle, reference: Rectangle) -> Rectangle {
+        aligned(target, reference, horizontal::NoAlignment, *self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        align_primitive_to, center_circle_on, AlignmentPosition, Anchored, ScreenEdge, ShapeCenter,
+    };
+    use crate::{
+        prelude::*,
+        utils::rotation::Rotation,
+        view_group::{EmptyViewGroup, ViewGroupHelper},
+        views::{ExpandHitArea, ZOrdered},
+    };
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::{Point, Primitive, Size},
+        primitives::{
+            Circle, Ellipse, PrimitiveStyleBuilder, Rectangle, StrokeAlignment, StyledDimensions,
+            Triangle,
+        },
+    };
+
+    #[test]
+    fn align_to_styled_includes_outside_stroke() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .build();
+
+        let reference = Rectangle::new(Point::new(20, 30), Size::new(40, 40));
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 10));
+
+        let unstyled = rect.align_to(&reference, horizontal::Left, vertical::Top);
+        let styled = rect.align_to_styled(&reference, &style, horizontal::Left, vertical::Top);
+
+        // The stroke is now inside the reference
+        assert_eq!(Point::new(23, 33), styled.top_left);
+        assert_eq!(unstyled.top_left + Point::new(3, 3), styled.top_left);
+        assert_eq!(rect.size, styled.size);
+    }
+
+    #[test]
+    fn center_stroked_circle_and_ellipse() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .build();
+
+        let reference = Rectangle::new(Point::new(10, 10), Size::new(41, 41));
+
+        let circle = center_circle_on(Circle::new(Point::zero(), 11), &style, &reference);
+        assert_eq!(
+            Point::new(22, 22),
+            circle.styled_bounding_box(&style).top_left
+        );
+        assert_eq!(
+            reference.center(),
+            circle.styled_bounding_box(&style).center()
+        );
+        assert_eq!(Point::new(25, 25), circle.top_left);
+
+        let ellipse = Ellipse::new(Point::zero(), Size::new(15, 5)).align_to_styled(
+            &reference,
+            &style,
+            horizontal::Center,
+            vertical::Center,
+        );
+        assert_eq!(
+            reference.center(),
+            ellipse.styled_bounding_box(&style).center()
+        );
+        assert_eq!(Point::new(23, 28), ellipse.top_left);
+    }
+
+    #[test]
+    fn align_primitive_inside_decorators() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .build();
+
+        let reference = Rectangle::new(Point::new(20, 30), Size::new(40, 40));
+        let styled = Rectangle::new(Point::zero(), Size::new(10, 10)).into_styled(style);
+
+        let mut wrapped = ExpandHitArea::new(ZOrdered::new(styled, 1), Size::new(20, 20));
+        align_primitive_to(
+            wrapped.inner_mut().inner_mut(),
+            &reference,
+            horizontal::Right,
+            vertical::Bottom,
+        );
+
+        let expected = styled.align_to(&reference, horizontal::Right, vertical::Bottom);
+        assert_eq!(expected.primitive, wrapped.inner().inner().primitive);
+        assert_eq!(Point::new(48, 58), expected.primitive.top_left);
+    }
+
+    #[test]
+    fn align_to_centroid_ignores_outliers() {
+        let view = Rectangle::new(Point::zero(), Size::new(10, 10));
+        let mut group = [
+            Rectangle::new(Point::new(0, 0), Size::new(3, 3)),
+            Rectangle::new(Point::new(6, 0), Size::new(3, 3)),
+            Rectangle::new(Point::new(90, 30), Size::new(3, 3)),
+        ];
+        let group = Views::new(&mut group);
+
+        // Centers at (1, 1), (7, 1) and (91, 31)
+        let by_centroid = view.align_to_centroid(&group, horizontal::Center, vertical::Center);
+        let by_bounds = view.align_to(&group, horizontal::Center, vertical::Center);
+
+        assert_eq!(Point::new(33, 11), by_centroid.center());
+        assert_eq!(Point::new(46, 16), by_bounds.center());
+
+        let by_edges = view.align_to_centroid(&group, horizontal::Left, vertical::Top);
+        assert_eq!(Point::new(33, 11), by_edges.top_left);
+    }
+
+    #[test]
+    fn align_to_union_centers_over_scattered_views() {
+        let title = Rectangle::new(Point::zero(), Size::new(9, 5));
+        let icons = Chain::new(Rectangle::new(Point::new(4, 20), Size::new(5, 5)))
+            .append(Rectangle::new(Point::new(30, 12), Size::new(5, 5)))
+            .append(Rectangle::new(Point::new(17, 26), Size::new(5, 9)));
+
+        // The icons span from (4, 12) to (34, 34)
+        let title = title.align_to_union(&icons, horizontal::Center, vertical::BottomToTop);
+
+        assert_eq!(Point::new(15, 7), title.top_left);
+        assert_eq!(ViewGroupHelper::bounds(&icons).center().x, title.center().x);
+
+        // An empty group doesn't move the view
+        let moved = title.align_to_union(&EmptyViewGroup, horizontal::Left, vertical::Top);
+        assert_eq!(title, moved);
+    }
+
+    #[test]
+    fn align_centers_to_uses_triangle_centroid() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 3));
+        let reference = Circle::with_center(Point::new(50, 50), 11);
+
+        // The centroid is 5 pixels left of the bounding box center
+        assert_eq!(Point::new(10, 1), triangle.shape_center());
+        assert_eq!(Point::new(15, 1), triangle.bounds().center());
+
+        let by_centroid = triangle.align_centers_to(&reference);
+        let by_bounds = triangle.align_to(&reference, horizontal::Center, vertical::Center);
+
+        assert_eq!(Point::new(50, 50), by_centroid.shape_center());
+        assert_eq!(Point::new(40, 49), by_centroid.vertices[0]);
+        assert_eq!(Point::new(35, 49), by_bounds.vertices[0]);
+    }
+
+    #[test]
+    fn dock_to_each_edge() {
+        let screen = Rectangle::new(Point::zero(), Size::new(20, 10));
+        let rect = Rectangle::new(Point::new(7, 3), Size::new(4, 2));
+
+        let dock = |edge, cross| rect.dock_to_edge(&screen, edge, cross, None).top_left;
+
+        assert_eq!(
+            Point::new(0, 0),
+            dock(ScreenEdge::Top, AlignmentPosition::Start)
+        );
+        assert_eq!(
+            Point::new(8, 0),
+            dock(ScreenEdge::Top, AlignmentPosition::Center)
+        );
+        assert_eq!(
+            Point::new(16, 8),
+            dock(ScreenEdge::Bottom, AlignmentPosition::End)
+        );
+        assert_eq!(
+            Point::new(0, 4),
+            dock(ScreenEdge::Left, AlignmentPosition::Center)
+        );
+        assert_eq!(
+            Point::new(16, 0),
+            dock(ScreenEdge::Right, AlignmentPosition::Start)
+        );
+    }
+
+    #[test]
+    fn dock_to_edge_of_rotated_screen() {
+        let screen = Rectangle::new(Point::zero(), Size::new(20, 10));
+        let rect = Rectangle::new(Point::new(7, 3), Size::new(4, 2));
+
+        // The logical top-left corner is the physical top-right corner
+        let docked = rect.dock_to_edge(
+            &screen,
+            ScreenEdge::Top,
+            AlignmentPosition::Start,
+            Some(Rotation::Quarter),
+        );
+        assert_eq!(Point::new(16, 0), docked.top_left);
+
+        // The logical bottom-right corner is the physical top-left corner
+        let docked = rect.dock_to_edge(
+            &screen,
+            ScreenEdge::Bottom,
+            AlignmentPosition::End,
+            Some(Rotation::Half),
+        );
+        assert_eq!(Point::new(0, 0), docked.top_left);
+    }
+
+    #[test]
+    fn anchored_places_views_against_one_anchor() {
+        let anchor = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+        let anchored = Anchored::from(&anchor);
+
+        let label = anchored.place(
+            Rectangle::new(Point::zero(), Size::new(6, 4)),
+            horizontal::RightToLeft,
+            vertical::Center,
+        );
+        let mut badge = Rectangle::new(Point::new(50, 50), Size::new(4, 4));
+        anchored.place_mut(&mut badge, horizontal::Right, vertical::BottomToTop);
+
+        assert_eq!(Rectangle::new(Point::new(4, 13), Size::new(6, 4)), label);
+        assert_eq!(Rectangle::new(Point::new(26, 6), Size::new(4, 4)), badge);
+        assert_eq!(
+            Rectangle::new(Point::new(10, 10), Size::new(20, 10)),
+            *anchored.reference()
+        );
+    }
+
+    #[test]
+    fn snap_to_nearest_edge() {
+        let reference = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+        let view = |x, y| Rectangle::new(Point::new(x, y), Size::new(4, 4));
+
+        // Left, right, above and below the reference, and inside it, close to an edge
+        assert_eq!(view(6, 13), view(0, 0).snap_to_nearest_edge(&reference));
+        assert_eq!(view(30, 13), view(40, 16).snap_to_nearest_edge(&reference));
+        assert_eq!(view(18, 6), view(17, 1).snap_to_nearest_edge(&reference));
+        assert_eq!(view(18, 20), view(14, 30).snap_to_nearest_edge(&reference));
+        assert_eq!(view(30, 13), view(26, 14).snap_to_nearest_edge(&reference));
+    }
+}
@@ -0,0 +1,133 @@
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::{Rectangle, RoundedRectangle},
+};
+
+use crate::{
+    align::{Align, HorizontalAlignment, VerticalAlignment},
+    View,
+};
+
+/// Returns the area of a rounded rectangle that is not affected by the rounded corners.
+///
+/// The corners of the returned rectangle lie on the 45° points of the corner arcs, so content
+/// aligned to an edge or corner of the content box doesn't overlap the curve of the corners. If the
+/// two corners on an edge have different radii, the edge is inset by the larger of the two insets,
+/// so the content box stays an axis-aligned rectangle.
+///
+/// The corner radii are expected to fit the size of the rounded rectangle.
+#[inline]
+pub fn rounded_content_box(rounded: &RoundedRectangle) -> Rectangle {
+    let corners = &rounded.corners;
+
+    let left = inset(corners.top_left.width).max(inset(corners.bottom_left.width));
+    let right = inset(corners.top_right.width).max(inset(corners.bottom_right.width));
+    let top = inset(corners.top_left.height).max(inset(corners.top_right.height));
+    let bottom = inset(corners.bottom_left.height).max(inset(corners.bottom_right.height));
+
+    let size = rounded.rectangle.size;
+    Rectangle::new(
+        rounded.rectangle.top_left + Point::new(left as i32, top as i32),
+        Size::new(
+            size.width.saturating_sub(left + right),
+            size.height.saturating_sub(top + bottom),
+        ),
+    )
+}
+
+/// Return `view` aligned inside the content box of `rounded`
+///
+/// See [`rounded_content_box`] for more information.
+#[inline]
+pub fn align_inside_rounded<T, H, V>(
+    view: T,
+    rounded: &RoundedRectangle,
+    horizontal: H,
+    vertical: V,
+) -> T
+where
+    T: View,
+    H: HorizontalAlignment,
+    V: VerticalAlignment,
+{
+    view.align_to(&rounded_content_box(rounded), horizontal, vertical)
+}
+
+/// Returns the distance between the edge and the 45° point of an arc with the given radius,
+/// rounded up.
+fn inset(radius: u32) -> u32 {
+    // radius - radius / sqrt(2), rounded up
+    radius - isqrt(u64::from(radius) * u64::from(radius) / 2)
+}
+
+/// Integer square root, rounded down.
+fn isqrt(value: u64) -> u32 {
+    if value < 2 {
+        return value as u32;
+    }
+
+    // Newton's method, starting above the result
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::primitives::CornerRadii;
+
+    #[test]
+    fn integer_square_root() {
+        let roots = (0..200).map(isqrt).collect::<Vec<_>>();
+        for (value, root) in roots.iter().enumerate() {
+            let root = *root as usize;
+            assert!(root * root <= value && (root + 1) * (root + 1) > value);
+        }
+    }
+
+    #[test]
+    fn symmetric_radii() {
+        let rounded = RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::new(5, 5), Size::new(40, 30)),
+            Size::new(10, 10),
+        );
+
+        // 10 - floor(10 / sqrt(2)) = 3
+        assert_eq!(
+            Rectangle::new(Point::new(8, 8), Size::new(34, 24)),
+            rounded_content_box(&rounded)
+        );
+    }
+
+    #[test]
+    fn asymmetric_radii() {
+        let rounded = RoundedRectangle::new(
+            Rectangle::new(Point::zero(), Size::new(40, 30)),
+            CornerRadii {
+                // insets 3 and 2
+                top_left: Size::new(10, 4),
+                // insets 2 and 2
+                top_right: Size::new(4, 4),
+                bottom_right: Size::zero(),
+                // insets 2 and 2
+                bottom_left: Size::new(6, 6),
+            },
+        );
+
+        assert_eq!(
+            Rectangle::new(Point::new(3, 2), Size::new(35, 26)),
+            rounded_content_box(&rounded)
+        );
+
+        let icon = Rectangle::new(Point::new(20, 20), Size::new(5, 5));
+        let placed = align_inside_rounded(icon, &rounded, horizontal::Left, vertical::Top);
+        assert_eq!(Point::new(3, 2), placed.top_left);
+    }
+}
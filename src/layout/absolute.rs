@@ -0,0 +1,325 @@
+//! Absolute layout
+//!
+//! An [`AbsoluteLayout`] places every view at a position given relative to a bounding rectangle,
+//! e.g. as a percentage of the canvas exported from a design tool. The positions are only resolved
+//! when [`AbsoluteLayout::arrange_within`] is called, so the same layout can be placed into
+//! differently sized areas.
+//!
+//! Relative positions are [`Fraction`]s of the bounds, plain integers are converted as per-mille
+//! (1/1000) values. `0` is the first pixel of the bounds and `1000` is the last one, so a view anchored at its right edge to `1000` ends exactly at the
+//! right edge of the bounds. Fractional pixel positions are rounded to the nearest pixel.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_layout::{layout::absolute::{AbsoluteLayout, Position}, prelude::*};
+//! # use embedded_graphics::{geometry::AnchorPoint, prelude::*, primitives::Rectangle};
+//! let canvas = Rectangle::new(Point::zero(), Size::new(200, 100));
+//! let mut views = [
+//!     Rectangle::new(Point::zero(), Size::new(20, 10)),
+//!     Rectangle::new(Point::zero(), Size::new(20, 10)),
+//! ];
+//!
+//! let positions = [
+//!     // Centered
+//!     Position::anchored(AnchorPoint::Center, 500, 500),
+//!     // Top right corner
+//!     Position::anchored(AnchorPoint::TopRight, 1000, 0),
+//! ];
+//!
+//! let _ = AbsoluteLayout::new(Views::new(&mut views), &positions).arrange_within(canvas);
+//!
+//! assert_eq!(Point::new(91, 46), views[0].top_left);
+//! assert_eq!(Point::new(180, 0), views[1].top_left);
+//! ```
+//!
+//! [`Fraction`]: crate::math::Fraction
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::AnchorPoint,
+    prelude::{Dimensions, PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    math::Fraction,
+    theme::Restyle,
+    view_group::{Placeable, PositionBuffer, ViewGroup},
+    views::Revision,
+    View,
+};
+
+/// The position of a single view in an [`AbsoluteLayout`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Position {
+    /// Place the top left corner of the view to the given point, regardless of the bounds.
+    Absolute(Point),
+
+    /// Place the top left corner of the view at the given fractions of the bounds.
+    Relative {
+        /// Horizontal position, as a fraction of the width of the bounds.
+        x: Fraction,
+
+        /// Vertical position, as a fraction of the height of the bounds.
+        y: Fraction,
+    },
+
+    /// Place the `anchor` of the view at the given fractions of the bounds.
+    Anchored {
+        /// The point of the view to place.
+        anchor: AnchorPoint,
+
+        /// Horizontal position, as a fraction of the width of the bounds.
+        x: Fraction,
+
+        /// Vertical position, as a fraction of the height of the bounds.
+        y: Fraction,
+    },
+}
+
+impl Position {
+    /// Place the top left corner of the view at the given fractions of the bounds.
+    ///
+    /// Plain integers are per-mille values.
+    #[inline]
+    pub fn relative(x: impl Into<Fraction>, y: impl Into<Fraction>) -> Self {
+        Position::Relative {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    /// Place the `anchor` of the view at the given fractions of the bounds.
+    ///
+    /// Plain integers are per-mille values.
+    #[inline]
+    pub fn anchored(anchor: AnchorPoint, x: impl Into<Fraction>, y: impl Into<Fraction>) -> Self {
+        Position::Anchored {
+            anchor,
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    /// Returns the top left corner of a view with the given bounds, placed within `bounds`.
+    #[inline]
+    pub fn resolve(&self, view: Rectangle, bounds: Rectangle) -> Point {
+        match *self {
+            Position::Absolute(point) => point,
+            Position::Relative { x, y } => fraction_of(bounds, x, y),
+            Position::Anchored { anchor, x, y } => {
+                let anchor_offset = view.anchor_point(anchor) - view.top_left;
+                fraction_of(bounds, x, y) - anchor_offset
+            }
+        }
+    }
+}
+
+fn fraction_of(bounds: Rectangle, x: Fraction, y: Fraction) -> Point {
+    fn scale(extent: u32, fraction: Fraction) -> i32 {
+        fraction.scale_nearest(extent.saturating_sub(1)) as i32
+    }
+
+    bounds.top_left + Point::new(scale(bounds.size.width, x), scale(bounds.size.height, y))
+}
+
+fn place(positions: &[Position], views: &mut impl Placeable, bounds: Rectangle) {
+    for (idx, position) in positions.iter().enumerate().take(views.item_count()) {
+        let view = views.item_bounds(idx);
+        let top_left = position.resolve(view, bounds);
+
+        views.move_item(idx, top_left - view.top_left);
+    }
+}
+
+/// Places views at positions relative to a bounding rectangle.
+///
+/// For more information and an example see the [module level documentation](crate::layout::absolute).
+pub struct AbsoluteLayout<'a, VG> {
+    views: VG,
+    positions: &'a [Position],
+    revision: Revision,
+}
+
+impl<'a, VG> AbsoluteLayout<'a, VG>
+where
+    VG: ViewGroup,
+{
+    /// Create a new [`AbsoluteLayout`].
+    ///
+    /// The view at index `n` is placed using `positions[n]`. Views without a corresponding position
+    /// are not moved by the layout.
+    #[inline]
+    pub fn new(views: VG, positions: &'a [Position]) -> Self {
+        Self {
+            views,
+            positions,
+            revision: Revision::ZERO,
+        }
+    }
+
+    /// Place the views within `bounds`.
+    #[inline]
+    #[must_use]
+    pub fn arrange_within(mut self, bounds: Rectangle) -> Self {
+        place(self.positions, &mut self.views, bounds);
+        self.revision = self.revision.next();
+
+        self
+    }
+
+    /// Compute the positions that [`arrange_within`] would move `items` to, without moving them.
+    ///
+    /// The top left corner of `items[n]` is written to `positions[n]`. Items without a
+    /// corresponding [`Position`] keep their current top left corner. The views of the layout
+    /// itself are ignored, so the layout can be created with an [`EmptyViewGroup`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` and `positions` have different lengths.
+    ///
+    /// [`arrange_within`]: AbsoluteLayout::arrange_within
+    /// [`EmptyViewGroup`]: crate::view_group::EmptyViewGroup
+    #[inline]
+    pub fn arrange_positions_within<T>(
+        &self,
+        items: &[T],
+        positions: &mut [Point],
+        bounds: Rectangle,
+    ) where
+        T: Dimensions,
+    {
+        place(
+            self.positions,
+            &mut PositionBuffer::new(items, positions),
+            bounds,
+        );
+    }
+
+    /// Returns a reference to the contained views.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.views
+    }
+
+    /// Returns a mutable reference to the contained views.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.views
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    pub fn into_inner(self) -> VG {
+        self.views
+    }
+}
+
+impl<VG> View for AbsoluteLayout<'_, VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        View::translate_impl(&mut self.views, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        View::bounds(&self.views)
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.revision.combine(self.views.revision())
+    }
+}
+
+impl<S, VG> Restyle<S> for AbsoluteLayout<'_, VG>
+where
+    VG: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.views.restyle(style);
+    }
+}
+
+impl<C, VG> Drawable for AbsoluteLayout<'_, VG>
+where
+    C: PixelColor,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{prelude::*, view_group::EmptyViewGroup};
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn mixed_positions() {
+        let canvas = Rectangle::new(Point::new(10, 20), Size::new(200, 100));
+        let rect = Rectangle::new(Point::new(3, 3), Size::new(20, 10));
+        let mut views = [rect; 5];
+
+        let positions = [
+            Position::anchored(AnchorPoint::Center, 500, 500),
+            Position::anchored(AnchorPoint::TopRight, 1000, 0),
+            Position::relative(250, 333),
+            Position::Absolute(Point::new(-5, 7)),
+        ];
+
+        let layout = AbsoluteLayout::new(Views::new(&mut views), &positions).arrange_within(canvas);
+        let views = layout.into_inner();
+
+        // 199 * 0.5 = 99.5 is rounded to 100, the center of the view is at (9, 4)
+        assert_eq!(Point::new(10 + 91, 20 + 46), views[0].top_left);
+        // 199 * 1.0 = 199, the right edge of the view is at 19
+        assert_eq!(Point::new(10 + 180, 20), views[1].top_left);
+        // 199 * 0.25 = 49.75, 99 * 0.333 = 32.967
+        assert_eq!(Point::new(10 + 50, 20 + 33), views[2].top_left);
+        assert_eq!(Point::new(-5, 7), views[3].top_left);
+        // No position, not moved
+        assert_eq!(Point::new(3, 3), views[4].top_left);
+    }
+
+    #[test]
+    fn positions_match_arranged_views() {
+        let canvas = Rectangle::new(Point::new(10, 20), Size::new(200, 100));
+        let items = [
+            Rectangle::new(Point::new(3, 3), Size::new(20, 10)),
+            Rectangle::new(Point::new(0, 0), Size::new(5, 7)),
+            Rectangle::new(Point::new(-4, 1), Size::new(9, 9)),
+        ];
+        let positions = [
+            Position::anchored(AnchorPoint::BottomRight, 1000, 1000),
+            Position::relative(250, 333),
+        ];
+
+        let mut points = [Point::zero(); 3];
+        AbsoluteLayout::new(EmptyViewGroup, &positions).arrange_positions_within(
+            &items,
+            &mut points,
+            canvas,
+        );
+
+        let mut views = items;
+        let _ = AbsoluteLayout::new(Views::new(&mut views), &positions).arrange_within(canvas);
+
+        assert_eq!(views.map(|view| view.top_left), points);
+    }
+}
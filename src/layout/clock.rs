@@ -0,0 +1,159 @@
+//! Clock face positions
+//!
+//! Helpers for placing views around a dial, e.g. hour markers and numbers of a watch face. Angles
+//! are whole degrees, measured clockwise from 12 o'clock. Positions are computed with integer
+//! math, so they can be used without a floating point unit.
+//!
+//! ```rust
+//! # use embedded_layout::{layout::clock, prelude::*};
+//! # use embedded_graphics::{
+//! #     geometry::AnchorPoint,
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     primitives::{Circle, PrimitiveStyle},
+//! #     text::Text,
+//! # };
+//! let center = Point::new(64, 32);
+//!
+//! // A dot for every hour, 12 o'clock first
+//! let dot = PrimitiveStyle::with_fill(BinaryColor::On);
+//! let markers = clock::hour_positions(center, 28)
+//!     .map(|position| Circle::with_center(position, 3).into_styled(dot));
+//!
+//! // A label centered at 3 o'clock, inside the markers
+//! let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//! let three = clock::align_at_angle(
+//!     Text::new("3", Point::zero(), style),
+//!     center,
+//!     20,
+//!     90,
+//!     AnchorPoint::Center,
+//! );
+//! # assert_eq!(Point::new(92, 32), markers[3].primitive.center());
+//! ```
+
+use embedded_graphics::{geometry::AnchorPoint, prelude::Point};
+
+use crate::{utils::trig::point_on_circle, View};
+
+/// Returns the positions of the 12 hours on a dial of the given `radius` around `center`.
+///
+/// The first position is 12 o'clock, followed by the others in clockwise order.
+#[inline]
+pub fn hour_positions(center: Point, radius: u32) -> [Point; 12] {
+    let mut positions = [center; 12];
+    for (hour, position) in positions.iter_mut().enumerate() {
+        *position = point_on_circle(center, radius, hour as i32 * 30);
+    }
+
+    positions
+}
+
+/// Returns the positions of the 60 minutes on a dial of the given `radius` around `center`.
+///
+/// The first position is 12 o'clock, followed by the others in clockwise order.
+#[inline]
+pub fn minute_positions(center: Point, radius: u32) -> [Point; 60] {
+    let mut positions = [center; 60];
+    for (minute, position) in positions.iter_mut().enumerate() {
+        *position = point_on_circle(center, radius, minute as i32 * 6);
+    }
+
+    positions
+}
+
+/// Move `view` so that its `anchor` is at `angle` degrees on the circle of the given `radius`
+/// around `center`.
+///
+/// An angle of 0 points to 12 o'clock, and angles grow clockwise. With a `radius` of 0, this
+/// places the view at `center`, e.g. to anchor the end of a clock hand.
+#[inline]
+pub fn align_at_angle<V>(
+    mut view: V,
+    center: Point,
+    radius: u32,
+    angle: i32,
+    anchor: AnchorPoint,
+) -> V
+where
+    V: View,
+{
+    let target = point_on_circle(center, radius, angle);
+    let by = target - view.bounds().anchor_point(anchor);
+    view.translate_mut(by);
+
+    view
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{prelude::Size, primitives::Rectangle};
+
+    fn assert_close(expected: Point, center: Point, radius: u32, angle: i32) {
+        let radians = f64::from(angle).to_radians();
+        let x = f64::from(center.x) + f64::from(radius) * radians.sin();
+        let y = f64::from(center.y) - f64::from(radius) * radians.cos();
+
+        assert!(
+            (f64::from(expected.x) - x).abs() <= 1.0 && (f64::from(expected.y) - y).abs() <= 1.0,
+            "angle {}, radius {}: {:?} is not within 1px of ({}, {})",
+            angle,
+            radius,
+            expected,
+            x,
+            y
+        );
+    }
+
+    #[test]
+    fn hours_match_reference() {
+        let center = Point::new(64, 32);
+        for radius in [0, 1, 10, 31, 100, 1000] {
+            for (hour, position) in hour_positions(center, radius).iter().enumerate() {
+                assert_close(*position, center, radius, hour as i32 * 30);
+            }
+        }
+    }
+
+    #[test]
+    fn minutes_match_reference() {
+        let center = Point::new(-5, 7);
+        for radius in [0, 1, 10, 31, 100, 1000] {
+            for (minute, position) in minute_positions(center, radius).iter().enumerate() {
+                assert_close(*position, center, radius, minute as i32 * 6);
+            }
+        }
+    }
+
+    #[test]
+    fn minutes_include_hours() {
+        let hours = hour_positions(Point::zero(), 50);
+        let minutes = minute_positions(Point::zero(), 50);
+
+        for (hour, position) in hours.iter().enumerate() {
+            assert_eq!(*position, minutes[hour * 5]);
+        }
+        assert_eq!(Point::new(0, -50), hours[0]);
+        assert_eq!(Point::new(50, 0), hours[3]);
+    }
+
+    #[test]
+    fn align_anchor_at_angle() {
+        let view = Rectangle::new(Point::new(100, 100), Size::new(5, 3));
+
+        assert_eq!(
+            Rectangle::new(Point::new(8, -1), Size::new(5, 3)),
+            align_at_angle(view, Point::zero(), 10, 90, AnchorPoint::Center)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(-4, 10), Size::new(5, 3)),
+            align_at_angle(view, Point::zero(), 10, 180, AnchorPoint::TopRight)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(0, 0), Size::new(5, 3)),
+            align_at_angle(view, Point::zero(), 0, 45, AnchorPoint::TopLeft)
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Constraint based placement
+//!
+//! Some screens are easier to describe as a list of rules ("the icon's left edge is 4 pixels from
+//! the panel's left edge") than as nested layouts. A [`Constraint`] places an edge or the center
+//! of a view relative to an edge or the center of an other view, or of a bounding rectangle.
+//!
+//! [`solve`] applies the constraints in a single pass, in the order they are given. There is no
+//! general constraint solving: a constraint only moves its target view along one axis, and it
+//! sees the positions produced by the constraints before it. If multiple constraints move the same
+//! view along the same axis, the last one wins. Axes without constraints keep their position.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_layout::{
+//! #     layout::constraint::{solve, Constraint, Reference},
+//! #     prelude::*,
+//! # };
+//! # use embedded_graphics::{
+//! #     geometry::{AnchorX, AnchorY},
+//! #     prelude::*,
+//! #     primitives::Rectangle,
+//! # };
+//! let panel = Rectangle::new(Point::zero(), Size::new(64, 32));
+//! let rect = Rectangle::new(Point::zero(), Size::new(8, 8));
+//! let mut views = [rect, rect];
+//!
+//! solve(
+//!     &mut Views::new(&mut views),
+//!     panel,
+//!     &[
+//!         // views[0].left = panel.left + 4
+//!         Constraint::Horizontal {
+//!             target: 0,
+//!             anchor: AnchorX::Left,
+//!             reference: Reference::Bounds,
+//!             reference_anchor: AnchorX::Left,
+//!             offset: 4,
+//!         },
+//!         // views[1].right = panel.right - 4
+//!         Constraint::Horizontal {
+//!             target: 1,
+//!             anchor: AnchorX::Right,
+//!             reference: Reference::Bounds,
+//!             reference_anchor: AnchorX::Right,
+//!             offset: -4,
+//!         },
+//!     ],
+//! );
+//!
+//! assert_eq!(Point::new(4, 0), views[0].top_left);
+//! assert_eq!(Point::new(52, 0), views[1].top_left);
+//! ```
+use embedded_graphics::{
+    geometry::{AnchorX, AnchorY},
+    prelude::Point,
+    primitives::Rectangle,
+};
+
+use crate::view_group::ViewGroup;
+
+/// The object a [`Constraint`] is relative to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Reference {
+    /// The view at the given index of the view group.
+    View(usize),
+
+    /// The bounding rectangle passed to [`solve`].
+    Bounds,
+}
+
+/// A rule that places an edge or the center of a view.
+///
+/// See the [module level documentation](crate::layout::constraint) for more information.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    /// Move view `target` horizontally so that `target.anchor = reference.reference_anchor + offset`
+    Horizontal {
+        /// The index of the view to move.
+        target: usize,
+
+        /// The edge or center of the target view.
+        anchor: AnchorX,
+
+        /// The object to place the target view relative to.
+        reference: Reference,
+
+        /// The edge or center of the reference object.
+        reference_anchor: AnchorX,
+
+        /// The distance between the two anchors, in pixels.
+        offset: i32,
+    },
+
+    /// Move view `target` vertically so that `target.anchor = reference.reference_anchor + offset`
+    Vertical {
+        /// The index of the view to move.
+        target: usize,
+
+        /// The edge or center of the target view.
+        anchor: AnchorY,
+
+        /// The object to place the target view relative to.
+        reference: Reference,
+
+        /// The edge or center of the reference object.
+        reference_anchor: AnchorY,
+
+        /// The distance between the two anchors, in pixels.
+        offset: i32,
+    },
+}
+
+/// Apply `constraints` to the views of `view_group`, in order.
+///
+/// `bounds` is the rectangle referenced by [`Reference::Bounds`].
+///
+/// # Panics
+///
+/// Panics if a constraint refers to a view index that is out of bounds.
+#[inline]
+pub fn solve(view_group: &mut impl ViewGroup, bounds: Rectangle, constraints: &[Constraint]) {
+    for constraint in constraints {
+        match *constraint {
+            Constraint::Horizontal {
+                target,
+                anchor,
+                reference,
+                reference_anchor,
+                offset,
+            } => {
+                let reference = reference_bounds(view_group, bounds, reference);
+                let current = view_group.bounds_of(target).anchor_x(anchor);
+                let by = reference.anchor_x(reference_anchor) + offset - current;
+
+                view_group.translate_child(target, Point::new(by, 0));
+            }
+            Constraint::Vertical {
+                target,
+                anchor,
+                reference,
+                reference_anchor,
+                offset,
+            } => {
+                let reference = reference_bounds(view_group, bounds, reference);
+                let current = view_group.bounds_of(target).anchor_y(anchor);
+                let by = reference.anchor_y(reference_anchor) + offset - current;
+
+                view_group.translate_child(target, Point::new(0, by));
+            }
+        }
+    }
+}
+
+fn reference_bounds(
+    view_group: &impl ViewGroup,
+    bounds: Rectangle,
+    reference: Reference,
+) -> Rectangle {
+    match reference {
+        Reference::View(idx) => view_group.bounds_of(idx),
+        Reference::Bounds => bounds,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::prelude::Size;
+
+    fn left_to(target: usize, reference: Reference, reference_anchor: AnchorX) -> Constraint {
+        Constraint::Horizontal {
+            target,
+            anchor: AnchorX::Left,
+            reference,
+            reference_anchor,
+            offset: 0,
+        }
+    }
+
+    #[test]
+    fn constraints_reproduce_linear_layout() {
+        let mut views = [
+            Rectangle::new(Point::new(3, 3), Size::new(5, 3)),
+            Rectangle::new(Point::new(-5, 0), Size::new(8, 2)),
+            Rectangle::new(Point::new(7, 1), Size::new(2, 4)),
+        ];
+        let mut expected = views;
+
+        let bounds = Rectangle::new(Point::zero(), Size::new(20, 20));
+        let top_to_bottom = |target: usize| Constraint::Vertical {
+            target,
+            anchor: AnchorY::Top,
+            reference: Reference::View(target - 1),
+            reference_anchor: AnchorY::Bottom,
+            offset: 1,
+        };
+
+        solve(
+            &mut Views::new(&mut views),
+            bounds,
+            &[
+                left_to(0, Reference::Bounds, AnchorX::Left),
+                Constraint::Vertical {
+                    target: 0,
+                    anchor: AnchorY::Top,
+                    reference: Reference::Bounds,
+                    reference_anchor: AnchorY::Top,
+                    offset: 0,
+                },
+                left_to(1, Reference::View(0), AnchorX::Left),
+                top_to_bottom(1),
+                left_to(2, Reference::View(1), AnchorX::Left),
+                top_to_bottom(2),
+            ],
+        );
+
+        let _ = LinearLayout::vertical(Views::new(&mut expected)).arrange();
+
+        assert_eq!(expected, views);
+    }
+
+    #[test]
+    fn asymmetric_constraints() {
+        let panel = Rectangle::new(Point::new(10, 10), Size::new(50, 30));
+        let mut views = [
+            Rectangle::new(Point::zero(), Size::new(10, 10)),
+            Rectangle::new(Point::zero(), Size::new(6, 4)),
+        ];
+
+        solve(
+            &mut Views::new(&mut views),
+            panel,
+            &[
+                Constraint::Horizontal {
+                    target: 0,
+                    anchor: AnchorX::Left,
+                    reference: Reference::Bounds,
+                    reference_anchor: AnchorX::Left,
+                    offset: 4,
+                },
+                Constraint::Vertical {
+                    target: 0,
+                    anchor: AnchorY::Bottom,
+                    reference: Reference::Bounds,
+                    reference_anchor: AnchorY::Bottom,
+                    offset: -2,
+                },
+                Constraint::Horizontal {
+                    target: 1,
+                    anchor: AnchorX::Right,
+                    reference: Reference::Bounds,
+                    reference_anchor: AnchorX::Right,
+                    offset: -4,
+                },
+                Constraint::Vertical {
+                    target: 1,
+                    anchor: AnchorY::Center,
+                    reference: Reference::View(0),
+                    reference_anchor: AnchorY::Center,
+                    offset: 0,
+                },
+            ],
+        );
+
+        assert_eq!(Point::new(14, 28), views[0].top_left);
+        assert_eq!(Point::new(50, 31), views[1].top_left);
+    }
+
+    #[test]
+    fn last_constraint_wins() {
+        let mut views = [Rectangle::new(Point::new(5, 5), Size::new(4, 4))];
+        let bounds = Rectangle::new(Point::zero(), Size::new(20, 20));
+
+        solve(
+            &mut Views::new(&mut views),
+            bounds,
+            &[
+                left_to(0, Reference::Bounds, AnchorX::Left),
+                left_to(0, Reference::Bounds, AnchorX::Center),
+            ],
+        );
+
+        // The vertical position is not constrained
+        assert_eq!(Point::new(9, 5), views[0].top_left);
+    }
+}
@@ -0,0 +1,408 @@
+//! Grid layout
+//!
+//! A [`GridLayout`] places views into the cells of a grid, in row-major order. Every column is as
+//! wide as its widest view, and every row is as tall as its tallest view. Views are placed at the
+//! top left corner of their cell.
+//!
+//! The space between the rows and the space between the columns are set independently, using any
+//! [`Arrangement`], e.g. an [`ElementSpacing`]. The row spacing places the rows as if they were the
+//! views of a vertical [`LinearLayout`] and the column spacing places the columns like a horizontal
+//! one, so e.g. [`DistributeFill`] spreads the columns to fill a given width.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_layout::{
+//! #     layout::{grid::GridLayout, linear::spacing::{DistributeFill, FixedMargin}},
+//! #     prelude::*,
+//! # };
+//! # use embedded_graphics::{prelude::*, primitives::Rectangle};
+//! let mut keys = [Rectangle::new(Point::zero(), Size::new(10, 8)); 6];
+//!
+//! let keypad = GridLayout::new(Views::new(&mut keys), 3)
+//!     .with_row_spacing(FixedMargin(2))
+//!     .with_column_spacing(DistributeFill(40))
+//!     .arrange();
+//!
+//! assert_eq!(Size::new(40, 18), keypad.size());
+//! assert_eq!(Point::new(15, 10), keypad.cell_origin(1, 1));
+//! assert_eq!(Point::new(30, 10), keys[5].top_left);
+//! ```
+//!
+//! [`Arrangement`]: crate::layout::linear::Arrangement
+//! [`ElementSpacing`]: crate::layout::linear::spacing::ElementSpacing
+//! [`LinearLayout`]: crate::layout::linear::LinearLayout
+//! [`DistributeFill`]: crate::layout::linear::spacing::DistributeFill
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    layout::linear::{spacing::Tight, Arrangement, Placer},
+    theme::Restyle,
+    view_group::ViewGroup,
+    views::Revision,
+    View,
+};
+
+/// Places views into the cells of a grid.
+///
+/// For more information and an example see the [module level documentation](crate::layout::grid).
+pub struct GridLayout<VG, RS = Tight, CS = Tight> {
+    position: Point,
+    views: VG,
+    columns: usize,
+    row_spacing: RS,
+    column_spacing: CS,
+    revision: Revision,
+}
+
+impl<VG> GridLayout<VG>
+where
+    VG: ViewGroup,
+{
+    /// Create a new [`GridLayout`] with the given number of columns.
+    ///
+    /// The number of rows follows from the number of views. The rows and columns are placed tightly
+    /// by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is 0.
+    #[inline]
+    #[must_use]
+    pub fn new(views: VG, columns: usize) -> Self {
+        assert!(columns > 0, "a grid needs at least one column");
+
+        Self {
+            position: Point::zero(),
+            views,
+            columns,
+            row_spacing: Tight,
+            column_spacing: Tight,
+            revision: Revision::ZERO,
+        }
+    }
+}
+
+impl<VG, RS, CS> GridLayout<VG, RS, CS>
+where
+    VG: ViewGroup,
+    RS: Arrangement,
+    CS: Arrangement,
+{
+    /// Change the spacing between the rows.
+    ///
+    /// The spacing sees the rows as views as tall as their tallest view.
+    #[inline]
+    #[must_use]
+    pub fn with_row_spacing<S>(self, spacing: S) -> GridLayout<VG, S, CS>
+    where
+        S: Arrangement,
+    {
+        GridLayout {
+            position: self.position,
+            views: self.views,
+            columns: self.columns,
+            row_spacing: spacing,
+            column_spacing: self.column_spacing,
+            revision: self.revision,
+        }
+    }
+
+    /// Change the spacing between the columns.
+    ///
+    /// The spacing sees the columns as views as wide as their widest view.
+    #[inline]
+    #[must_use]
+    pub fn with_column_spacing<S>(self, spacing: S) -> GridLayout<VG, RS, S>
+    where
+        S: Arrangement,
+    {
+        GridLayout {
+            position: self.position,
+            views: self.views,
+            columns: self.columns,
+            row_spacing: self.row_spacing,
+            column_spacing: spacing,
+            revision: self.revision,
+        }
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        (self.views.len() + self.columns - 1) / self.columns
+    }
+
+    /// Returns the top left corner of the cell in the given `row` and `column`.
+    #[inline]
+    pub fn cell_origin(&self, row: usize, column: usize) -> Point {
+        let x = self.column_offsets().nth(column).unwrap_or(0);
+        let y = self.row_offsets().nth(row).unwrap_or(0);
+
+        self.position + Point::new(x, y)
+    }
+
+    /// Arrange the views into the cells of the grid.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        let (columns, rows) = (self.columns, self.rows());
+        let len = self.views.len();
+
+        let occupied = self.column_widths().sum();
+        let mut placer = Placer::new(self.column_spacing, columns, occupied, None);
+        for column in 0..columns {
+            let x = self.position.x + placer.place(self.column_width(column));
+            for idx in (column..len).step_by(columns) {
+                let by = x - self.views.bounds_of(idx).top_left.x;
+                self.views.translate_child(idx, Point::new(by, 0));
+            }
+        }
+
+        let occupied = self.row_heights().sum();
+        let mut placer = Placer::new(self.row_spacing, rows, occupied, None);
+        for row in 0..rows {
+            let y = self.position.y + placer.place(self.row_height(row));
+            for idx in row * columns..((row + 1) * columns).min(len) {
+                let by = y - self.views.bounds_of(idx).top_left.y;
+                self.views.translate_child(idx, Point::new(0, by));
+            }
+        }
+        self.revision = self.revision.next();
+
+        self
+    }
+
+    /// Returns a reference to the contained views.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.views
+    }
+
+    /// Returns a mutable reference to the contained views.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.views
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    pub fn into_inner(self) -> VG {
+        self.views
+    }
+
+    fn column_width(&self, column: usize) -> u32 {
+        (column..self.views.len())
+            .step_by(self.columns)
+            .map(|idx| self.views.bounds_of(idx).size.width)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn row_height(&self, row: usize) -> u32 {
+        let first = row * self.columns;
+        let last = (first + self.columns).min(self.views.len());
+
+        (first..last)
+            .map(|idx| self.views.bounds_of(idx).size.height)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn column_widths(&self) -> impl Iterator<Item = u32> + Clone + '_ {
+        (0..self.columns).map(move |column| self.column_width(column))
+    }
+
+    fn row_heights(&self) -> impl Iterator<Item = u32> + Clone + '_ {
+        (0..self.rows()).map(move |row| self.row_height(row))
+    }
+
+    /// Returns the positions of the columns, relative to the position of the grid.
+    fn column_offsets(&self) -> impl Iterator<Item = i32> + '_ {
+        self.column_spacing.place(self.column_widths(), None)
+    }
+
+    /// Returns the positions of the rows, relative to the position of the grid.
+    fn row_offsets(&self) -> impl Iterator<Item = i32> + '_ {
+        self.row_spacing.place(self.row_heights(), None)
+    }
+
+    fn grid_size(&self) -> Size {
+        let (width, height) = if self.views.len() == 0 {
+            (0, 0)
+        } else {
+            let width = self.column_offsets().zip(self.column_widths()).last();
+            let height = self.row_offsets().zip(self.row_heights()).last();
+            let end = |(offset, extent): (i32, u32)| (offset + extent as i32).max(0) as u32;
+
+            (width.map_or(0, end), height.map_or(0, end))
+        };
+
+        Size::new(
+            width.max(self.column_spacing.track_size().unwrap_or(0)),
+            height.max(self.row_spacing.track_size().unwrap_or(0)),
+        )
+    }
+}
+
+impl<VG, RS, CS> View for GridLayout<VG, RS, CS>
+where
+    VG: ViewGroup,
+    RS: Arrangement,
+    CS: Arrangement,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        View::translate_impl(&mut self.views, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.position, self.grid_size())
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.revision.combine(self.views.revision())
+    }
+}
+
+impl<S, VG, RS, CS> Restyle<S> for GridLayout<VG, RS, CS>
+where
+    VG: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.views.restyle(style);
+    }
+}
+
+impl<C, VG, RS, CS> Drawable for GridLayout<VG, RS, CS>
+where
+    C: PixelColor,
+    VG: ViewGroup + Drawable<Color = C>,
+    RS: Arrangement,
+    CS: Arrangement,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::spacing::{DistributeFill, FixedMargin},
+        prelude::*,
+    };
+
+    fn cells(sizes: &[(u32, u32)]) -> Vec<Rectangle> {
+        sizes
+            .iter()
+            .enumerate()
+            .map(|(i, &(width, height))| {
+                Rectangle::new(Point::new(i as i32, -3), Size::new(width, height))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn independent_row_and_column_spacing() {
+        // Columns are 8, 6 and 5 pixels wide, rows are 5 and 6 pixels tall
+        let mut views = cells(&[(4, 3), (6, 5), (5, 2), (8, 4), (3, 3), (2, 6)]);
+
+        let grid = GridLayout::new(Views::new(&mut views), 3)
+            .with_row_spacing(FixedMargin(2))
+            .with_column_spacing(DistributeFill(40))
+            .arrange();
+
+        assert_eq!((2, 3), (grid.rows(), grid.columns()));
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(40, 13)),
+            grid.bounds()
+        );
+
+        // The 21 free pixels between the columns are split into gaps of 11 and 10 pixels
+        let origins = [
+            Point::new(0, 0),
+            Point::new(19, 0),
+            Point::new(35, 0),
+            Point::new(0, 7),
+            Point::new(19, 7),
+            Point::new(35, 7),
+        ];
+        for (idx, origin) in origins.iter().enumerate() {
+            assert_eq!(*origin, grid.cell_origin(idx / 3, idx % 3));
+            assert_eq!(*origin, grid.inner().bounds_of(idx).top_left);
+        }
+
+        // Swapping the spacings swaps the roles
+        let mut views = cells(&[(4, 3), (6, 5), (5, 2), (8, 4), (3, 3), (2, 6)]);
+        let grid = GridLayout::new(Views::new(&mut views), 3)
+            .with_row_spacing(DistributeFill(20))
+            .with_column_spacing(FixedMargin(2))
+            .arrange();
+
+        assert_eq!(Size::new(23, 20), grid.size());
+        assert_eq!(Point::new(10, 14), grid.cell_origin(1, 1));
+    }
+
+    #[test]
+    fn incomplete_last_row() {
+        let mut views = cells(&[(2, 2), (3, 1), (4, 4), (1, 5), (2, 2)]);
+
+        let grid = GridLayout::new(Views::new(&mut views), 2)
+            .arrange()
+            .translate(Point::new(10, 20));
+
+        assert_eq!(3, grid.rows());
+        assert_eq!(
+            Rectangle::new(Point::new(10, 20), Size::new(7, 9)),
+            grid.bounds()
+        );
+        assert_eq!(Point::new(14, 22), grid.cell_origin(1, 1));
+        assert_eq!(Point::new(10, 27), views[4].top_left);
+    }
+
+    #[test]
+    fn empty_grid() {
+        let mut views: [Rectangle; 0] = [];
+
+        let grid = GridLayout::new(Views::new(&mut views), 3)
+            .with_column_spacing(DistributeFill(30))
+            .arrange();
+
+        assert_eq!(0, grid.rows());
+        assert_eq!(Size::new(30, 0), grid.size());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_columns() {
+        let mut views = [Rectangle::zero()];
+
+        let _ = GridLayout::new(Views::new(&mut views), 0);
+    }
+}
@@ -0,0 +1,391 @@
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+use crate::{
+    align::horizontal,
+    layout::linear::{spacing::ElementSpacing, Justify},
+};
+
+/// A view on a line, together with everything an [`Arrangement`] needs to know to place it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Slot {
+    /// The index of the view.
+    pub n: usize,
+
+    /// The number of views on the line.
+    pub count: usize,
+
+    /// The extent of the view along the line.
+    pub extent: u32,
+
+    /// The sum of the extents of all views on the line.
+    pub occupied: u32,
+
+    /// The position of the previous view, relative to the start of the line.
+    ///
+    /// `0` for the first view.
+    pub previous_start: i32,
+
+    /// The extent of the previous view along the line.
+    ///
+    /// `0` for the first view.
+    pub previous_extent: u32,
+
+    /// The length of the line, if it has one.
+    pub track: Option<u32>,
+
+    /// The [leading margin] of the view.
+    ///
+    /// [leading margin]: crate::layout::linear::spacing::HasMargins::leading_margin
+    pub leading_margin: i32,
+
+    /// The [trailing margin] of the previous view.
+    ///
+    /// `0` for the first view.
+    ///
+    /// [trailing margin]: crate::layout::linear::spacing::HasMargins::trailing_margin
+    pub previous_trailing_margin: i32,
+}
+
+/// A strategy that places views along a line, knowing the sizes of all of them.
+///
+/// An arrangement is asked for the position of one view at a time, but each [`Slot`] describes
+/// the whole line: the number of views, the space they occupy together and the length of the
+/// line, if there is one. This is what [`LinearLayout`] uses to place its views along the primary
+/// axis, and [`place`] computes the same positions from a list of extents, without any views.
+///
+/// Every [`ElementSpacing`] is an `Arrangement` that places each view relative to the one before
+/// it, and [`Justify`] distributes the views within the track.
+///
+/// # Example
+///
+/// ```
+/// use embedded_layout::layout::linear::{spacing::FixedMargin, Arrangement, Justify};
+///
+/// let rows = [10, 20, 10];
+///
+/// let mut offsets = [0; 3];
+/// for (offset, placed) in offsets.iter_mut().zip(FixedMargin(2).place(rows.iter().copied(), None)) {
+///     *offset = placed;
+/// }
+/// assert_eq!([0, 12, 34], offsets);
+///
+/// for (offset, placed) in offsets.iter_mut().zip(Justify::SpaceBetween.place(rows.iter().copied(), Some(60))) {
+///     *offset = placed;
+/// }
+/// assert_eq!([0, 20, 50], offsets);
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`place`]: Arrangement::place
+pub trait Arrangement: Copy {
+    /// Returns the position of the view in `slot`, relative to the start of the line.
+    fn position(&self, slot: Slot) -> i32;
+
+    /// Returns the length of the line, if the arrangement defines one.
+    #[inline]
+    fn track_size(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns `true` if the arrangement needs to know the length of the line.
+    #[inline]
+    fn requires_total_size(&self) -> bool {
+        false
+    }
+
+    /// Returns the position of each view with the given `extents`, within `track`.
+    ///
+    /// Without a `track`, the arrangement uses its own [`track_size`], if it has one.
+    ///
+    /// [`track_size`]: Arrangement::track_size
+    #[inline]
+    fn place<I>(&self, extents: I, track: Option<u32>) -> PlannedOffsets<I, Self>
+    where
+        I: Iterator<Item = u32> + Clone,
+    {
+        let (count, occupied) = extents
+            .clone()
+            .fold((0, 0), |(count, total), extent| (count + 1, total + extent));
+
+        PlannedOffsets {
+            extents,
+            placer: Placer::new(*self, count, occupied, track.or_else(|| self.track_size())),
+        }
+    }
+}
+
+/// Every [`ElementSpacing`] places views one after the other, modeled as one pixel high
+/// rectangles in a horizontal layout.
+impl<S> Arrangement for S
+where
+    S: ElementSpacing,
+{
+    #[inline]
+    fn position(&self, slot: Slot) -> i32 {
+        let view = segment(0, slot.extent);
+
+        if slot.n == 0 {
+            let line = segment(slot.previous_start, slot.occupied);
+            self.align(horizontal::Left, view, line, 0, slot.count, slot.occupied)
+        } else {
+            self.align_after(
+                horizontal::LeftToRight,
+                view,
+                segment(slot.previous_start, slot.previous_extent),
+                slot.previous_extent,
+                slot.n,
+                slot.count,
+                slot.occupied,
+            )
+        }
+    }
+
+    #[inline]
+    fn track_size(&self) -> Option<u32> {
+        ElementSpacing::track_size(self)
+    }
+
+    #[inline]
+    fn requires_total_size(&self) -> bool {
+        ElementSpacing::requires_total_size(self)
+    }
+}
+
+fn segment(start: i32, extent: u32) -> Rectangle {
+    Rectangle::new(Point::new(start, 0), Size::new(extent, 1))
+}
+
+/// Place the views next to each other within the track, see [`LinearLayout::justify`].
+///
+/// Justifying views requires a track, e.g. set by [`LinearLayout::with_span`]. Without one, there
+/// is no free space to distribute, so [`place`] places the views tightly.
+///
+/// [`LinearLayout::justify`]: crate::layout::linear::LinearLayout::justify
+/// [`LinearLayout::with_span`]: crate::layout::linear::LinearLayout::with_span
+/// [`place`]: Arrangement::place
+impl Arrangement for Justify {
+    #[inline]
+    fn requires_total_size(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn position(&self, slot: Slot) -> i32 {
+        let free = slot
+            .track
+            .map_or(0, |track| track as i32 - slot.occupied as i32);
+        let before = self.space_before(free, slot.n, slot.count);
+
+        if slot.n == 0 {
+            before
+        } else {
+            let after_previous = slot.previous_start + slot.previous_extent as i32;
+            after_previous + before - self.space_before(free, slot.n - 1, slot.count)
+        }
+    }
+}
+
+/// Iterator over the view positions computed by [`Arrangement::place`].
+#[derive(Clone)]
+pub struct PlannedOffsets<I, A> {
+    extents: I,
+    placer: Placer<A>,
+}
+
+impl<I, A> Iterator for PlannedOffsets<I, A>
+where
+    I: Iterator<Item = u32>,
+    A: Arrangement,
+{
+    type Item = i32;
+
+    #[inline]
+    fn next(&mut self) -> Option<i32> {
+        let extent = self.extents.next()?;
+
+        Some(self.placer.place(extent))
+    }
+}
+
+/// Places views one at a time, for callers that move the views while they are placed.
+#[derive(Copy, Clone)]
+pub(crate) struct Placer<A> {
+    arrangement: A,
+    n: usize,
+    count: usize,
+    occupied: u32,
+    track: Option<u32>,
+    previous_start: i32,
+    previous_extent: u32,
+}
+
+impl<A> Placer<A>
+where
+    A: Arrangement,
+{
+    /// Creates a placer for `count` views that occupy `occupied` pixels together.
+    pub(crate) fn new(arrangement: A, count: usize, occupied: u32, track: Option<u32>) -> Self {
+        Self {
+            arrangement,
+            n: 0,
+            count,
+            occupied,
+            track,
+            previous_start: 0,
+            previous_extent: 0,
+        }
+    }
+
+    /// Returns the position of the next view, which has the given `extent`.
+    pub(crate) fn place(&mut self, extent: u32) -> i32 {
+        let position = self.arrangement.position(Slot {
+            n: self.n,
+            count: self.count,
+            extent,
+            occupied: self.occupied,
+            previous_start: self.previous_start,
+            previous_extent: self.previous_extent,
+            track: self.track,
+            leading_margin: 0,
+            previous_trailing_margin: 0,
+        });
+
+        self.previous_start = position;
+        self.previous_extent = extent;
+        self.n += 1;
+
+        position
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::{
+            spacing::{
+                ClosureSpacing, DistributeFill, FixedMargin, FixedSizes, ProportionalGap, Tight,
+            },
+            LinearLayout,
+        },
+        prelude::*,
+    };
+
+    const HEIGHTS: [u32; 4] = [12, 3, 7, 12];
+
+    fn rows() -> [Rectangle; 4] {
+        HEIGHTS.map(|height| Rectangle::new(Point::zero(), Size::new(5, height)))
+    }
+
+    fn assert_placed(arrangement: impl Arrangement, expected: [i32; 4]) {
+        let mut placed = [0; 4];
+        let mut count = 0;
+        for (offset, position) in placed
+            .iter_mut()
+            .zip(arrangement.place(HEIGHTS.iter().copied(), Some(50)))
+        {
+            *offset = position;
+            count += 1;
+        }
+
+        assert_eq!(4, count);
+        assert_eq!(expected, placed);
+    }
+
+    /// Places `views` like linear layouts did before they were placed by an [`Arrangement`]: each
+    /// view is aligned to the real bounds of the one before it.
+    fn place_by_spacing(spacing: impl ElementSpacing, views: &mut [Rectangle]) {
+        let count = views.len();
+        let total = views.iter().map(|view| view.size.width).sum();
+        let mut previous = Rectangle::new(Point::new(4, 2), Size::new(total, 9));
+
+        for n in 0..count {
+            let view = views[n];
+            let x = if n == 0 {
+                spacing.align(horizontal::Left, view, previous, n, count, total)
+            } else {
+                spacing.align_after(
+                    horizontal::LeftToRight,
+                    view,
+                    previous,
+                    previous.size.width,
+                    n,
+                    count,
+                    total,
+                )
+            };
+
+            views[n] = view.translate(Point::new(x, 0));
+            previous = views[n];
+        }
+    }
+
+    fn assert_spacing_equivalent(spacing: impl ElementSpacing) {
+        let mut views = rows();
+        let _ = LinearLayout::vertical(Views::new(&mut views))
+            .with_spacing(spacing)
+            .arrange();
+
+        assert_placed(spacing, views.map(|view| view.top_left.y));
+
+        let columns = HEIGHTS.map(|width| Rectangle::new(Point::zero(), Size::new(width, 3)));
+        let mut expected = columns;
+        place_by_spacing(spacing, &mut expected);
+
+        let mut views = columns;
+        let _ = LinearLayout::horizontal(Views::new(&mut views))
+            .with_alignment(vertical::Top)
+            .with_spacing(spacing)
+            .arrange();
+
+        let by = expected[0].top_left - views[0].top_left;
+        assert_eq!(expected, views.map(|view| view.translate(by)));
+    }
+
+    #[test]
+    fn spacings_place_like_layouts() {
+        assert_spacing_equivalent(Tight);
+        assert_spacing_equivalent(FixedMargin(3));
+        assert_spacing_equivalent(FixedMargin(-2));
+        assert_spacing_equivalent(ClosureSpacing(|n| n as i32));
+        assert_spacing_equivalent(DistributeFill(50));
+        assert_spacing_equivalent(ProportionalGap::new(500));
+        assert_spacing_equivalent(FixedSizes(&[15, 4]));
+    }
+
+    #[test]
+    fn justify_places_like_layouts() {
+        for mode in [
+            Justify::Start,
+            Justify::Center,
+            Justify::End,
+            Justify::SpaceBetween,
+            Justify::SpaceAround,
+        ] {
+            let mut views = rows();
+            let _ = LinearLayout::vertical(Views::new(&mut views))
+                .arrange()
+                .justify(50, mode);
+
+            assert_placed(mode, views.map(|view| view.top_left.y));
+
+            let mut spaced = rows();
+            let _ = LinearLayout::vertical(Views::new(&mut spaced))
+                .with_spacing(mode)
+                .with_span(50)
+                .arrange();
+
+            assert_eq!(views, spaced);
+        }
+    }
+
+    #[test]
+    fn justify_without_track_is_tight() {
+        let offsets = Justify::SpaceAround.place(HEIGHTS.iter().copied(), None);
+
+        assert!(offsets.eq([0, 12, 15, 22]));
+    }
+}
@@ -0,0 +1,195 @@
+/// Declares nested linear layouts.
+///
+/// `layout!` expands to the same [`LinearLayout`] and [`Chain`] construction that would be written
+/// by hand, so it has no runtime cost. A layout is declared as `vertical` or `horizontal`, with
+/// optional `spacing` and `align` (secondary alignment) options, followed by its views in braces.
+/// A view is either an expression or a nested layout.
+///
+/// Nested layouts are arranged when they are created, the outermost layout is returned without
+/// arranging it, so it can be changed further before calling [`arrange`].
+///
+/// # Example
+///
+/// ```rust
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle},
+///     text::Text,
+/// };
+/// use embedded_layout::{layout, prelude::*};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let icon = Circle::new(Point::zero(), 7).into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+///
+/// let screen = layout! {
+///     vertical(spacing: FixedMargin(2), align: horizontal::Center) {
+///         Text::new("Title", Point::zero(), text_style),
+///         horizontal(align: vertical::Center) {
+///             icon,
+///             Text::new("Label", Point::zero(), text_style),
+///         },
+///         Text::new("Footer", Point::zero(), text_style),
+///     }
+/// }
+/// .arrange();
+///
+/// // The same layout, written by hand
+/// let manual = LinearLayout::vertical(
+///     Chain::new(Text::new("Title", Point::zero(), text_style))
+///         .append(
+///             LinearLayout::horizontal(
+///                 Chain::new(icon).append(Text::new("Label", Point::zero(), text_style)),
+///             )
+///             .with_alignment(vertical::Center)
+///             .arrange(),
+///         )
+///         .append(Text::new("Footer", Point::zero(), text_style)),
+/// )
+/// .with_spacing(FixedMargin(2))
+/// .with_alignment(horizontal::Center)
+/// .arrange();
+///
+/// assert_eq!(manual.bounds(), screen.bounds());
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`Chain`]: crate::object_chain::Chain
+/// [`arrange`]: crate::layout::linear::LinearLayout::arrange
+#[macro_export(local_inner_macros)]
+macro_rules! layout {
+    (vertical $(( $($options:tt)* ))? { $($views:tt)* }) => {
+        layout! {
+            @options
+            $crate::layout::linear::LinearLayout::vertical(layout! { @chain [] $($views)* });
+            $($($options)*)?
+        }
+    };
+    (horizontal $(( $($options:tt)* ))? { $($views:tt)* }) => {
+        layout! {
+            @options
+            $crate::layout::linear::LinearLayout::horizontal(layout! { @chain [] $($views)* });
+            $($($options)*)?
+        }
+    };
+
+    // Apply the options one by one
+    (@options $layout:expr; $(,)?) => {
+        $layout
+    };
+    (@options $layout:expr; spacing: $spacing:expr $(, $($rest:tt)*)?) => {
+        layout! { @options $layout.with_spacing($spacing); $($($rest)*)? }
+    };
+    (@options $layout:expr; align: $alignment:expr $(, $($rest:tt)*)?) => {
+        layout! { @options $layout.with_alignment($alignment); $($($rest)*)? }
+    };
+
+    // Collect the views, then build the chain
+    (@chain [($first:expr) $(($views:expr))*] $(,)?) => {
+        $crate::object_chain::Chain::new($first) $(.append($views))*
+    };
+    (@chain [$($views:tt)*] vertical $(( $($options:tt)* ))? { $($nested:tt)* } $(, $($rest:tt)*)?) => {
+        layout! {
+            @chain [$($views)* (layout! { vertical $(($($options)*))? { $($nested)* } }.arrange())]
+            $($($rest)*)?
+        }
+    };
+    (@chain [$($views:tt)*] horizontal $(( $($options:tt)* ))? { $($nested:tt)* } $(, $($rest:tt)*)?) => {
+        layout! {
+            @chain [$($views)* (layout! { horizontal $(($($options)*))? { $($nested)* } }.arrange())]
+            $($($rest)*)?
+        }
+    };
+    (@chain [$($views:tt)*] $view:expr $(, $($rest:tt)*)?) => {
+        layout! { @chain [$($views)* ($view)] $($($rest)*)? }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        layout::linear::{spacing::DistributeFill, LinearLayout},
+        prelude::*,
+        view_group::ViewGroup,
+    };
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    fn rect(width: u32, height: u32) -> Rectangle {
+        Rectangle::new(Point::new(3, -2), Size::new(width, height))
+    }
+
+    #[test]
+    fn single_view() {
+        let built: LinearLayout<_, Chain<Rectangle>> = crate::layout! { horizontal { rect(2, 2) } };
+
+        assert_eq!(1, built.inner().len());
+    }
+
+    #[test]
+    fn options_in_any_order() {
+        let built = crate::layout! {
+            vertical(align: horizontal::Right, spacing: FixedMargin(1),) {
+                rect(2, 2),
+                rect(4, 2),
+            }
+        }
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(2, 0), Size::new(2, 2)),
+            built.inner().parent.object
+        );
+        assert_eq!(Size::new(4, 5), built.size());
+    }
+
+    #[test]
+    fn macro_matches_handwritten_layout() {
+        let built = crate::layout! {
+            vertical(spacing: FixedMargin(2), align: horizontal::Center) {
+                rect(10, 4),
+                horizontal(spacing: DistributeFill(30), align: vertical::Center) {
+                    rect(5, 5),
+                    vertical { rect(3, 3), rect(4, 4) },
+                    rect(1, 9),
+                },
+                rect(20, 2),
+            }
+        }
+        .arrange();
+
+        let manual = LinearLayout::vertical(
+            Chain::new(rect(10, 4))
+                .append(
+                    LinearLayout::horizontal(
+                        Chain::new(rect(5, 5))
+                            .append(
+                                LinearLayout::vertical(Chain::new(rect(3, 3)).append(rect(4, 4)))
+                                    .arrange(),
+                            )
+                            .append(rect(1, 9)),
+                    )
+                    .with_spacing(DistributeFill(30))
+                    .with_alignment(vertical::Center)
+                    .arrange(),
+                )
+                .append(rect(20, 2)),
+        )
+        .with_spacing(FixedMargin(2))
+        .with_alignment(horizontal::Center)
+        .arrange();
+
+        assert_eq!(manual.bounds(), built.bounds());
+        for i in 0..3 {
+            assert_eq!(manual.bounds_of(i), built.bounds_of(i));
+        }
+
+        let (manual_row, built_row) = (&manual.inner().parent.object, &built.inner().parent.object);
+        for i in 0..3 {
+            assert_eq!(manual_row.bounds_of(i), built_row.bounds_of(i));
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::utils::rounding::{div_floor, half, split};
+
+/// Placement of the views of a [`LinearLayout`] within a fixed span along its primary axis.
+///
+/// See [`LinearLayout::justify`] for more information.
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`LinearLayout::justify`]: crate::layout::linear::LinearLayout::justify
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Move the views to the start of the span.
+    Start,
+
+    /// Move the views to the center of the span.
+    Center,
+
+    /// Move the views to the end of the span.
+    End,
+
+    /// Place the first view at the start, the last view at the end of the span, and distribute
+    /// the remaining space evenly between the views. A single view is placed at the start.
+    SpaceBetween,
+
+    /// Distribute the remaining space evenly around the views, so that the space before the first
+    /// and after the last view is half of the space between two views.
+    SpaceAround,
+}
+
+impl Justify {
+    /// Returns `true` if the spacing of the arranged views is replaced.
+    pub(crate) fn distributes(self) -> bool {
+        matches!(self, Justify::SpaceBetween | Justify::SpaceAround)
+    }
+
+    /// Returns the total free space placed before the `n`th of `count` views.
+    pub(crate) fn space_before(self, free: i32, n: usize, count: usize) -> i32 {
+        let n = n as i32;
+        let count = count as i32;
+
+        match self {
+            Justify::Start => 0,
+            Justify::Center => half(free),
+            Justify::End => free,
+            Justify::SpaceBetween if count < 2 => 0,
+            Justify::SpaceBetween => split(free, count - 1, n).0,
+            Justify::SpaceAround => div_floor(free * (2 * n + 1), 2 * count),
+        }
+    }
+}
@@ -13,6 +13,9 @@
 //!  - Align the layout object to where you want it to be displayed
 //!  - Call `draw` to display the views
 //!
+//! To keep access to the views after arranging them, use [`LinearLayout::arrange_group`], which
+//! writes the positions into the views and returns an [`ArrangementInfo`] that doesn't borrow them.
+//!
 //! # Orientation
 //!
 //! When constructing a [`LinearLayout`] object, you need to choose an orientation along which
@@ -59,50 +62,99 @@
 //!  * The default is [`Tight`] which is equivalent to [`FixedMargin(0)`]
 //!  * [`FixedMargin(margin)`]: `margin` px distance between views, where `margin` can be negative to overlap views
 //!  * [`DistributeFill(size)`]: force the primary layout size to `size`, distribute views evenly
+//!  * [`ProportionalGap(fraction)`]: leave a gap after each view, proportional to its size
+//!  * [`FixedSizes(sizes)`]: place each view in a slot of a given length
+//!  * [`CollapseWithPadding(margin)`]: like `FixedMargin`, but collapse the margin with the
+//!    padding of the views
+//!
+//! Views are placed along the primary axis by an [`Arrangement`]. Every element spacing is an
+//! arrangement, and [`Justify`] distributes views within the [span] of the layout. Arrangements
+//! can also compute positions from a list of sizes, without any views.
 //!
 //! [`View`]: crate::View
 //! [`ViewGroup`]: crate::view_group::ViewGroup
+//! [`ArrangementInfo`]: crate::layout::ArrangementInfo
 //! [secondary alignment]: LinearLayout::with_alignment
 //! [element spacing]: LinearLayout::with_spacing
+//! [span]: LinearLayout::with_span
 //! [`Tight`]: crate::layout::linear::spacing::Tight
 //! [`FixedMargin(0)`]: crate::layout::linear::spacing::FixedMargin
 //! [`FixedMargin(margin)`]: crate::layout::linear::spacing::FixedMargin
 //! [`DistributeFill(size)`]: crate::layout::linear::spacing::DistributeFill
+//! [`ProportionalGap(fraction)`]: crate::layout::linear::spacing::ProportionalGap
+//! [`FixedSizes(sizes)`]: crate::layout::linear::spacing::FixedSizes
+//! [`CollapseWithPadding(margin)`]: crate::layout::linear::spacing::CollapseWithPadding
 //! [`vertical::Bottom`]: crate::align::vertical::Bottom
 //! [`horizontal::Left`]: crate::align::horizontal::Left
 
 use crate::{
     align::{horizontal, vertical},
-    align::{HorizontalAlignment, VerticalAlignment},
-    view_group::{EmptyViewGroup, ViewGroup},
+    align::{AlignmentPosition, HorizontalAlignment, VerticalAlignment},
+    layout::{ArrangementInfo, Axis, Constraints},
+    theme::Restyle,
+    utils::{rect_helper::union_non_empty, rounding::RoundingMode, view_ext::snap_rectangle},
+    view_group::{DrawableViewGroup, EmptyViewGroup, Items, Placeable, PositionBuffer, ViewGroup},
+    views::Revision,
     View,
 };
 
+mod arrangement;
+mod dsl;
+mod justify;
+mod options;
 mod orientation;
+mod placement;
+mod plan;
 mod secondary_alignment;
 pub mod spacing;
 
+pub(crate) use arrangement::Placer;
+pub use arrangement::{Arrangement, PlannedOffsets, Slot};
+use core::ops::Range;
 use embedded_graphics::{
-    draw_target::DrawTarget,
-    prelude::{PixelColor, Point},
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::AnchorPoint,
+    prelude::{Dimensions, PixelColor, Point, Size},
     primitives::Rectangle,
     Drawable,
 };
-pub use orientation::{Horizontal, Orientation, Vertical};
+pub use justify::Justify;
+pub(crate) use options::ArrangeOptions;
+use orientation::{primary_coordinate, secondary_offset};
+pub use orientation::{Horizontal, Orientation, OrientationExt, Vertical};
+use placement::Placement;
+pub use placement::PreviewPositions;
+pub use plan::{plan_linear, plan_linear_size, LayoutPlan, LengthMismatch};
 pub use secondary_alignment::SecondaryAlignment;
 pub use spacing::{ElementSpacing, FixedMargin};
 
-use spacing::Tight;
+use spacing::{FixedSizes, Tight};
 
 /// `LinearLayout`
 ///
-/// [`LinearLayout`] is used to arrange views along the horizontal or vertical axis.
+/// [`LinearLayout`] is used to arrange views along the horizontal or vertical axis. Arranging the
+/// views changes the [`Revision`] of the layout, even if none of them moved.
 ///
 /// For more information and examples see the [module level documentation](crate::layout::linear).
 pub struct LinearLayout<LD, VG> {
     position: Point,
     direction: LD,
     views: VG,
+    options: ArrangeOptions,
+    scroll_remainder: f32,
+    fingerprint: Option<u64>,
+    justified_span: Option<u32>,
+    placement: Option<Rectangle>,
+    revision: Revision,
+}
+
+/// The result of [`LinearLayout::arrange_into`].
+pub struct ArrangeResult<LD, VG> {
+    /// The arranged layout, placed at the top left corner of the region.
+    pub layout: LinearLayout<LD, VG>,
+
+    /// `true` if the arranged layout fits into the region.
+    pub fits: bool,
 }
 
 impl<LD, VG> LinearLayout<LD, VG> {
@@ -117,6 +169,47 @@ impl<LD, VG> LinearLayout<LD, VG> {
     pub fn inner_mut(&mut self) -> &mut VG {
         &mut self.views
     }
+
+    /// Creates a layout that hasn't been arranged, scrolled or moved yet.
+    pub(crate) const fn from_parts(
+        position: Point,
+        direction: LD,
+        views: VG,
+        options: ArrangeOptions,
+    ) -> Self {
+        Self {
+            position,
+            direction,
+            views,
+            options,
+            scroll_remainder: 0.0,
+            fingerprint: None,
+            justified_span: None,
+            placement: None,
+            revision: Revision::ZERO,
+        }
+    }
+
+    /// Returns the layout with a different orientation, keeping its position and options.
+    fn with_direction<D>(self, direction: D) -> LinearLayout<D, VG> {
+        LinearLayout {
+            scroll_remainder: self.scroll_remainder,
+            placement: self.placement,
+            revision: self.revision,
+            ..LinearLayout::from_parts(self.position, direction, self.views, self.options)
+        }
+    }
+}
+
+impl<LD, VG> LinearLayout<LD, VG>
+where
+    LD: Copy,
+{
+    /// Returns a layout with the same properties that doesn't hold any views, to arrange the
+    /// views of this layout while they are borrowed mutably.
+    fn detached(&self) -> LinearLayout<LD, EmptyViewGroup> {
+        LinearLayout::from_parts(self.position, self.direction, EmptyViewGroup, self.options)
+    }
 }
 
 impl<VG> LinearLayout<Horizontal<vertical::Bottom, Tight>, VG>
@@ -127,11 +220,12 @@ where
     #[inline]
     #[must_use]
     pub fn horizontal(views: VG) -> Self {
-        Self {
-            position: Point::new(0, 0),
-            direction: Horizontal::default(),
+        Self::from_parts(
+            Point::zero(),
+            Horizontal::default(),
             views,
-        }
+            ArrangeOptions::new(),
+        )
     }
 }
 
@@ -143,18 +237,19 @@ where
     #[inline]
     #[must_use]
     pub fn vertical(views: VG) -> Self {
-        Self {
-            position: Point::new(0, 0),
-            direction: Vertical::default(),
+        Self::from_parts(
+            Point::zero(),
+            Vertical::default(),
             views,
-        }
+            ArrangeOptions::new(),
+        )
     }
 }
 
 impl<S, ELS, VG> LinearLayout<Horizontal<S, ELS>, VG>
 where
     S: SecondaryAlignment + VerticalAlignment,
-    ELS: ElementSpacing,
+    ELS: Arrangement,
     VG: ViewGroup,
 {
     /// Change the secondary alignment for this [`LinearLayout`] object.
@@ -168,35 +263,45 @@ where
     where
         Sec: SecondaryAlignment + VerticalAlignment,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_secondary_alignment(alignment),
-            views: self.views,
-        }
+        let direction = self.direction.with_secondary_alignment(alignment);
+        self.with_direction(direction)
     }
 
     /// Change the element spacing
     ///
-    /// For available values and their properties, see [spacing]
+    /// For available values and their properties, see [spacing]. Any [`Arrangement`] can be used,
+    /// e.g. a [`Justify`] mode distributes the views within the [span] of the layout.
     ///
     /// [spacing]: crate::layout::linear::spacing
+    /// [span]: LinearLayout::with_span
     #[inline]
     pub fn with_spacing<ES>(self, spacing: ES) -> LinearLayout<Horizontal<S, ES>, VG>
     where
-        ES: ElementSpacing,
+        ES: Arrangement,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_spacing(spacing),
-            views: self.views,
-        }
+        let direction = self.direction.with_spacing(spacing);
+        self.with_direction(direction)
+    }
+
+    /// Place the views in slots of fixed lengths along the primary axis
+    ///
+    /// The views are placed at the start of their slots, regardless of their own width. If there
+    /// are more views than `sizes`, the last size is reused. See [`FixedSizes`] for more information.
+    ///
+    /// [`FixedSizes`]: crate::layout::linear::spacing::FixedSizes
+    #[inline]
+    pub fn with_fixed_sizes(
+        self,
+        sizes: &[u32],
+    ) -> LinearLayout<Horizontal<S, FixedSizes<'_>>, VG> {
+        self.with_spacing(FixedSizes(sizes))
     }
 }
 
 impl<S, ELS, VG> LinearLayout<Vertical<S, ELS>, VG>
 where
     S: SecondaryAlignment + HorizontalAlignment,
-    ELS: ElementSpacing,
+    ELS: Arrangement,
     VG: ViewGroup,
 {
     /// Change the secondary alignment for this [`LinearLayout`] object.
@@ -210,34 +315,41 @@ where
     where
         Sec: SecondaryAlignment + HorizontalAlignment,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_secondary_alignment(alignment),
-            views: self.views,
-        }
+        let direction = self.direction.with_secondary_alignment(alignment);
+        self.with_direction(direction)
     }
 
     /// Change the element spacing
     ///
-    /// For available values and their properties, see [spacing]
+    /// For available values and their properties, see [spacing]. Any [`Arrangement`] can be used,
+    /// e.g. a [`Justify`] mode distributes the views within the [span] of the layout.
     ///
     /// [spacing]: crate::layout::linear::spacing
+    /// [span]: LinearLayout::with_span
     #[inline]
     pub fn with_spacing<ES>(self, spacing: ES) -> LinearLayout<Vertical<S, ES>, VG>
     where
-        ES: ElementSpacing,
+        ES: Arrangement,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_spacing(spacing),
-            views: self.views,
-        }
+        let direction = self.direction.with_spacing(spacing);
+        self.with_direction(direction)
+    }
+
+    /// Place the views in slots of fixed lengths along the primary axis
+    ///
+    /// The views are placed at the start of their slots, regardless of their own width. If there
+    /// are more views than `sizes`, the last size is reused. See [`FixedSizes`] for more information.
+    ///
+    /// [`FixedSizes`]: crate::layout::linear::spacing::FixedSizes
+    #[inline]
+    pub fn with_fixed_sizes(self, sizes: &[u32]) -> LinearLayout<Vertical<S, FixedSizes<'_>>, VG> {
+        self.with_spacing(FixedSizes(sizes))
     }
 }
 
 impl<LD, VG> Clone for LinearLayout<LD, VG>
 where
-    LD: Orientation,
+    LD: OrientationExt,
     VG: ViewGroup + Clone,
 {
     fn clone(&self) -> Self {
@@ -245,13 +357,19 @@ where
             position: self.position,
             direction: self.direction,
             views: self.views.clone(),
+            options: self.options,
+            scroll_remainder: self.scroll_remainder,
+            fingerprint: self.fingerprint,
+            justified_span: self.justified_span,
+            placement: self.placement,
+            revision: self.revision,
         }
     }
 }
 
 impl<LD, VG> LinearLayout<LD, VG>
 where
-    LD: Orientation,
+    LD: OrientationExt,
     VG: ViewGroup,
 {
     /// Consume the layout object and return the wrapped [`ViewGroup`].
@@ -300,537 +418,2553 @@ where
         self.views
     }
 
-    /// Arrange the views according to the layout properties and return the views as a [`ViewGroup`].
+    /// Returns the axis along which the views are placed.
     #[inline]
-    #[must_use]
-    pub fn arrange(mut self) -> Self {
-        // Place first child to the layout's position.
-        self.views
-            .translate_child(0, self.position - self.views.bounds_of(0).top_left);
-
-        // We can't use `self` because we borrow parts of it mutably.
-        LinearLayout {
-            position: Point::zero(),
-            direction: self.direction,
-            views: EmptyViewGroup,
-        }
-        .arrange_view_group(&mut self.views);
-
-        self
+    pub fn axis(&self) -> Axis {
+        LD::AXIS
     }
 
-    /// Arrange a [`ViewGroup`] according to the layout properties.
+    /// Returns the position of the layout, the top left corner of the arranged views.
+    ///
+    /// Translating or aligning the layout moves its position, and arranging the views again places
+    /// them at this position.
     #[inline]
-    pub fn arrange_view_group(&self, view_group: &mut impl ViewGroup) {
-        let view_count = view_group.len();
-
-        // measure
-        let bounds = view_group.bounds_of(0);
-        let position = bounds.top_left;
-        let mut size = bounds.size();
-        for i in 1..view_count {
-            let current_el_size = view_group.bounds_of(i).size();
-            size = LD::Secondary::measure(size, current_el_size);
-        }
+    pub fn position(&self) -> Point {
+        self.position
+    }
 
-        // arrange
-        let mut bounds = Rectangle::new(position, size);
-        for i in 0..view_count {
-            let offset =
-                self.direction
-                    .compute_offset(view_group.bounds_of(i), size, bounds, i, view_count);
-            view_group.translate_child(i, offset);
-            bounds = view_group.bounds_of(i);
+    /// Move the layout back to where it was last translated or aligned to, keeping its `anchor`
+    /// point in place.
+    ///
+    /// The layout remembers its bounds every time it is moved. If the views are arranged again
+    /// after their content changed, the layout keeps its top left corner, so a layout that was
+    /// aligned to e.g. the bottom right corner of the screen may no longer touch it. This method
+    /// moves the layout so that the `anchor` point of its bounds is where the same point was after
+    /// the layout was last moved. If the size of the layout didn't change, it is moved back to the
+    /// exact same place, regardless of `anchor`.
+    ///
+    /// A layout that was never moved is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{geometry::AnchorPoint, prelude::*, primitives::Rectangle};
+    /// let screen = Rectangle::new(Point::zero(), Size::new(64, 32));
+    /// let mut items = [Rectangle::new(Point::zero(), Size::new(10, 5)); 2];
+    ///
+    /// let mut layout = LinearLayout::vertical(Views::new(&mut items))
+    ///     .arrange()
+    ///     .align_to(&screen, horizontal::Right, vertical::Bottom);
+    ///
+    /// // The content grows, and the layout grows past the right edge of the screen.
+    /// layout.inner_mut()[1].size.width = 20;
+    /// let mut layout = layout.arrange();
+    /// assert_eq!(Point::new(73, 31), layout.bounds().bottom_right().unwrap());
+    ///
+    /// // Move it back into the corner.
+    /// layout.restore_position(AnchorPoint::BottomRight);
+    /// assert_eq!(Point::new(63, 31), layout.bounds().bottom_right().unwrap());
+    /// ```
+    #[inline]
+    pub fn restore_position(&mut self, anchor: AnchorPoint) {
+        if let Some(placement) = self.placement {
+            let by = placement.anchor_point(anchor) - View::bounds(self).anchor_point(anchor);
+            View::translate_impl(self, by);
         }
     }
-}
 
-impl<LD, VG> View for LinearLayout<LD, VG>
-where
-    LD: Orientation,
-    VG: ViewGroup,
-{
+    /// Arrange the views according to the layout properties and return the views as a [`ViewGroup`].
+    ///
+    /// The views are placed starting at the position of the layout, regardless of where they were
+    /// before. Arranging an arranged layout again doesn't move the views, and a layout that was
+    /// translated as a whole stays where it was moved to.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the element spacing [requires a total size] but neither it nor
+    /// [`with_span`] provides one.
+    ///
+    /// [requires a total size]: Arrangement::requires_total_size
+    /// [`with_span`]: LinearLayout::with_span
     #[inline]
-    fn translate_impl(&mut self, by: Point) {
-        self.position += by;
-        View::translate_impl(&mut self.views, by);
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        self.arrange_in_place();
+        self
     }
 
+    /// Arrange the views and return the layout together with its size.
+    ///
+    /// This is equivalent to calling [`arrange`] followed by [`View::size`], for when the size of
+    /// the arranged views is needed right away, e.g. to place the layout itself.
+    ///
+    /// [`arrange`]: LinearLayout::arrange
     #[inline]
-    fn bounds(&self) -> Rectangle {
-        let bounds = View::bounds(&self.views);
-        let top_left = bounds.top_left;
-        let correction = self.position - top_left;
+    #[must_use]
+    pub fn arrange_measured(self) -> (Self, Size) {
+        let layout = self.arrange();
+        let size = layout.size();
 
-        bounds.translate(correction)
+        (layout, size)
     }
-}
 
-impl<LD, VG> ViewGroup for LinearLayout<LD, VG>
-where
-    LD: Orientation,
-    VG: ViewGroup,
-{
+    /// Arrange the views into `region`, shrinking them along the primary axis if necessary.
+    ///
+    /// The layout is placed at the top left corner of `region`. If the views take up more space
+    /// along the primary axis than `region` provides, each view is [measured] again with a maximum
+    /// length that is proportional to its current length, so that the views together give up the
+    /// missing space. Views that don't shrink when measured, e.g. primitives and text, keep their
+    /// size.
+    ///
+    /// The returned [`ArrangeResult`] reports whether the arranged layout fits into `region` along
+    /// both axes.
+    ///
+    /// [measured]: View::measure
     #[inline]
-    fn len(&self) -> usize {
-        self.views.len()
+    #[must_use]
+    pub fn arrange_into(mut self, region: Rectangle) -> ArrangeResult<LD, VG> {
+        self.position = region.top_left;
+        self.arrange_in_place();
+
+        let (available, _) = LD::destructure_size(region.size);
+        let (natural, _) = LD::destructure_size(self.size());
+        if natural > available {
+            let view_count = self.views.len();
+            let primary_of = |size| LD::destructure_size(size).0 as u64;
+
+            let views_total: u64 = (0..view_count)
+                .map(|i| primary_of(self.views.bounds_of(i).size))
+                .sum();
+            let remaining = views_total.saturating_sub(u64::from(natural - available));
+
+            for i in 0..view_count {
+                let length = primary_of(self.views.bounds_of(i).size);
+                let max_length = (length * remaining)
+                    .checked_div(views_total)
+                    .unwrap_or_default() as u32;
+
+                self.views
+                    .at_mut(i)
+                    .measure(Constraints::loose(LD::create_size(max_length, u32::MAX)));
+            }
+
+            self.arrange_in_place();
+        }
+
+        let size = self.size();
+        let fits = size.width <= region.size.width && size.height <= region.size.height;
+
+        ArrangeResult { layout: self, fits }
     }
 
+    /// Arrange the views, unless they were already arranged with the same sizes.
+    ///
+    /// The layout stores a fingerprint of the sizes of its views each time this method arranges
+    /// them. If the fingerprint still matches, this method returns without touching the views.
+    /// Translating the arranged layout does not invalidate the cache, but changing the size of a
+    /// view, the spacing or the alignment does.
+    ///
+    /// The fingerprint is a 64 bit hash, so two different sets of sizes could in theory share
+    /// it. Use [`invalidate`] if the views were moved individually and need to be arranged again.
+    ///
+    /// [`invalidate`]: LinearLayout::invalidate
     #[inline]
-    fn at(&self, idx: usize) -> &dyn View {
-        self.views.at(idx)
+    pub fn arrange_cached(&mut self) {
+        let fingerprint = self.fingerprint();
+        if self.fingerprint == Some(fingerprint) {
+            return;
+        }
+
+        self.arrange_in_place();
+        self.fingerprint = Some(fingerprint);
     }
 
+    /// Force the next [`arrange_cached`] call to arrange the views.
+    ///
+    /// [`arrange_cached`]: LinearLayout::arrange_cached
     #[inline]
-    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
-        self.views.at_mut(idx)
+    pub fn invalidate(&mut self) {
+        self.fingerprint = None;
     }
 
+    /// Align every view to a shared rectangle along the secondary axis.
+    ///
+    /// By default, each view is aligned to the previous one. With this option enabled, the views are
+    /// aligned to the bounding box of all views after arrangement, i.e. to the span of the widest
+    /// view in a vertical layout or the tallest view in a horizontal one. This keeps rows of a
+    /// gridded menu on exactly the same edge or center line.
+    ///
+    /// Cascading alignments (e.g. [`horizontal::LeftToRight`]) have no shared span, so with this
+    /// option they align every view like their first view.
     #[inline]
-    fn bounds_of(&self, idx: usize) -> Rectangle {
-        self.views.bounds_of(idx)
+    #[must_use]
+    pub fn with_uniform_cross_alignment(self) -> Self {
+        let mut options = self.options;
+        options.uniform_cross_alignment = true;
+
+        Self {
+            options,
+            fingerprint: None,
+            ..self
+        }
     }
 
+    /// Make the layout at least `span` pixels long along its primary axis.
+    ///
+    /// [Pinned] views are placed within this span, starting at the position of the layout. The
+    /// other views are placed by the [element spacing], which may distribute them within the span,
+    /// like [`Justify`] does, or let them extend past it.
+    ///
+    /// [Pinned]: crate::views::Pinned
+    /// [element spacing]: LinearLayout::with_spacing
     #[inline]
-    fn translate_child(&mut self, idx: usize, by: Point) {
-        self.views.translate_child(idx, by)
-    }
-}
+    #[must_use]
+    pub fn with_span(self, span: u32) -> Self {
+        let mut options = self.options;
+        options.span = Some(span);
 
-impl<C, LD, VG> Drawable for LinearLayout<LD, VG>
-where
-    C: PixelColor,
-    LD: Orientation,
-    VG: ViewGroup + Drawable<Color = C>,
-{
-    type Color = C;
-    type Output = ();
+        Self {
+            options,
+            fingerprint: None,
+            ..self
+        }
+    }
 
+    /// Move the views down so that their bottom edges land on a grid.
+    ///
+    /// The grid lines are `pitch` pixels apart, starting at the top edge of the layout. Each view
+    /// is moved to the nearest grid line at or below its bottom edge after arrangement. In vertical
+    /// layouts, the views after a moved view are moved by the same distance, so they don't overlap.
+    /// The layout keeps its position, so it grows by the space left above the moved views.
+    ///
+    /// The views of a layout can be of any type, so they are snapped by their bottom edges. To snap
+    /// texts by their baselines, use [`snap_to_baseline_grid`] on the arranged texts instead.
+    ///
+    /// [`snap_to_baseline_grid`]: crate::align::snap_to_baseline_grid
     #[inline]
-    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
-    where
-        D: DrawTarget<Color = C>,
-    {
-        self.views.draw(display)?;
-        Ok(())
+    #[must_use]
+    pub fn with_baseline_grid(self, pitch: u32) -> Self {
+        let mut options = self.options;
+        options.baseline_grid = Some(pitch);
+
+        Self {
+            options,
+            fingerprint: None,
+            ..self
+        }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::{
-        layout::linear::{
-            spacing::{DistributeFill, FixedMargin},
-            LinearLayout,
-        },
-        object_chain::Chain,
-        prelude::*,
-    };
-    use embedded_graphics::{
-        mock_display::MockDisplay,
-        pixelcolor::BinaryColor,
-        prelude::{Point, Primitive, Size},
-        primitives::{Circle, PrimitiveStyle, Rectangle},
-        Drawable,
-    };
+    /// Returns an FNV-1a hash of the orientation, the options and the sizes of the views.
+    fn fingerprint(&self) -> u64 {
+        let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+        let mut add = |value: u32| {
+            for byte in value.to_le_bytes() {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+
+        let optional = |value: Option<u32>| value.map_or(0, |value| value.wrapping_add(1));
+        add(LD::AXIS as u32);
+        add(u32::from(self.options.uniform_cross_alignment));
+        add(optional(self.options.baseline_grid));
+        add(optional(self.options.span));
+
+        add(self.views.len() as u32);
+        for i in 0..self.views.len() {
+            let size = self.views.bounds_of(i).size;
+            add(size.width);
+            add(size.height);
+        }
 
-    #[allow(dead_code)]
-    fn compile_check() {
-        let style = PrimitiveStyle::with_fill(BinaryColor::On);
-        let rect = Rectangle::new(Point::zero(), Size::new(10, 20)).into_styled(style);
-        let circ = Circle::new(Point::zero(), 10).into_styled(style);
-        let _ = LinearLayout::horizontal(Chain::new(rect).append(circ));
+        hash
     }
 
-    #[test]
-    fn layout_size() {
-        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
-        let rect2 = Rectangle::new(Point::zero(), Size::new(10, 20));
-        let size = LinearLayout::horizontal(Chain::new(rect).append(rect2))
-            .arrange()
-            .size();
-
-        assert_eq!(Size::new(20, 20), size);
+    fn arrange_in_place(&mut self) {
+        self.justified_span = None;
+        self.revision = self.revision.next();
 
-        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
-        let rect2 = Rectangle::new(Point::zero(), Size::new(10, 20));
-        let size = LinearLayout::vertical(Chain::new(rect).append(rect2))
-            .arrange()
-            .size();
+        // We can't use `self` because we borrow parts of it mutably.
+        self.detached().arrange_group(&mut self.views);
+    }
 
-        assert_eq!(Size::new(10, 40), size);
+    /// Arrange the views of `group` according to the layout properties, starting at the layout's
+    /// position.
+    ///
+    /// Unlike [`arrange`], this method doesn't hold on to the views. The positions are written into
+    /// the views, and the returned [`ArrangementInfo`] doesn't borrow them, so the views can be
+    /// changed and drawn without arranging them again. The views of the layout itself are not
+    /// touched, so the layout can be created with an [`EmptyViewGroup`] and reused for any number
+    /// of groups.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_layout::view_group::EmptyViewGroup;
+    /// # use embedded_graphics::{
+    /// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     mock_display::MockDisplay,
+    /// #     text::Text,
+    /// # };
+    /// # let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+    /// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+    /// let mut texts = [
+    ///     Text::new("Count:", Point::zero(), text_style),
+    ///     Text::new("0", Point::zero(), text_style),
+    /// ];
+    ///
+    /// let info = LinearLayout::horizontal(EmptyViewGroup).arrange_group(&mut Views::new(&mut texts));
+    ///
+    /// // The views are no longer borrowed, update the text and draw it in place.
+    /// texts[1].text = "1";
+    /// Views::new(&mut texts).draw(&mut display).unwrap();
+    /// # assert_eq!(Size::new(42, 9), info.size());
+    /// ```
+    ///
+    /// [`arrange`]: LinearLayout::arrange
+    #[inline]
+    pub fn arrange_group(&self, group: &mut impl ViewGroup) -> ArrangementInfo {
+        self.arrange_items(group)
     }
 
-    #[test]
-    fn layout_arrange_vertical() {
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+    /// Arrange `items` like [`arrange_group`] arranges the views of a view group.
+    ///
+    /// [`arrange_group`]: LinearLayout::arrange_group
+    fn arrange_items(&self, items: &mut impl Placeable) -> ArrangementInfo {
+        debug_assert!(
+            !self.direction.requires_total_size()
+                || self
+                    .options
+                    .span
+                    .or_else(|| self.direction.track_size())
+                    .is_some(),
+            "the element spacing distributes views, but doesn't define the size of the layout"
+        );
 
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+        // Place first child to the layout's position.
+        items.move_item(0, self.position - items.item_bounds(0).top_left);
 
-        LinearLayout::vertical(Chain::new(rect).append(rect2))
-            .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
+        self.place_group(items);
 
-        assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "           ",
-                "           ",
-                " ##########",
-                " #        #",
-                " #        #",
-                " #        #",
-                " ##########",
-                " #####     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #####     ",
-            ])
-        );
+        let bounds = self.layout_bounds(items.items_bounds());
+        ArrangementInfo::new(bounds, items.item_count())
     }
 
-    #[test]
+    /// Compute the positions that [`arrange_group`] would move `items` to, without moving them.
+    ///
+    /// The top left corner of `items[n]` is written to `positions[n]`, and the size of the
+    /// arranged layout is returned. The items only need to know their bounding box, so they can be
+    /// immutable, e.g. `const` data, and drawn at their positions with [`DrawTranslated`]. Plain
+    /// items have no [margins] and can't be [pinned].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` and `positions` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::{prelude::*, view_group::EmptyViewGroup};
+    /// # use embedded_graphics::{
+    /// #     mock_display::MockDisplay,
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     primitives::{PrimitiveStyle, Rectangle, Styled},
+    /// # };
+    /// const STYLE: PrimitiveStyle<BinaryColor> = PrimitiveStyle::with_fill(BinaryColor::On);
+    /// const ITEMS: [Styled<Rectangle, PrimitiveStyle<BinaryColor>>; 2] = [
+    ///     Styled::new(Rectangle::new(Point::zero(), Size::new(2, 2)), STYLE),
+    ///     Styled::new(Rectangle::new(Point::zero(), Size::new(3, 1)), STYLE),
+    /// ];
+    ///
+    /// let mut positions = [Point::zero(); 2];
+    /// let size = LinearLayout::horizontal(EmptyViewGroup)
+    ///     .with_spacing(FixedMargin(1))
+    ///     .arrange_positions(&ITEMS, &mut positions);
+    ///
+    /// assert_eq!([Point::new(0, 0), Point::new(3, 1)], positions);
+    /// assert_eq!(Size::new(6, 2), size);
+    ///
+    /// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+    /// for (item, position) in ITEMS.iter().zip(positions) {
+    ///     item.draw_translated(position - item.bounds().top_left)
+    ///         .draw(&mut display)
+    ///         .unwrap();
+    /// }
+    /// display.assert_pattern(&["##    ", "## ###"]);
+    /// ```
+    ///
+    /// [`arrange_group`]: LinearLayout::arrange_group
+    /// [`DrawTranslated`]: crate::views::DrawTranslated
+    /// [margins]: View::margins
+    /// [pinned]: View::pinned
+    #[inline]
+    pub fn arrange_positions<T>(&self, items: &[T], positions: &mut [Point]) -> Size
+    where
+        T: Dimensions,
+    {
+        let mut buffer = PositionBuffer::new(items, positions);
+        if items.is_empty() {
+            return self.extend_primary_size(Size::zero());
+        }
+
+        self.arrange_items(&mut buffer).size()
+    }
+
+    /// Place the arranged views within `total` pixels along the primary axis.
+    ///
+    /// [`Justify::Start`], [`Justify::Center`] and [`Justify::End`] move the arranged views as a
+    /// group, keeping the element spacing. [`Justify::SpaceBetween`] and [`Justify::SpaceAround`]
+    /// replace the element spacing by distributing the space not taken up by the views.
+    ///
+    /// The layout is at least `total` pixels long afterwards, starting at its current position.
+    /// If the views don't fit, they extend past the span, e.g. to both sides with
+    /// [`Justify::Center`]. Arranging the views again discards the justification.
+    #[inline]
+    #[must_use]
+    pub fn justify(mut self, total: u32, mode: Justify) -> Self {
+        let count = self.views.len();
+        let start = primary_coordinate::<LD>(self.position);
+
+        if mode.distributes() {
+            let primary_size = |bounds: Rectangle| {
+                if bounds.is_zero_sized() {
+                    0
+                } else {
+                    LD::destructure_size(bounds.size).0
+                }
+            };
+
+            let occupied = (0..count)
+                .map(|i| primary_size(self.views.bounds_of(i)))
+                .sum::<u32>();
+            let free = total as i32 - occupied as i32;
+
+            let mut position = start;
+            for i in 0..count {
+                let bounds = self.views.bounds_of(i);
+                let target = position + mode.space_before(free, i, count);
+                let by = target - primary_coordinate::<LD>(bounds.top_left);
+
+                self.views.translate_child(i, LD::create_point(by, 0));
+                position += primary_size(bounds) as i32;
+            }
+        } else {
+            let (size, _) = LD::destructure_size(self.size());
+            let by = mode.space_before(total as i32 - size as i32, 0, count);
+
+            for i in 0..count {
+                self.views.translate_child(i, LD::create_point(by, 0));
+            }
+        }
+
+        self.justified_span = Some(total);
+        self.revision = self.revision.next();
+        self
+    }
+
+    /// Scroll the layout along its primary axis by a fractional number of pixels.
+    ///
+    /// The layout is translated by the integer part of the accumulated scroll distance. The
+    /// fractional remainder is kept and added to the `delta` of the next call, so repeated small
+    /// steps don't get lost to rounding.
+    #[inline]
+    pub fn scroll_fractional(&mut self, delta: f32) {
+        self.scroll_remainder += delta;
+
+        // `as` truncates towards zero, which keeps the remainder's sign consistent with `delta`.
+        let whole = self.scroll_remainder as i32;
+        if whole != 0 {
+            self.scroll_remainder -= whole as f32;
+            View::translate_impl(self, LD::create_point(whole, 0));
+        }
+    }
+
+    /// Returns the range of indices of the views that are at least partially inside `viewport`.
+    ///
+    /// The views are expected to be arranged, so that they are ordered along the primary axis.
+    /// Views outside of the returned range don't need to be drawn. If no view is visible, the
+    /// returned range is empty.
+    #[inline]
+    pub fn visible_range(&self, viewport: Rectangle) -> Range<usize> {
+        let count = self.views.len();
+        let visible = |i: &usize| self.views.bounds_of(*i).intersects(viewport);
+
+        match (0..count).find(visible) {
+            Some(first) => {
+                let end = (first..count).rfind(visible).map_or(first, |last| last + 1);
+                first..end
+            }
+            None => 0..0,
+        }
+    }
+
+    /// Returns the top left corners that the layout would arrange `count` views at.
+    ///
+    /// This doesn't move any views or allocate. The first `count` views are placed by the same code
+    /// as in [`arrange`], starting at the position of the layout, so margins, pinned views and all
+    /// layout options are taken into account. If `count` is larger than the number of views, the
+    /// extra slots are placed after the last view, as if it was repeated: they have its size, its
+    /// margins and its pinning. A layout without views yields no positions.
+    ///
+    /// Use it to animate the views of a list that grows or shrinks, by comparing the positions
+    /// for `n` and `n + 1` views.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// let row = Rectangle::new(Point::zero(), Size::new(10, 4));
+    /// let layout = LinearLayout::vertical(Chain::new(row).append(row)).with_spacing(FixedMargin(1));
+    ///
+    /// // The position of a third row, appended to the list
+    /// assert_eq!(Some(Point::new(0, 10)), layout.positions_for(3).nth(2));
+    /// ```
+    ///
+    /// [`arrange`]: LinearLayout::arrange
+    #[inline]
+    pub fn positions_for(&self, count: usize) -> PreviewPositions<'_, LD, VG> {
+        PreviewPositions::new(self, count)
+    }
+
+    /// Move every view of an arranged layout so that its top left corner is on a multiple of `grid`.
+    ///
+    /// Each coordinate is rounded in the direction given by `rounding`, so the gaps between views
+    /// may change by up to `grid - 1` pixels. The order of the views is preserved: a view that
+    /// would be rounded onto (or before) the previous view's grid line is moved to the next grid
+    /// line instead.
+    #[inline]
+    pub fn quantize_positions(&mut self, grid: Size, rounding: RoundingMode) {
+        let mut previous: Option<(Point, Point)> = None;
+        for i in 0..self.views.len() {
+            let original = self.views.bounds_of(i).top_left;
+            let mut snapped = snap_rectangle(self.views.bounds_of(i), grid, rounding).top_left;
+
+            if let Some((previous_original, previous_snapped)) = previous {
+                if original.x > previous_original.x && snapped.x <= previous_snapped.x {
+                    snapped.x = previous_snapped.x + grid.width.max(1) as i32;
+                }
+                if original.y > previous_original.y && snapped.y <= previous_snapped.y {
+                    snapped.y = previous_snapped.y + grid.height.max(1) as i32;
+                }
+            }
+
+            self.views.translate_child(i, snapped - original);
+            previous = Some((original, snapped));
+        }
+
+        self.position = View::bounds(&self.views).top_left;
+    }
+
+    /// Arrange a [`ViewGroup`] according to the layout properties.
+    ///
+    /// The first view keeps its position, and the other views are placed relative to it. This means
+    /// that arranging the views of an already arranged group again doesn't move them.
+    ///
+    /// The [baseline grid] is only applied by [`arrange`] and [`arrange_group`], which place the
+    /// views at the position of the layout.
+    ///
+    /// [baseline grid]: LinearLayout::with_baseline_grid
+    /// [`arrange`]: LinearLayout::arrange
+    /// [`arrange_group`]: LinearLayout::arrange_group
+    #[inline]
+    pub fn arrange_view_group(&self, view_group: &mut impl ViewGroup) {
+        let origin = view_group.bounds_of(0).top_left;
+
+        self.place_views(view_group, false);
+
+        // Depending on the alignment, the first view may have been moved within the space taken
+        // up by the views. Move the views back so that arranging them again doesn't move them.
+        let by = origin - view_group.bounds_of(0).top_left;
+        if by != Point::zero() {
+            for i in 0..view_group.len() {
+                view_group.translate_child(i, by);
+            }
+        }
+    }
+
+    /// Arrange the views of `view_group` starting at the top left corner of the first view, then
+    /// move them onto the baseline grid, if there is one.
+    pub(crate) fn place_group(&self, view_group: &mut impl Placeable) {
+        self.place_views(view_group, true);
+    }
+
+    /// Arrange the views of `view_group`, starting at the top left corner of the first view.
+    ///
+    /// The views are only moved onto the baseline grid if `snap` is set.
+    fn place_views(&self, view_group: &mut impl Placeable, snap: bool) {
+        if view_group.item_count() == 0 {
+            return;
+        }
+
+        let start = view_group.item_bounds(0).top_left;
+        let mut placement = Placement::new(self, view_group, start, snap);
+        for i in 0..view_group.item_count() {
+            if let Some(bounds) = placement.next(view_group) {
+                let by = bounds.top_left - view_group.item_bounds(i).top_left;
+                view_group.move_item(i, by);
+            }
+        }
+    }
+
+    /// Returns the translation that moves the `n`th of `count` views in `items` into place.
+    ///
+    /// The position along the primary axis is computed by the element spacing, relative to
+    /// `start`, the position of the layout. `size` is the measured size of the views and
+    /// `previous` the bounds of the previous view, or of the whole layout for the first view.
+    fn placement_offset(
+        &self,
+        items: &impl Items,
+        start: Point,
+        size: Size,
+        previous: Rectangle,
+        n: usize,
+        count: usize,
+    ) -> Point {
+        let bounds = items.item_bounds(n);
+        let (leading_margin, _) = items.item_inner_margins(n, LD::AXIS);
+        let previous_trailing_margin = match n {
+            0 => 0,
+            _ => items.item_inner_margins(n - 1, LD::AXIS).1,
+        };
+        let origin = primary_coordinate::<LD>(start);
+        let (previous_start, previous_extent) = if n == 0 {
+            (0, 0)
+        } else {
+            (
+                primary_coordinate::<LD>(previous.top_left) - origin,
+                LD::destructure_size(previous.size).0,
+            )
+        };
+
+        let position = self.direction.spacing().position(Slot {
+            n,
+            count,
+            extent: LD::destructure_size(bounds.size).0,
+            occupied: LD::destructure_size(size).0,
+            previous_start,
+            previous_extent,
+            track: self.options.span.or_else(|| self.direction.track_size()),
+            leading_margin,
+            previous_trailing_margin,
+        });
+
+        LD::create_point(
+            origin + position - primary_coordinate::<LD>(bounds.top_left),
+            secondary_offset::<LD>(bounds, previous, n),
+        )
+    }
+
+    /// Returns the space between the `idx`th view and the one before it, from their [margins].
+    ///
+    /// [margins]: View::margins
+    fn collapsed_margin(view_group: &impl Items, idx: usize) -> Point {
+        if idx == 0 {
+            return Point::zero();
+        }
+
+        let (_, after) = view_group.item_margins(idx - 1);
+        let (before, _) = view_group.item_margins(idx);
+
+        LD::create_point(after.max(before) as i32, 0)
+    }
+
+    /// Returns the span that the pinned views from `first` on are placed in, starting at `start`.
+    ///
+    /// Returns `None` if no view is pinned.
+    fn pin_span(&self, view_group: &impl Items, first: usize, start: Point) -> Option<Rectangle> {
+        let views = first..view_group.item_count();
+        if !views.clone().any(|i| view_group.item_pinned(i).is_some()) {
+            return None;
+        }
+
+        let length = self
+            .options
+            .span
+            .or_else(|| self.direction.track_size())
+            .unwrap_or_else(|| {
+                views
+                    .map(|i| view_group.item_bounds(i))
+                    .filter(|bounds| !bounds.is_zero_sized())
+                    .map(|bounds| LD::destructure_size(bounds.size).0)
+                    .sum()
+            });
+
+        Some(Rectangle::new(start, LD::create_size(length, 0)))
+    }
+
+    /// Replaces the primary axis component of `offset` if the view is pinned within `span`.
+    fn pin(
+        pinned: Option<AlignmentPosition>,
+        bounds: Rectangle,
+        offset: Point,
+        span: Option<Rectangle>,
+    ) -> Point {
+        match (pinned, span) {
+            (Some(position), Some(span)) => match LD::AXIS {
+                Axis::Horizontal => Point::new(position.align_horizontal(bounds, span), offset.y),
+                Axis::Vertical => Point::new(offset.x, position.align_vertical(bounds, span)),
+            },
+            _ => offset,
+        }
+    }
+}
+
+impl<LD, VG> LinearLayout<LD, VG>
+where
+    LD: OrientationExt,
+{
+    /// Returns the bounding box of the layout that contains the views within `views`.
+    ///
+    /// The bounding box covers the views wherever they are, e.g. when they were justified past the
+    /// position of the layout. Along the primary axis, it also covers the span that starts at the
+    /// position of the layout. Views that were moved down by the baseline grid leave space above
+    /// them, which belongs to the layout.
+    fn layout_bounds(&self, views: Rectangle) -> Rectangle {
+        if views.is_zero_sized() {
+            return Rectangle::new(self.position, self.extend_primary_size(Size::zero()));
+        }
+
+        let mut bounds = views;
+        if self.options.baseline_grid.is_some() && self.position.y < bounds.top_left.y {
+            let top = Point::new(bounds.top_left.x, self.position.y);
+            bounds = union_non_empty(bounds, Rectangle::new(top, Size::new(1, 1)));
+        }
+
+        let (span, _) = LD::destructure_size(self.extend_primary_size(Size::zero()));
+        if span > 0 {
+            let start = primary_coordinate::<LD>(self.position);
+            let by = LD::create_point(start - primary_coordinate::<LD>(bounds.top_left), 0);
+            let (_, secondary) = LD::destructure_size(bounds.size);
+            let span = Rectangle::new(bounds.top_left + by, LD::create_size(span, secondary));
+            bounds = union_non_empty(bounds, span);
+        }
+
+        bounds
+    }
+
+    /// Extends `size` along the primary axis to the track size and the justified span.
+    fn extend_primary_size(&self, size: Size) -> Size {
+        let (primary, secondary) = LD::destructure_size(size);
+        let min_primary = self
+            .direction
+            .track_size()
+            .into_iter()
+            .chain(self.justified_span)
+            .chain(self.options.span)
+            .max();
+
+        match min_primary {
+            Some(min_primary) if min_primary > primary => LD::create_size(min_primary, secondary),
+            _ => size,
+        }
+    }
+}
+
+impl<LD, VG> View for LinearLayout<LD, VG>
+where
+    LD: OrientationExt,
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        View::translate_impl(&mut self.views, by);
+        self.placement = Some(View::bounds(self));
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.layout_bounds(View::bounds(&self.views))
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.revision.combine(self.views.revision())
+    }
+
+    /// Measure the views within `constraints`, and arrange them again if their sizes changed.
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        let child_constraints = Constraints::loose(constraints.max);
+        let mut resized = false;
+        for i in 0..self.views.len() {
+            let size = self.views.bounds_of(i).size;
+            self.views.at_mut(i).measure(child_constraints);
+            resized |= self.views.bounds_of(i).size != size;
+        }
+
+        if resized {
+            self.arrange_in_place();
+        }
+
+        constraints.constrain(self.size())
+    }
+}
+
+impl<S, LD, VG> Restyle<S> for LinearLayout<LD, VG>
+where
+    VG: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.views.restyle(style);
+    }
+}
+
+impl<LD, VG> ViewGroup for LinearLayout<LD, VG>
+where
+    LD: OrientationExt,
+    VG: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.views.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.views.at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views.translate_child(idx, by)
+    }
+}
+
+impl<C, LD, VG> DrawableViewGroup<C> for LinearLayout<LD, VG>
+where
+    C: PixelColor,
+    LD: OrientationExt,
+    VG: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn draw_child<D>(&self, idx: usize, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw_child(idx, display)
+    }
+}
+
+impl<C, LD, VG> Drawable for LinearLayout<LD, VG>
+where
+    C: PixelColor,
+    LD: OrientationExt,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+impl<C, LD, VG> LinearLayout<LD, VG>
+where
+    C: PixelColor,
+    LD: OrientationExt,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    /// Draw only the first `reveal` pixels of the layout along its primary axis.
+    ///
+    /// Drawing is clipped to the leading part of the bounds of the layout, e.g. the left `reveal`
+    /// columns of a horizontal layout. Increase `reveal` from frame to frame to animate a
+    /// progressive reveal. A `reveal` of 0 draws nothing, a `reveal` larger than the layout draws
+    /// all of it.
+    #[inline]
+    pub fn draw_revealed<D>(&self, reveal: u32, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let bounds = View::bounds(self);
+        let (primary, secondary) = LD::destructure_size(bounds.size);
+        let area = Rectangle::new(
+            bounds.top_left,
+            LD::create_size(reveal.min(primary), secondary),
+        );
+
+        self.views.draw(&mut display.clipped(&area))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        align::AlignmentPosition,
+        layout::linear::{
+            plan_linear_size,
+            spacing::{
+                ClosureSpacing, CollapseWithPadding, DistributeFill, ElementSpacing, FixedMargin,
+                ProportionalGap, Tight,
+            },
+            Horizontal, Justify, LinearLayout, Orientation, OrientationExt,
+        },
+        layout::{Axis, Constraints},
+        object_chain::Chain,
+        prelude::*,
+        utils::rounding::RoundingMode,
+        view_group::EmptyViewGroup,
+        views::{Margin, Padding},
+    };
+    use embedded_graphics::{
+        geometry::AnchorPoint,
+        mock_display::MockDisplay,
+        mono_font::{
+            ascii::{FONT_6X9, FONT_9X15},
+            MonoTextStyle,
+        },
+        pixelcolor::BinaryColor,
+        prelude::{Point, Primitive, Size},
+        primitives::{
+            Circle, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StrokeAlignment, Styled,
+        },
+        text::Text,
+        Drawable,
+    };
+
+    #[test]
+    #[allow(deprecated)]
+    fn orientation_without_extension() {
+        // An orientation implemented like before `OrientationExt` was added
+        #[derive(Copy, Clone)]
+        struct Diagonal;
+
+        impl Orientation for Diagonal {
+            type Secondary = vertical::Top;
+
+            fn destructure_size(size: Size) -> (u32, u32) {
+                (size.width, size.height)
+            }
+
+            fn create_size(primary: u32, secondary: u32) -> Size {
+                Size::new(primary, secondary)
+            }
+
+            fn compute_offset(
+                &self,
+                bounds: Rectangle,
+                _size: Size,
+                previous: Rectangle,
+                _n: usize,
+                _count: usize,
+            ) -> Point {
+                previous.bottom_right().unwrap_or(previous.top_left) + Point::new(1, 1)
+                    - bounds.top_left
+            }
+        }
+
+        let mut view = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let previous = Rectangle::new(Point::new(3, 3), Size::new(2, 2));
+        assert_eq!(
+            Rectangle::new(Point::new(5, 5), Size::new(2, 2)),
+            Diagonal.place(&mut view, Size::zero(), previous, 1, 2)
+        );
+
+        // `Horizontal` keeps implementing the deprecated methods through its spacing
+        let offset = Horizontal::default()
+            .with_spacing(FixedMargin(1))
+            .compute_offset(view, Size::new(4, 2), previous, 1, 2);
+        assert_eq!(Point::new(1, -2), offset);
+    }
+
+    #[allow(dead_code)]
+    fn compile_check() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 20)).into_styled(style);
+        let circ = Circle::new(Point::zero(), 10).into_styled(style);
+        let _ = LinearLayout::horizontal(Chain::new(rect).append(circ));
+    }
+
+    #[test]
+    fn layout_size() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let rect2 = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let size = LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .arrange()
+            .size();
+
+        assert_eq!(Size::new(20, 20), size);
+
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let rect2 = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let size = LinearLayout::vertical(Chain::new(rect).append(rect2))
+            .arrange()
+            .size();
+
+        assert_eq!(Size::new(10, 40), size);
+    }
+
+    #[test]
+    fn layout_arrange_vertical() {
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::vertical(Chain::new(rect).append(rect2))
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "           ",
+                "           ",
+                " ##########",
+                " #        #",
+                " #        #",
+                " #        #",
+                " ##########",
+                " #####     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #####     ",
+            ])
+        );
+    }
+
+    #[test]
     fn empty_rectangle_takes_up_no_vertical_space() {
         let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
 
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect_empty = Rectangle::new(Point::new(-50, 10), Size::zero()).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect_empty = Rectangle::new(Point::new(-50, 10), Size::zero()).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::vertical(Chain::new(rect).append(rect_empty).append(rect2))
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "           ",
+                "           ",
+                " ##########",
+                " #        #",
+                " #        #",
+                " #        #",
+                " ##########",
+                " #####     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #   #     ",
+                " #####     ",
+            ])
+        );
+    }
+
+    #[test]
+    fn empty_view_is_not_measured() {
+        use crate::view_group::ViewGroup;
+
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+        let empty = Rectangle::new(Point::new(-50, 100), Size::new(0, 20));
+
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(empty).append(rect))
+            .arrange()
+            .translate(Point::new(30, 30));
+
+        assert_eq!(
+            Rectangle::new(Point::new(30, 30), Size::new(20, 5)),
+            layout.bounds()
+        );
+        assert_eq!(Point::new(30, 30), layout.bounds_of(0).top_left);
+        assert_eq!(Point::new(40, 30), layout.bounds_of(2).top_left);
+    }
+
+    #[test]
+    fn layout_arrange_vertical_secondary() {
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::vertical(Chain::new(rect).append(rect2))
+            .with_alignment(horizontal::Right)
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "           ",
+                "           ",
+                " ##########",
+                " #        #",
+                " #        #",
+                " #        #",
+                " ##########",
+                "      #####",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #####",
+            ])
+        );
+    }
+
+    #[test]
+    fn layout_arrange_horizontal() {
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "                ",
+                "                ",
+                "           #####",
+                "           #   #",
+                "           #   #",
+                "           #   #",
+                "           #   #",
+                " ###########   #",
+                " #        ##   #",
+                " #        ##   #",
+                " #        ##   #",
+                " ###############",
+            ])
+        );
+    }
+
+    #[test]
+    fn empty_rectangle_takes_up_no_horizontal_space() {
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect_empty = Rectangle::new(Point::new(-50, 10), Size::zero()).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::horizontal(Chain::new(rect).append(rect_empty).append(rect2))
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "                ",
+                "                ",
+                "           #####",
+                "           #   #",
+                "           #   #",
+                "           #   #",
+                "           #   #",
+                " ###########   #",
+                " #        ##   #",
+                " #        ##   #",
+                " #        ##   #",
+                " ###############",
+            ])
+        );
+    }
+
+    #[test]
+    fn layout_arrange_horizontal_secondary() {
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .with_alignment(vertical::Top)
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "                ",
+                "                ",
+                " ###############",
+                " #        ##   #",
+                " #        ##   #",
+                " #        ##   #",
+                " ###########   #",
+                "           #   #",
+                "           #   #",
+                "           #   #",
+                "           #   #",
+                "           #####",
+            ])
+        );
+    }
+
+    #[test]
+    fn layout_spacing_size() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        let size = LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .with_spacing(FixedMargin(2))
+            .with_alignment(vertical::Top)
+            .arrange()
+            .size();
+
+        assert_eq!(Size::new(17, 10), size);
+
+        let size = LinearLayout::vertical(Chain::new(rect).append(rect2))
+            .with_spacing(FixedMargin(2))
+            .arrange()
+            .size();
+
+        assert_eq!(Size::new(10, 17), size);
+    }
+
+    #[test]
+    fn layout_spacing() {
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
+        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+
+        LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .with_spacing(FixedMargin(2))
+            .with_alignment(vertical::Top)
+            .arrange()
+            .translate(Point::new(1, 2))
+            .draw(&mut disp)
+            .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "                  ",
+                "                  ",
+                " ##########  #####",
+                " #        #  #   #",
+                " #        #  #   #",
+                " #        #  #   #",
+                " ##########  #   #",
+                "             #   #",
+                "             #   #",
+                "             #   #",
+                "             #   #",
+                "             #####",
+            ])
+        );
+    }
+
+    #[test]
+    fn layout_spacing_distribute_overflow() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5)).into_styled(style);
+
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+            .with_spacing(DistributeFill(11))
+            .with_alignment(vertical::TopToBottom)
+            .arrange();
+
+        assert_eq!(Size::new(11, 15), layout.size());
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        layout.arrange().draw(&mut disp).unwrap();
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "#####      ",
+                "#   #      ",
+                "#   #      ",
+                "#   #      ",
+                "#####      ",
+                "   #####   ",
+                "   #   #   ",
+                "   #   #   ",
+                "   #   #   ",
+                "   #####   ",
+                "      #####",
+                "      #   #",
+                "      #   #",
+                "      #   #",
+                "      #####",
+            ])
+        );
+    }
+
+    #[test]
+    fn layout_spacing_distribute_fill() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+
+        let view_group =
+            LinearLayout::vertical(Chain::new(rect).append(rect).append(rect).append(rect))
+                .with_spacing(DistributeFill(18))
+                .arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        view_group.draw(&mut disp).unwrap();
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "##            ",
+                "##            ",
+                "              ",
+                "              ",
+                "              ",
+                "              ",
+                "##            ",
+                "##            ",
+                "              ",
+                "              ",
+                "              ",
+                "##            ",
+                "##            ",
+                "              ",
+                "              ",
+                "              ",
+                "##            ",
+                "##            "
+            ])
+        );
+    }
+
+    #[test]
+    fn layout_spacing_closure() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let mut rects = [rect; 4];
+
+        let layout = LinearLayout::horizontal(Views::new(&mut rects))
+            .with_spacing(ClosureSpacing(|n| n as i32 * 2))
+            .arrange();
+
+        let views = layout.inner();
+        for n in 1..views.len() {
+            let gap = views[n].top_left.x - (views[n - 1].top_left.x + 2);
+            assert_eq!(n as i32 * 2, gap);
+        }
+    }
+
+    #[test]
+    fn arrange_measured_returns_bounds_size() {
+        let rect = Rectangle::new(Point::new(3, 7), Size::new(10, 20));
+        let rect2 = Rectangle::new(Point::zero(), Size::new(6, 5));
+
+        let (layout, size) = LinearLayout::vertical(Chain::new(rect).append(rect2))
+            .with_spacing(FixedMargin(2))
+            .arrange_measured();
+
+        assert_eq!(Size::new(10, 27), size);
+        assert_eq!(layout.bounds().size, size);
+    }
+
+    #[test]
+    fn default_presets() {
+        #[derive(Default)]
+        struct Config {
+            spacing: Tight,
+            margin: FixedMargin,
+            position: AlignmentPosition,
+        }
+
+        let config = Config::default();
+
+        assert_eq!(0, config.margin.0);
+        assert_eq!(AlignmentPosition::Start, config.position);
+        assert_eq!(
+            arrange_with(config.spacing, 2),
+            arrange_with(config.margin, 2)
+        );
+    }
+
+    /// Returns the left edges of three 4 pixel wide views justified within 20 pixels.
+    fn justified(mode: Justify) -> ([i32; 3], Size) {
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(4, 4)); 3];
+
+        let layout = LinearLayout::horizontal(Views::new(&mut rects))
+            .arrange()
+            .justify(20, mode);
+        let size = layout.size();
+
+        (rects.map(|rect| rect.top_left.x), size)
+    }
+
+    #[test]
+    fn justify_moves_cluster() {
+        assert_eq!(([0, 4, 8], Size::new(20, 4)), justified(Justify::Start));
+        assert_eq!(([4, 8, 12], Size::new(20, 4)), justified(Justify::Center));
+        assert_eq!(([8, 12, 16], Size::new(20, 4)), justified(Justify::End));
+    }
+
+    #[test]
+    fn justify_distributes_space() {
+        // The first and last views are pinned to the ends of the span
+        assert_eq!(
+            ([0, 8, 16], Size::new(20, 4)),
+            justified(Justify::SpaceBetween)
+        );
+        // 8 free pixels, 4/3 pixels before the first view and 8/3 between views
+        assert_eq!(
+            ([1, 8, 14], Size::new(20, 4)),
+            justified(Justify::SpaceAround)
+        );
+    }
+
+    #[test]
+    fn overflowing_justify_reports_views() {
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(4, 4)); 3];
 
-        LinearLayout::vertical(Chain::new(rect).append(rect_empty).append(rect2))
+        // The views are 2 pixels wider than the span, so they start a pixel before the layout
+        let layout = LinearLayout::horizontal(Views::new(&mut rects))
             .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
+            .translate(Point::new(5, 3))
+            .justify(10, Justify::Center);
 
         assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "           ",
-                "           ",
-                " ##########",
-                " #        #",
-                " #        #",
-                " #        #",
-                " ##########",
-                " #####     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #   #     ",
-                " #####     ",
-            ])
+            Rectangle::new(Point::new(4, 3), Size::new(12, 4)),
+            layout.bounds()
         );
+        assert_eq!(4, rects[0].top_left.x);
     }
 
     #[test]
-    fn layout_arrange_vertical_secondary() {
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+    fn justify_keeps_layout_position() {
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 2));
+        let layout = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .arrange()
+            .translate(Point::new(3, 5))
+            .justify(10, Justify::End);
 
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+        assert_eq!(
+            Rectangle::new(Point::new(3, 5), Size::new(4, 10)),
+            layout.bounds()
+        );
+        assert_eq!(Point::new(3, 11), layout.inner().parent.object.top_left);
+        assert_eq!(Point::new(3, 13), layout.inner().object.top_left);
+    }
 
-        LinearLayout::vertical(Chain::new(rect).append(rect2))
-            .with_alignment(horizontal::Right)
+    /// Arranges `count` 4x4 rectangles horizontally, returns their positions and the layout size.
+    fn arrange_with(spacing: impl ElementSpacing, count: usize) -> ([i32; 2], Size) {
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(4, 4)); 2];
+
+        let layout = LinearLayout::horizontal(Views::new(&mut rects[..count]))
+            .with_spacing(spacing)
+            .arrange();
+        let size = layout.size();
+
+        ([rects[0].top_left.x, rects[1].top_left.x], size)
+    }
+
+    #[test]
+    fn single_view_spacing() {
+        assert_eq!(([0, 0], Size::new(4, 4)), arrange_with(Tight, 1));
+        assert_eq!(([0, 0], Size::new(4, 4)), arrange_with(FixedMargin(3), 1));
+        assert_eq!(
+            ([0, 0], Size::new(4, 4)),
+            arrange_with(ClosureSpacing(|n| n as i32 * 5), 1)
+        );
+        // The single view is placed at the start, the layout still fills the track
+        assert_eq!(
+            ([0, 0], Size::new(20, 4)),
+            arrange_with(DistributeFill(20), 1)
+        );
+    }
+
+    #[test]
+    fn two_view_spacing() {
+        assert_eq!(([0, 4], Size::new(8, 4)), arrange_with(Tight, 2));
+        assert_eq!(([0, 7], Size::new(11, 4)), arrange_with(FixedMargin(3), 2));
+        assert_eq!(
+            ([0, 9], Size::new(13, 4)),
+            arrange_with(ClosureSpacing(|n| n as i32 * 5), 2)
+        );
+        assert_eq!(
+            ([0, 16], Size::new(20, 4)),
+            arrange_with(DistributeFill(20), 2)
+        );
+    }
+
+    #[test]
+    fn layout_spacing_proportional() {
+        let mut rects =
+            [10, 20, 40].map(|height| Rectangle::new(Point::zero(), Size::new(5, height)));
+
+        let size = LinearLayout::vertical(Views::new(&mut rects))
+            .with_spacing(ProportionalGap::new(250))
             .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
+            .size();
 
+        // 10 * 0.25 = 2.5 is rounded down, 20 * 0.25 = 5
+        assert_eq!(0, rects[0].top_left.y);
+        assert_eq!(12, rects[1].top_left.y);
+        assert_eq!(37, rects[2].top_left.y);
+        assert_eq!(Size::new(5, 77), size);
         assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "           ",
-                "           ",
-                " ##########",
-                " #        #",
-                " #        #",
-                " #        #",
-                " ##########",
-                "      #####",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #####",
-            ])
+            77,
+            plan_linear_size([10, 20, 40].into_iter(), ProportionalGap::new(250))
+        );
+    }
+
+    #[test]
+    fn layout_fixed_sizes() {
+        let mut rects = [
+            Rectangle::new(Point::new(3, 3), Size::new(5, 5)),
+            Rectangle::new(Point::zero(), Size::new(25, 5)),
+            Rectangle::new(Point::new(-7, 0), Size::new(1, 5)),
+        ];
+
+        let layout = LinearLayout::horizontal(Views::new(&mut rects))
+            .with_fixed_sizes(&[10, 20, 30])
+            .arrange();
+        assert_eq!(Size::new(35, 5), layout.size());
+
+        assert_eq!(0, rects[0].top_left.x);
+        assert_eq!(10, rects[1].top_left.x);
+        assert_eq!(30, rects[2].top_left.x);
+    }
+
+    #[test]
+    fn fixed_sizes_reuse_last_size() {
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(5, 5)); 4];
+
+        let layout = LinearLayout::vertical(Views::new(&mut rects))
+            .with_fixed_sizes(&[10, 20])
+            .arrange();
+        assert_eq!(Size::new(5, 55), layout.size());
+
+        assert_eq!([0, 10, 30, 50], [0, 1, 2, 3].map(|i| rects[i].top_left.y));
+    }
+
+    #[test]
+    fn arrange_group_releases_views() {
+        use embedded_graphics::{
+            mono_font::{ascii::FONT_6X9, MonoTextStyle},
+            text::Text,
+        };
+
+        let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let mut texts = [
+            Text::new("a", Point::new(20, 20), text_style),
+            Text::new("bc", Point::new(-5, 0), text_style),
+        ];
+
+        let info = LinearLayout::vertical(EmptyViewGroup)
+            .with_spacing(FixedMargin(1))
+            .arrange_group(&mut Views::new(&mut texts));
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(12, 19)),
+            info.bounds()
+        );
+        assert_eq!(2, info.len());
+
+        // Change the text without arranging it again
+        texts[0].text = "def";
+        let second = texts[1].bounds();
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        Views::new(&mut texts).draw(&mut display).unwrap();
+
+        assert_eq!(second, texts[1].bounds());
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(18, 9)),
+            texts[0].bounds()
+        );
+    }
+
+    #[test]
+    fn arrange_matches_arrange_group() {
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(4, 4)); 3];
+        let mut copy = rects;
+
+        let layout = LinearLayout::horizontal(Views::new(&mut rects))
+            .with_spacing(DistributeFill(30))
+            .translate(Point::new(2, 3));
+        let info = layout.arrange_group(&mut Views::new(&mut copy));
+        let layout = layout.arrange();
+
+        assert_eq!(layout.bounds(), info.bounds());
+        drop(layout);
+        assert_eq!(rects, copy);
+    }
+
+    #[test]
+    fn measure_rearranges_resized_views() {
+        /// Flows into as many 4px high lines as needed to fit the maximum width.
+        struct Flow {
+            bounds: Rectangle,
+            length: u32,
+        }
+
+        impl View for Flow {
+            fn translate_impl(&mut self, by: Point) {
+                self.bounds.top_left += by;
+            }
+
+            fn bounds(&self) -> Rectangle {
+                self.bounds
+            }
+
+            fn measure(&mut self, constraints: Constraints) -> Size {
+                let width = self.length.min(constraints.max.width).max(1);
+                let lines = (self.length + width - 1) / width;
+                self.bounds.size = Size::new(width, lines * 4);
+
+                constraints.constrain(self.bounds.size)
+            }
+        }
+
+        let flow = |length| Flow {
+            bounds: Rectangle::new(Point::zero(), Size::new(length, 4)),
+            length,
+        };
+        let mut layout = LinearLayout::vertical(Chain::new(flow(30)).append(flow(10))).arrange();
+        assert_eq!(Size::new(30, 8), layout.size());
+
+        let size = layout.measure(Constraints::loose(Size::new(12, 100)));
+
+        assert_eq!(Size::new(12, 16), size);
+        assert_eq!(Point::new(0, 12), layout.inner().object.bounds.top_left);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "doesn't define the size of the layout")]
+    fn distributing_spacing_without_total_size() {
+        use crate::align::Alignment;
+
+        /// Distributes views, but forgets to report the size it distributes them in.
+        #[derive(Copy, Clone)]
+        struct Spread;
+
+        impl ElementSpacing for Spread {
+            fn align(
+                &self,
+                alignment: impl Alignment,
+                view: Rectangle,
+                reference: Rectangle,
+                _n: usize,
+                _objects: usize,
+                _total_size: u32,
+            ) -> i32 {
+                alignment.align_with_offset(view, reference, 0)
+            }
+
+            fn requires_total_size(&self) -> bool {
+                true
+            }
+        }
+
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(4, 4)); 2];
+        let _ = LinearLayout::horizontal(Views::new(&mut rects))
+            .with_spacing(Spread)
+            .arrange();
+    }
+
+    #[test]
+    fn distributing_spacing_with_total_size() {
+        assert!(DistributeFill(20).requires_total_size());
+        assert!(!FixedMargin(2).requires_total_size());
+        assert!(super::Arrangement::requires_total_size(
+            &Justify::SpaceBetween
+        ));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "doesn't define the size of the layout")]
+    fn justify_spacing_without_span() {
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(4, 4)); 2];
+        let _ = LinearLayout::horizontal(Views::new(&mut rects))
+            .with_spacing(Justify::SpaceBetween)
+            .arrange();
+    }
+
+    #[test]
+    fn visible_range_follows_scrolling() {
+        let viewport = Rectangle::new(Point::zero(), Size::new(20, 30));
+        let mut rows = [Rectangle::new(Point::zero(), Size::new(20, 10)); 10];
+        let mut layout = LinearLayout::vertical(Views::new(&mut rows)).arrange();
+
+        assert_eq!(0..3, layout.visible_range(viewport));
+
+        layout.translate_mut(Point::new(0, -15));
+        assert_eq!(1..5, layout.visible_range(viewport));
+
+        layout.translate_mut(Point::new(0, -15));
+        assert_eq!(3..6, layout.visible_range(viewport));
+
+        layout.translate_mut(Point::new(0, -65));
+        assert_eq!(9..10, layout.visible_range(viewport));
+
+        layout.translate_mut(Point::new(0, -50));
+        assert!(layout.visible_range(viewport).is_empty());
+    }
+
+    #[test]
+    fn scroll_fractional_carries_remainder() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let mut layout = LinearLayout::vertical(Chain::new(rect).append(rect)).arrange();
+
+        layout.scroll_fractional(0.4);
+        assert_eq!(Point::zero(), layout.bounds().top_left);
+        assert_eq!(Point::zero(), layout.inner().parent.object.top_left);
+
+        layout.scroll_fractional(0.4);
+        assert_eq!(Point::zero(), layout.bounds().top_left);
+
+        layout.scroll_fractional(0.4);
+        assert_eq!(Point::new(0, 1), layout.bounds().top_left);
+        assert_eq!(Point::new(0, 1), layout.inner().parent.object.top_left);
+        assert_eq!(Point::new(0, 21), layout.inner().object.top_left);
+
+        let mut layout = LinearLayout::horizontal(Chain::new(rect).append(rect)).arrange();
+        layout.scroll_fractional(-1.5);
+        assert_eq!(Point::new(-1, 0), layout.bounds().top_left);
+        layout.scroll_fractional(-0.5);
+        assert_eq!(Point::new(-2, 0), layout.bounds().top_left);
+    }
+
+    #[test]
+    fn arrange_cached_skips_unchanged_sizes() {
+        use core::cell::Cell;
+        use embedded_graphics::{geometry::Dimensions, transform::Transform};
+
+        struct Counted<'a> {
+            bounds: Rectangle,
+            translations: &'a Cell<usize>,
+        }
+
+        impl Transform for Counted<'_> {
+            fn translate(&self, by: Point) -> Self {
+                Self {
+                    bounds: self.bounds.translate(by),
+                    translations: self.translations,
+                }
+            }
+
+            fn translate_mut(&mut self, by: Point) -> &mut Self {
+                self.translations.set(self.translations.get() + 1);
+                self.bounds.top_left += by;
+                self
+            }
+        }
+
+        impl Dimensions for Counted<'_> {
+            fn bounding_box(&self) -> Rectangle {
+                self.bounds
+            }
+        }
+
+        let translations = Cell::new(0);
+        let counted = |size| Counted {
+            bounds: Rectangle::new(Point::zero(), size),
+            translations: &translations,
+        };
+
+        let mut layout = LinearLayout::vertical(
+            Chain::new(counted(Size::new(10, 5))).append(counted(Size::new(5, 10))),
+        );
+
+        layout.arrange_cached();
+        let full_arrangement = translations.get();
+        assert_ne!(0, full_arrangement);
+        assert_eq!(Size::new(10, 15), layout.size());
+
+        // Cache hit, even if the layout has been moved since
+        translations.set(0);
+        layout.translate_mut(Point::new(3, 3));
+        let moved = translations.get();
+        layout.arrange_cached();
+        assert_eq!(moved, translations.get());
+        assert_eq!(Point::new(3, 3), layout.bounds().top_left);
+
+        // Changing the size of an element invalidates the cache
+        translations.set(0);
+        layout.inner_mut().object.bounds.size = Size::new(5, 20);
+        layout.arrange_cached();
+        assert_eq!(full_arrangement, translations.get());
+        assert_eq!(Size::new(10, 25), layout.size());
+
+        // Explicit invalidation
+        translations.set(0);
+        layout.invalidate();
+        layout.arrange_cached();
+        assert_eq!(full_arrangement, translations.get());
+    }
+
+    #[test]
+    fn layout_size_independent_of_view_location() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let rect2 = Rectangle::new(Point::zero(), Size::new(10, 20));
+
+        let size1 = LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .arrange()
+            .bounds()
+            .size();
+
+        let rect = rect.translate(Point::new(30, 50));
+
+        let size2 = LinearLayout::horizontal(Chain::new(rect).append(rect2))
+            .arrange()
+            .bounds()
+            .size();
+
+        assert_eq!(size1, size2);
+    }
+
+    #[test]
+    fn layout_axis() {
+        let rect = Rectangle::new(Point::zero(), Size::new(6, 4));
+
+        assert_eq!(
+            Axis::Horizontal,
+            LinearLayout::horizontal(Chain::new(rect)).axis()
+        );
+        assert_eq!(
+            Axis::Vertical,
+            LinearLayout::vertical(Chain::new(rect)).axis()
         );
     }
 
     #[test]
-    fn layout_arrange_horizontal() {
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+    fn uniform_cross_alignment_shares_right_edge() {
+        let mut rows = [
+            Rectangle::new(Point::new(3, 0), Size::new(5, 4)),
+            Rectangle::new(Point::zero(), Size::new(12, 4)),
+            Rectangle::new(Point::new(-7, 2), Size::new(8, 4)),
+        ];
+
+        let layout = LinearLayout::vertical(Views::new(&mut rows))
+            .with_alignment(horizontal::Right)
+            .with_uniform_cross_alignment()
+            .arrange();
+
+        let right_edge = layout.bounds().bottom_right().unwrap().x;
+        for row in layout.inner().iter() {
+            assert_eq!(right_edge, row.bottom_right().unwrap().x);
+        }
+        assert_eq!(Point::new(7, 0), layout.inner()[0].top_left);
+        assert_eq!(Point::new(0, 4), layout.inner()[1].top_left);
+        assert_eq!(Point::new(4, 8), layout.inner()[2].top_left);
+    }
+
+    #[test]
+    fn quantize_positions_keeps_order() {
+        let rect = Rectangle::new(Point::zero(), Size::new(6, 4));
+        let mut rects = [rect; 4];
+
+        let mut layout = LinearLayout::horizontal(Views::new(&mut rects)).arrange();
+        layout.quantize_positions(Size::new(8, 1), RoundingMode::Nearest);
+
+        let xs = layout
+            .inner()
+            .iter()
+            .map(|view| view.top_left.x)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 8, 16, 24], xs);
+        assert_eq!(Point::zero(), layout.bounds().top_left);
+    }
+
+    struct Shrinking {
+        bounds: Rectangle,
+    }
+
+    impl View for Shrinking {
+        fn translate_impl(&mut self, by: Point) {
+            self.bounds.translate_mut(by);
+        }
+
+        fn bounds(&self) -> Rectangle {
+            self.bounds
+        }
+
+        fn measure(&mut self, constraints: Constraints) -> Size {
+            self.bounds.size = constraints.constrain(self.bounds.size);
+            self.bounds.size
+        }
+    }
+
+    #[test]
+    fn arrange_into_shrinks_views_to_fit() {
+        let bar = |width| Shrinking {
+            bounds: Rectangle::new(Point::zero(), Size::new(width, 5)),
+        };
+
+        let result = LinearLayout::horizontal(Chain::new(bar(40)).append(bar(20)).append(bar(20)))
+            .with_spacing(FixedMargin(2))
+            .arrange_into(Rectangle::new(Point::new(3, 4), Size::new(44, 10)));
+
+        assert!(result.fits);
+        assert_eq!(
+            Rectangle::new(Point::new(3, 4), Size::new(44, 5)),
+            result.layout.bounds()
+        );
+
+        let views = result.layout.inner();
+        assert_eq!(
+            Rectangle::new(Point::new(3, 4), Size::new(20, 5)),
+            views.parent.parent.object.bounds
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(25, 4), Size::new(10, 5)),
+            views.parent.object.bounds
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(37, 4), Size::new(10, 5)),
+            views.object.bounds
+        );
+    }
+
+    #[test]
+    fn arrange_into_reports_overflow() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+
+        let fitting = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .arrange_into(Rectangle::new(Point::zero(), Size::new(10, 10)));
+        assert!(fitting.fits);
+
+        // Rectangles don't shrink
+        let too_short = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .arrange_into(Rectangle::new(Point::zero(), Size::new(10, 9)));
+        assert!(!too_short.fits);
+        assert_eq!(Size::new(10, 10), too_short.layout.size());
+
+        let too_narrow = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .arrange_into(Rectangle::new(Point::zero(), Size::new(9, 10)));
+        assert!(!too_narrow.fits);
+    }
+
+    #[test]
+    fn baseline_grid_mixed_fonts() {
+        let small = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let large = MonoTextStyle::new(&FONT_9X15, BinaryColor::On);
+        let mut texts = [
+            Text::new("Label", Point::zero(), small),
+            Text::new("Value", Point::zero(), large),
+            Text::new("unit", Point::zero(), small),
+        ];
+
+        let layout = LinearLayout::horizontal(Views::new(&mut texts))
+            .with_alignment(vertical::Top)
+            .with_baseline_grid(12)
+            .arrange();
+
+        let top = layout.bounds().top_left.y;
+        for text in layout.inner().iter() {
+            let bottom = text.bounds().bottom_right().unwrap().y;
+            assert_eq!(0, (bottom - top).rem_euclid(12));
+        }
+        assert_eq!(Size::new(99, 25), layout.size());
+    }
+
+    #[test]
+    fn baseline_grid_keeps_vertical_order() {
+        let mut rows = [
+            Rectangle::new(Point::zero(), Size::new(6, 5)),
+            Rectangle::new(Point::zero(), Size::new(6, 9)),
+        ];
+
+        let layout = LinearLayout::vertical(Views::new(&mut rows))
+            .with_baseline_grid(8)
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(0, 4), Size::new(6, 5)),
+            layout.inner()[0]
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(0, 16), Size::new(6, 9)),
+            layout.inner()[1]
+        );
+    }
+
+    fn scattered() -> [Rectangle; 3] {
+        [
+            Rectangle::new(Point::new(13, -4), Size::new(6, 4)),
+            Rectangle::new(Point::new(-20, 31), Size::new(10, 7)),
+            Rectangle::new(Point::new(2, 2), Size::new(3, 12)),
+        ]
+    }
+
+    fn assert_idempotent<LD, F>(configure: F)
+    where
+        LD: OrientationExt,
+        F: for<'a> FnOnce(Views<'a, Rectangle>) -> LinearLayout<LD, Views<'a, Rectangle>>,
+    {
+        let mut views = scattered();
+        let layout = configure(Views::new(&mut views)).arrange();
+        let once = layout.inner().iter().copied().collect::<Vec<_>>();
+
+        let layout = layout.arrange();
+        let twice = layout.inner().iter().copied().collect::<Vec<_>>();
+        assert_eq!(once, twice);
+
+        let by = Point::new(7, -3);
+        let layout = layout.translate(by).arrange();
+        let translated = layout.inner().iter().copied().collect::<Vec<_>>();
+        let expected = once.iter().map(|r| r.translate(by)).collect::<Vec<_>>();
+        assert_eq!(expected, translated);
+    }
+
+    #[test]
+    fn arrange_is_idempotent_horizontal() {
+        assert_idempotent(|views| LinearLayout::horizontal(views));
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_spacing(FixedMargin(3)));
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_spacing(FixedMargin(-2)));
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_spacing(DistributeFill(50)));
+        assert_idempotent(|views| {
+            LinearLayout::horizontal(views).with_spacing(ProportionalGap::new(250))
+        });
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_fixed_sizes(&[8, 12]));
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_alignment(vertical::Top));
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_alignment(vertical::Center));
+        assert_idempotent(|views| {
+            LinearLayout::horizontal(views).with_alignment(vertical::TopToBottom)
+        });
+        assert_idempotent(|views| {
+            LinearLayout::horizontal(views)
+                .with_alignment(vertical::BottomToTop)
+                .with_uniform_cross_alignment()
+        });
+        assert_idempotent(|views| LinearLayout::horizontal(views).with_baseline_grid(8));
+    }
+
+    #[test]
+    fn arrange_is_idempotent_vertical() {
+        assert_idempotent(|views| LinearLayout::vertical(views));
+        assert_idempotent(|views| LinearLayout::vertical(views).with_spacing(FixedMargin(3)));
+        assert_idempotent(|views| LinearLayout::vertical(views).with_spacing(DistributeFill(50)));
+        assert_idempotent(|views| LinearLayout::vertical(views).with_alignment(horizontal::Center));
+        assert_idempotent(|views| LinearLayout::vertical(views).with_alignment(horizontal::Right));
+        assert_idempotent(|views| {
+            LinearLayout::vertical(views).with_alignment(horizontal::LeftToRight)
+        });
+        assert_idempotent(|views| {
+            LinearLayout::vertical(views)
+                .with_alignment(horizontal::RightToLeft)
+                .with_uniform_cross_alignment()
+        });
+        assert_idempotent(|views| LinearLayout::vertical(views).with_baseline_grid(5));
+    }
 
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+    fn assert_positions_match<LD, F>(configure: F)
+    where
+        LD: OrientationExt,
+        F: for<'a> FnOnce(Views<'a, Rectangle>) -> LinearLayout<LD, Views<'a, Rectangle>>,
+    {
+        let mut views = scattered();
+        let layout = configure(Views::new(&mut views));
+        let predicted = layout.positions_for(3).collect::<Vec<_>>();
+
+        let layout = layout.arrange();
+        let arranged = layout
+            .inner()
+            .iter()
+            .map(|r| r.top_left)
+            .collect::<Vec<_>>();
+        assert_eq!(arranged, predicted);
+
+        let again = layout.positions_for(3).collect::<Vec<_>>();
+        assert_eq!(predicted, again);
+    }
 
-        LinearLayout::horizontal(Chain::new(rect).append(rect2))
-            .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
+    #[test]
+    fn positions_for_all_views_match_arrange() {
+        assert_positions_match(|views| LinearLayout::horizontal(views));
+        assert_positions_match(|views| {
+            LinearLayout::horizontal(views).with_spacing(DistributeFill(50))
+        });
+        assert_positions_match(|views| {
+            LinearLayout::horizontal(views)
+                .with_alignment(vertical::BottomToTop)
+                .with_uniform_cross_alignment()
+        });
+        assert_positions_match(|views| LinearLayout::horizontal(views).with_baseline_grid(8));
+        assert_positions_match(|views| {
+            LinearLayout::vertical(views).with_alignment(horizontal::Center)
+        });
+        assert_positions_match(|views| {
+            LinearLayout::vertical(views)
+                .with_alignment(horizontal::RightToLeft)
+                .with_uniform_cross_alignment()
+        });
+        assert_positions_match(|views| LinearLayout::vertical(views).with_baseline_grid(5));
+    }
+
+    #[test]
+    fn positions_for_one_more_view() {
+        let item = |height| Rectangle::new(Point::zero(), Size::new(20, height));
+        let arranged = |views: &mut [Rectangle], spacing| {
+            let layout = LinearLayout::vertical(Views::new(views))
+                .with_spacing(spacing)
+                .arrange()
+                .translate(Point::new(3, 10));
+            (0..layout.inner().len())
+                .map(|i| layout.bounds_of(i).top_left)
+                .collect::<Vec<_>>()
+        };
+
+        for spacing in [FixedMargin(2), FixedMargin(-1)] {
+            let mut three = [item(6), item(8), item(8)];
+            let expected = arranged(&mut three.clone(), spacing);
+            let expected_one_more = arranged(&mut [item(6), item(8), item(8), item(8)], spacing);
+
+            let layout = LinearLayout::vertical(Views::new(&mut three))
+                .with_spacing(spacing)
+                .arrange()
+                .translate(Point::new(3, 10));
+
+            // The extra view is a copy of the last one
+            let n = layout.positions_for(3).collect::<Vec<_>>();
+            let n_plus_one = layout.positions_for(4).collect::<Vec<_>>();
+            assert_eq!(expected, n);
+            assert_eq!(expected_one_more, n_plus_one);
+            assert_eq!(n[..], n_plus_one[..3]);
+
+            assert_eq!(n[..2], layout.positions_for(2).collect::<Vec<_>>()[..]);
+            assert_eq!(0, layout.positions_for(0).count());
+        }
+    }
 
+    #[test]
+    fn positions_for_distributed_views() {
+        let item = |height| Rectangle::new(Point::zero(), Size::new(20, height));
+        let mut three = [item(6), item(8), item(8)];
+        let mut four = [item(6), item(8), item(8), item(8)];
+
+        let layout =
+            LinearLayout::vertical(Views::new(&mut three)).with_spacing(DistributeFill(40));
+        let n = layout.positions_for(3).collect::<Vec<_>>();
+        let n_plus_one = layout.positions_for(4).collect::<Vec<_>>();
+
+        // Adding a view moves the others up to make room for it
+        let four = LinearLayout::vertical(Views::new(&mut four))
+            .with_spacing(DistributeFill(40))
+            .arrange();
         assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "                ",
-                "                ",
-                "           #####",
-                "           #   #",
-                "           #   #",
-                "           #   #",
-                "           #   #",
-                " ###########   #",
-                " #        ##   #",
-                " #        ##   #",
-                " #        ##   #",
-                " ###############",
-            ])
+            (0..4)
+                .map(|i| four.bounds_of(i).top_left)
+                .collect::<Vec<_>>(),
+            n_plus_one
+        );
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(0, 15), Point::new(0, 32)],
+            n
         );
+        assert_eq!(Point::new(0, 10), n_plus_one[1]);
     }
 
     #[test]
-    fn empty_rectangle_takes_up_no_horizontal_space() {
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+    fn positions_for_empty_layout() {
+        let layout = LinearLayout::horizontal(Views::<Rectangle>::new(&mut []));
+        assert_eq!(0, layout.positions_for(2).count());
+    }
 
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect_empty = Rectangle::new(Point::new(-50, 10), Size::zero()).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+    #[test]
+    fn collapse_with_padded_neighbours() {
+        let button = Rectangle::new(Point::zero(), Size::new(10, 4));
+        let gap = |first: Rectangle, second: Rectangle| {
+            second.top_left.y - first.bottom_right().unwrap().y - 1
+        };
+
+        for (base, expected) in [(1, 3), (3, 3), (5, 5), (-2, 3)] {
+            let layout = LinearLayout::vertical(
+                Chain::new(Padding::new(button, 3)).append(Padding::new(button, 2)),
+            )
+            .with_spacing(CollapseWithPadding(base))
+            .arrange();
 
-        LinearLayout::horizontal(Chain::new(rect).append(rect_empty).append(rect2))
-            .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
+            let chain = layout.inner();
+            assert_eq!(
+                expected,
+                gap(
+                    chain.parent.object.child_bounds(),
+                    chain.object.child_bounds()
+                )
+            );
+        }
 
+        // Only the insets along the primary axis are collapsed
+        let layout = LinearLayout::vertical(
+            Chain::new(Padding::each(0, 9, 4, 9, button)).append(Padding::each(6, 9, 0, 9, button)),
+        )
+        .with_spacing(CollapseWithPadding(2))
+        .arrange();
+        let chain = layout.inner();
         assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "                ",
-                "                ",
-                "           #####",
-                "           #   #",
-                "           #   #",
-                "           #   #",
-                "           #   #",
-                " ###########   #",
-                " #        ##   #",
-                " #        ##   #",
-                " #        ##   #",
-                " ###############",
-            ])
+            6,
+            gap(
+                chain.parent.object.child_bounds(),
+                chain.object.child_bounds()
+            )
         );
+        assert_eq!(Size::new(28, 14), layout.size());
     }
 
     #[test]
-    fn layout_arrange_horizontal_secondary() {
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
-
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
-
-        LinearLayout::horizontal(Chain::new(rect).append(rect2))
-            .with_alignment(vertical::Top)
-            .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
-
+    fn collapse_with_unpadded_neighbours() {
+        let button = Rectangle::new(Point::zero(), Size::new(4, 10));
+
+        let layout = LinearLayout::horizontal(
+            Chain::new(button)
+                .append(Padding::new(button, 3))
+                .append(button)
+                .append(button),
+        )
+        .with_spacing(CollapseWithPadding(2))
+        .arrange();
+
+        let chain = layout.inner();
+        let padded = chain.parent.parent.object.child_bounds();
+
+        // The padding is wider than the base margin on both sides of the padded view
+        assert_eq!(3, padded.top_left.x - 4);
         assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "                ",
-                "                ",
-                " ###############",
-                " #        ##   #",
-                " #        ##   #",
-                " #        ##   #",
-                " ###########   #",
-                "           #   #",
-                "           #   #",
-                "           #   #",
-                "           #   #",
-                "           #####",
-            ])
+            3,
+            chain.parent.object.top_left.x - padded.bottom_right().unwrap().x - 1
+        );
+        // Unpadded views are `base_margin` apart
+        assert_eq!(
+            2,
+            chain.object.top_left.x - chain.parent.object.bottom_right().unwrap().x - 1
         );
+        assert_eq!(Size::new(24, 16), layout.size());
     }
 
     #[test]
-    fn layout_spacing_size() {
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+    fn positions_for_keeps_margins() {
+        let mut items = [
+            Margin::new(Rectangle::new(Point::zero(), Size::new(20, 6)), 0, 4),
+            Margin::new(Rectangle::new(Point::zero(), Size::new(20, 8)), 3, 0),
+        ];
+
+        let layout = LinearLayout::vertical(Views::new(&mut items));
+        let positions = layout.positions_for(2).collect::<Vec<_>>();
+
+        let layout = layout.arrange();
+        assert_eq!(layout.inner()[1].bounds().top_left, positions[1]);
+        assert_eq!(Point::new(0, 10), positions[1]);
+    }
 
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+    #[test]
+    fn restore_position_same_size() {
+        let screen = Rectangle::new(Point::zero(), Size::new(64, 32));
+        let mut views = scattered();
 
-        let size = LinearLayout::horizontal(Chain::new(rect).append(rect2))
-            .with_spacing(FixedMargin(2))
-            .with_alignment(vertical::Top)
+        let mut layout = LinearLayout::horizontal(Views::new(&mut views))
             .arrange()
-            .size();
+            .align_to(&screen, horizontal::Center, vertical::Bottom);
+        let placed = layout.bounds();
+        assert_eq!(placed.top_left, layout.position());
+
+        // Content moved by hand, e.g. by an animation, is placed back by arranging the views
+        layout.inner_mut()[0].top_left = Point::new(-50, 50);
+        let mut layout = layout.arrange();
+        assert_eq!(placed, layout.bounds());
+
+        for anchor in [
+            AnchorPoint::TopLeft,
+            AnchorPoint::Center,
+            AnchorPoint::BottomRight,
+        ] {
+            layout.restore_position(anchor);
+            assert_eq!(placed, layout.bounds());
+        }
+    }
 
-        assert_eq!(Size::new(17, 10), size);
+    #[test]
+    fn restore_position_grown_content() {
+        let screen = Rectangle::new(Point::zero(), Size::new(64, 32));
+        let mut views = scattered();
 
-        let size = LinearLayout::vertical(Chain::new(rect).append(rect2))
-            .with_spacing(FixedMargin(2))
+        let mut layout = LinearLayout::vertical(Views::new(&mut views))
             .arrange()
-            .size();
+            .align_to(&screen, horizontal::Right, vertical::Bottom);
+        let placed = layout.bounds();
 
-        assert_eq!(Size::new(10, 17), size);
+        layout.inner_mut()[1].size = Size::new(14, 9);
+        let mut layout = layout.arrange();
+        assert_eq!(placed.top_left, layout.position());
+
+        layout.restore_position(AnchorPoint::BottomRight);
+        assert_eq!(placed.bottom_right(), layout.bounds().bottom_right());
+        assert_eq!(Size::new(14, 25), layout.size());
+
+        // Restoring again doesn't move the layout
+        layout.restore_position(AnchorPoint::TopLeft);
+        assert_eq!(placed.bottom_right(), layout.bounds().bottom_right());
     }
 
     #[test]
-    fn layout_spacing() {
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+    fn restore_position_without_placement() {
+        let mut views = scattered();
+        let mut layout = LinearLayout::vertical(Views::new(&mut views)).arrange();
+        let bounds = layout.bounds();
+
+        layout.restore_position(AnchorPoint::BottomRight);
+        assert_eq!(bounds, layout.bounds());
+        assert_eq!(Point::zero(), layout.position());
+    }
 
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+    #[test]
+    fn arrange_view_group_keeps_first_view() {
+        let mut views = scattered();
+        let layout = LinearLayout::horizontal(EmptyViewGroup).with_alignment(vertical::Center);
 
-        let rect = Rectangle::new(Point::new(10, 30), Size::new(10, 5)).into_styled(style);
-        let rect2 = Rectangle::new(Point::new(-50, 10), Size::new(5, 10)).into_styled(style);
+        layout.arrange_view_group(&mut Views::new(&mut views));
+        let once = views;
+        layout.arrange_view_group(&mut Views::new(&mut views));
 
-        LinearLayout::horizontal(Chain::new(rect).append(rect2))
-            .with_spacing(FixedMargin(2))
-            .with_alignment(vertical::Top)
+        assert_eq!(scattered()[0], views[0]);
+        assert_eq!(once, views);
+        assert_eq!(Point::new(19, -6), views[1].top_left);
+    }
+
+    #[test]
+    fn pinned_view_stays_centered() {
+        use crate::views::Pinned;
+
+        let button = |width| Rectangle::new(Point::zero(), Size::new(width, 6));
+
+        for (previous, next) in [(12, 8), (20, 3), (1, 30)] {
+            let bar = LinearLayout::horizontal(
+                Chain::new(button(previous))
+                    .append_pinned(button(10), AlignmentPosition::Center)
+                    .append(button(next)),
+            )
+            .with_span(64)
             .arrange()
-            .translate(Point::new(1, 2))
-            .draw(&mut disp)
-            .unwrap();
+            .translate(Point::new(5, 2));
+
+            let play: &Pinned<Rectangle> = &bar.inner().parent.object;
+            assert_eq!(Point::new(5 + 31, 2 + 2), play.bounds().center());
+
+            // The flowing views are placed around the pinned view
+            assert_eq!(Point::new(5, 2), bar.bounds_of(0).top_left);
+            assert_eq!(Point::new(5 + 37, 2), bar.bounds_of(2).top_left);
+            // The last view may extend past the span
+            assert_eq!(Size::new(64.max(37 + next), 6), bar.size());
+        }
+    }
 
+    #[test]
+    fn pinned_view_without_span() {
+        let item = |height| Rectangle::new(Point::zero(), Size::new(4, height));
+        let views = || {
+            Chain::new(item(6))
+                .append(item(2))
+                .append_pinned(item(4), AlignmentPosition::End)
+        };
+
+        let layout = LinearLayout::vertical(views());
+        let predicted = layout.positions_for(3).collect::<Vec<_>>();
+        let layout = layout.arrange();
+
+        // Pinned to the end of the combined length of 12 pixels
+        assert_eq!(Point::new(0, 8), layout.bounds_of(2).top_left);
         assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "                  ",
-                "                  ",
-                " ##########  #####",
-                " #        #  #   #",
-                " #        #  #   #",
-                " #        #  #   #",
-                " ##########  #   #",
-                "             #   #",
-                "             #   #",
-                "             #   #",
-                "             #   #",
-                "             #####",
-            ])
+            (0..3)
+                .map(|i| layout.bounds_of(i).top_left)
+                .collect::<Vec<_>>(),
+            predicted
         );
+
+        // With a spacing that defines the size of the layout
+        let layout = LinearLayout::vertical(views())
+            .with_spacing(DistributeFill(30))
+            .arrange();
+        assert_eq!(Point::new(0, 26), layout.bounds_of(2).top_left);
     }
 
     #[test]
-    fn layout_spacing_distribute_overflow() {
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+    fn arrange_positions_draws_like_arrange() {
+        type Item = Styled<Rectangle, PrimitiveStyle<BinaryColor>>;
+
+        fn draw_at(items: &[Item], positions: &[Point]) -> MockDisplay<BinaryColor> {
+            let mut display = MockDisplay::new();
+            for (item, position) in items.iter().zip(positions) {
+                item.draw_translated(*position - item.bounds().top_left)
+                    .draw(&mut display)
+                    .unwrap();
+            }
+            display
+        }
 
-        let rect = Rectangle::new(Point::zero(), Size::new(5, 5)).into_styled(style);
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let items = [
+            Rectangle::new(Point::new(5, 5), Size::new(3, 4)).into_styled(style),
+            Rectangle::new(Point::new(-2, 1), Size::new(6, 2)).into_styled(style),
+            Rectangle::new(Point::zero(), Size::new(1, 7)).into_styled(style),
+        ];
+
+        let mut positions = [Point::zero(); 3];
+        let size = LinearLayout::horizontal(EmptyViewGroup)
+            .with_alignment(vertical::Center)
+            .with_spacing(FixedMargin(2))
+            .arrange_positions(&items, &mut positions);
+        assert_eq!(Size::new(14, 7), size);
 
-        let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
-            .with_spacing(DistributeFill(11))
-            .with_alignment(vertical::TopToBottom)
-            .arrange();
+        let mut views = items;
+        let mut expected = MockDisplay::new();
+        LinearLayout::horizontal(Views::new(&mut views))
+            .with_alignment(vertical::Center)
+            .with_spacing(FixedMargin(2))
+            .arrange()
+            .draw(&mut expected)
+            .unwrap();
+        draw_at(&items, &positions).assert_eq(&expected);
 
-        assert_eq!(Size::new(11, 15), layout.size());
+        let size = LinearLayout::vertical(EmptyViewGroup)
+            .with_alignment(horizontal::Right)
+            .with_spacing(DistributeFill(20))
+            .arrange_positions(&items, &mut positions);
+        assert_eq!(Size::new(6, 20), size);
 
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        let mut views = items;
+        let mut expected = MockDisplay::new();
+        LinearLayout::vertical(Views::new(&mut views))
+            .with_alignment(horizontal::Right)
+            .with_spacing(DistributeFill(20))
+            .arrange()
+            .draw(&mut expected)
+            .unwrap();
+        draw_at(&items, &positions).assert_eq(&expected);
 
-        layout.arrange().draw(&mut disp).unwrap();
-        assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "#####      ",
-                "#   #      ",
-                "#   #      ",
-                "#   #      ",
-                "#####      ",
-                "   #####   ",
-                "   #   #   ",
-                "   #   #   ",
-                "   #   #   ",
-                "   #####   ",
-                "      #####",
-                "      #   #",
-                "      #   #",
-                "      #   #",
-                "      #####",
-            ])
-        );
+        // The items are not moved
+        assert_eq!(Point::new(5, 5), items[0].bounds().top_left);
     }
 
     #[test]
-    fn layout_spacing_distribute_fill() {
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-
-        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+    fn arrange_positions_without_items() {
+        let layout = LinearLayout::horizontal(EmptyViewGroup).with_span(10);
+        let items: [Rectangle; 0] = [];
 
-        let view_group =
-            LinearLayout::vertical(Chain::new(rect).append(rect).append(rect).append(rect))
-                .with_spacing(DistributeFill(18))
-                .arrange();
+        assert_eq!(Size::new(10, 0), layout.arrange_positions(&items, &mut []));
+    }
 
-        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+    #[test]
+    #[should_panic]
+    fn arrange_positions_length_mismatch() {
+        let items = [Rectangle::new(Point::zero(), Size::new(1, 1)); 2];
 
-        view_group.draw(&mut disp).unwrap();
-        assert_eq!(
-            disp,
-            MockDisplay::from_pattern(&[
-                "##            ",
-                "##            ",
-                "              ",
-                "              ",
-                "              ",
-                "              ",
-                "##            ",
-                "##            ",
-                "              ",
-                "              ",
-                "              ",
-                "##            ",
-                "##            ",
-                "              ",
-                "              ",
-                "              ",
-                "##            ",
-                "##            "
-            ])
-        );
+        LinearLayout::horizontal(EmptyViewGroup).arrange_positions(&items, &mut [Point::zero()]);
     }
 
     #[test]
-    fn layout_size_independent_of_view_location() {
-        let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
-        let rect2 = Rectangle::new(Point::zero(), Size::new(10, 20));
-
-        let size1 = LinearLayout::horizontal(Chain::new(rect).append(rect2))
+    fn draw_revealed_draws_the_leading_part() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let mut items = [Rectangle::new(Point::zero(), Size::new(3, 2)).into_styled(style); 3];
+        let layout = LinearLayout::horizontal(Views::new(&mut items))
+            .with_spacing(FixedMargin(1))
             .arrange()
-            .bounds()
-            .size();
+            .translate(Point::new(1, 0));
 
-        let rect = rect.translate(Point::new(30, 50));
+        let mut display = MockDisplay::new();
+        layout.draw_revealed(5, &mut display).unwrap();
+        display.assert_pattern(&[" ### #", " ### #"]);
 
-        let size2 = LinearLayout::horizontal(Chain::new(rect).append(rect2))
-            .arrange()
-            .bounds()
-            .size();
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw_revealed(0, &mut display).unwrap();
+        assert!(display.affected_area().is_zero_sized());
 
-        assert_eq!(size1, size2);
+        let mut display = MockDisplay::new();
+        layout.draw_revealed(100, &mut display).unwrap();
+        display.assert_pattern(&[" ### ### ###", " ### ### ###"]);
+    }
+
+    #[test]
+    fn outside_strokes_are_part_of_the_slot() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .fill_color(BinaryColor::Off)
+            .build();
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 1)).into_styled(style);
+
+        let layout =
+            LinearLayout::horizontal(Chain::new(rect).append(Padding::new(rect, 0))).arrange();
+
+        // The primitives are placed 4 pixels apart to make room for both strokes
+        assert_eq!(
+            Point::new(2, 2),
+            layout.inner().parent.object.primitive.top_left
+        );
+        assert_eq!(
+            Point::new(8, 2),
+            layout.inner().object.inner().primitive.top_left
+        );
+
+        // MockDisplay panics if a pixel is drawn twice
+        let mut display = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "############",
+            "############",
+            "##..####..##",
+            "############",
+            "############",
+        ]);
     }
 }
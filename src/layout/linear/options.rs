@@ -0,0 +1,28 @@
+/// The properties of a linear layout that don't depend on its orientation.
+///
+/// Shared by [`LinearLayout`] and [`LayoutPlan`], so that both arrange views the same way.
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`LayoutPlan`]: crate::layout::linear::LayoutPlan
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ArrangeOptions {
+    /// Align every view to the span of all views along the secondary axis.
+    pub uniform_cross_alignment: bool,
+
+    /// The pitch of the grid the bottom edges of the views are moved onto.
+    pub baseline_grid: Option<u32>,
+
+    /// The minimum length of the layout along its primary axis.
+    pub span: Option<u32>,
+}
+
+impl ArrangeOptions {
+    /// Options that arrange views like a plain layout.
+    pub const fn new() -> Self {
+        Self {
+            uniform_cross_alignment: false,
+            baseline_grid: None,
+            span: None,
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use crate::{
     align::{horizontal, vertical, Alignment, HorizontalAlignment, VerticalAlignment},
-    layout::linear::{
-        secondary_alignment::SecondaryAlignment,
-        spacing::{ElementSpacing, Tight},
+    layout::{
+        linear::{
+            arrangement::{Arrangement, Slot},
+            secondary_alignment::SecondaryAlignment,
+            spacing::Tight,
+        },
+        Axis,
     },
     View,
 };
@@ -23,6 +27,12 @@ pub trait Orientation: Copy + Clone {
     fn create_size(primary: u32, secondary: u32) -> Size;
 
     /// Computes translation for the next view.
+    ///
+    /// [`LinearLayout`] doesn't call this method, it places views through
+    /// [`OrientationExt::spacing`] instead.
+    ///
+    /// [`LinearLayout`]: crate::layout::linear::LinearLayout
+    #[deprecated(note = "views are placed along the primary axis by `OrientationExt::spacing`")]
     fn compute_offset(
         &self,
         bounds: Rectangle,
@@ -33,6 +43,7 @@ pub trait Orientation: Copy + Clone {
     ) -> Point;
 
     /// Place view
+    #[deprecated(note = "views are placed along the primary axis by `OrientationExt::spacing`")]
     #[inline]
     fn place(
         &self,
@@ -42,18 +53,117 @@ pub trait Orientation: Copy + Clone {
         n: usize,
         count: usize,
     ) -> Rectangle {
+        #[allow(deprecated)]
         let offset = self.compute_offset(view.bounds(), size, previous, n, count);
         view.translate_impl(offset);
         view.bounds()
     }
 }
 
+/// The parts of an [`Orientation`] that [`LinearLayout`] places views with.
+///
+/// Implemented by [`Horizontal`] and [`Vertical`]. These are separate from [`Orientation`], so
+/// that existing implementations of [`Orientation`] keep compiling.
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+pub trait OrientationExt: Orientation {
+    /// The arrangement that places the views along the primary axis
+    type Spacing: Arrangement;
+
+    /// The primary axis of the orientation
+    const AXIS: Axis;
+
+    /// Create a `Point` from primary and secondary coordinate values
+    fn create_point(primary: i32, secondary: i32) -> Point;
+
+    /// Returns the arrangement that places the views along the primary axis
+    fn spacing(&self) -> Self::Spacing;
+
+    /// Returns the size of the layout along the orientation, if the element spacing defines one.
+    #[inline]
+    fn track_size(&self) -> Option<u32> {
+        self.spacing().track_size()
+    }
+
+    /// Returns `true` if the element spacing requires the size of the layout to be given.
+    #[inline]
+    fn requires_total_size(&self) -> bool {
+        self.spacing().requires_total_size()
+    }
+}
+
+/// Implements the deprecated [`Orientation::compute_offset`] through [`OrientationExt::spacing`].
+///
+/// The position along the primary axis assumes that the layout starts at `previous` for the first
+/// view and at the origin for the others. Arrangements that don't place views relative to each
+/// other need to know where the layout starts, which this function doesn't.
+fn spacing_offset<LD: OrientationExt>(
+    direction: &LD,
+    bounds: Rectangle,
+    size: Size,
+    previous: Rectangle,
+    n: usize,
+    count: usize,
+) -> Point {
+    let (occupied, _) = LD::destructure_size(size);
+    let (extent, _) = LD::destructure_size(bounds.size);
+    let primary = primary_coordinate::<LD>(bounds.top_left);
+    let (start, previous_start, previous_extent) = if n == 0 {
+        (primary_coordinate::<LD>(previous.top_left), 0, 0)
+    } else {
+        (
+            0,
+            primary_coordinate::<LD>(previous.top_left),
+            LD::destructure_size(previous.size).0,
+        )
+    };
+
+    let position = direction.spacing().position(Slot {
+        n,
+        count,
+        extent,
+        occupied,
+        previous_start,
+        previous_extent,
+        track: direction.track_size(),
+        leading_margin: 0,
+        previous_trailing_margin: 0,
+    });
+
+    LD::create_point(
+        start + position - primary,
+        secondary_offset::<LD>(bounds, previous, n),
+    )
+}
+
+/// Returns the coordinate of `point` along the primary axis of `LD`.
+pub(crate) fn primary_coordinate<LD: OrientationExt>(point: Point) -> i32 {
+    match LD::AXIS {
+        Axis::Horizontal => point.x,
+        Axis::Vertical => point.y,
+    }
+}
+
+/// Returns the offset along the secondary axis that aligns the `n`th view with `bounds` to
+/// `previous`, the previous view, or the bounds of the layout for the first view.
+pub(crate) fn secondary_offset<LD: OrientationExt>(
+    bounds: Rectangle,
+    previous: Rectangle,
+    n: usize,
+) -> i32 {
+    if n == 0 {
+        <LD::Secondary as SecondaryAlignment>::First::default().align(bounds, previous)
+    } else {
+        LD::Secondary::default().align(bounds, previous)
+    }
+}
+
 /// Horizontal layout direction
 #[derive(Copy, Clone)]
 pub struct Horizontal<Secondary, Spacing = Tight>
 where
     Secondary: SecondaryAlignment + VerticalAlignment,
-    Spacing: ElementSpacing,
+    Spacing: Arrangement,
 {
     pub(crate) secondary: Secondary,
     pub(crate) spacing: Spacing,
@@ -62,7 +172,7 @@ where
 impl<Secondary, Spacing> Horizontal<Secondary, Spacing>
 where
     Secondary: SecondaryAlignment + VerticalAlignment,
-    Spacing: ElementSpacing,
+    Spacing: Arrangement,
 {
     /// Change secondary alignment
     #[inline]
@@ -78,7 +188,7 @@ where
 
     /// Change element spacing
     #[inline]
-    pub fn with_spacing<ElSpacing: ElementSpacing>(
+    pub fn with_spacing<ElSpacing: Arrangement>(
         self,
         spacing: ElSpacing,
     ) -> Horizontal<Secondary, ElSpacing> {
@@ -102,7 +212,7 @@ impl Default for Horizontal<vertical::Bottom, Tight> {
 impl<Secondary, Spacing> Orientation for Horizontal<Secondary, Spacing>
 where
     Secondary: SecondaryAlignment + VerticalAlignment,
-    Spacing: ElementSpacing,
+    Spacing: Arrangement,
 {
     type Secondary = Secondary;
 
@@ -125,27 +235,27 @@ where
         n: usize,
         count: usize,
     ) -> Point {
-        let (primary_size, _) = Self::destructure_size(size);
-
-        if n == 0 {
-            Point::new(
-                self.spacing
-                    .align(horizontal::Left, bounds, previous, n, count, primary_size),
-                Secondary::First::default().align(bounds, previous),
-            )
-        } else {
-            Point::new(
-                self.spacing.align(
-                    horizontal::LeftToRight,
-                    bounds,
-                    previous,
-                    n,
-                    count,
-                    primary_size,
-                ),
-                Secondary::default().align(bounds, previous),
-            )
-        }
+        spacing_offset(self, bounds, size, previous, n, count)
+    }
+}
+
+impl<Secondary, Spacing> OrientationExt for Horizontal<Secondary, Spacing>
+where
+    Secondary: SecondaryAlignment + VerticalAlignment,
+    Spacing: Arrangement,
+{
+    type Spacing = Spacing;
+
+    const AXIS: Axis = Axis::Horizontal;
+
+    #[inline]
+    fn create_point(primary: i32, secondary: i32) -> Point {
+        Point::new(primary, secondary)
+    }
+
+    #[inline]
+    fn spacing(&self) -> Spacing {
+        self.spacing
     }
 }
 
@@ -154,7 +264,7 @@ where
 pub struct Vertical<Secondary, Spacing = Tight>
 where
     Secondary: SecondaryAlignment + HorizontalAlignment,
-    Spacing: ElementSpacing,
+    Spacing: Arrangement,
 {
     pub(crate) secondary: Secondary,
     pub(crate) spacing: Spacing,
@@ -173,7 +283,7 @@ impl Default for Vertical<horizontal::Left, Tight> {
 impl<Secondary, Spacing> Vertical<Secondary, Spacing>
 where
     Secondary: SecondaryAlignment + HorizontalAlignment,
-    Spacing: ElementSpacing,
+    Spacing: Arrangement,
 {
     /// Change secondary alignment
     #[inline]
@@ -189,7 +299,7 @@ where
 
     /// Change element spacing
     #[inline]
-    pub fn with_spacing<ElSpacing: ElementSpacing>(
+    pub fn with_spacing<ElSpacing: Arrangement>(
         self,
         spacing: ElSpacing,
     ) -> Vertical<Secondary, ElSpacing> {
@@ -203,7 +313,7 @@ where
 impl<Secondary, Spacing> Orientation for Vertical<Secondary, Spacing>
 where
     Secondary: SecondaryAlignment + HorizontalAlignment,
-    Spacing: ElementSpacing,
+    Spacing: Arrangement,
 {
     type Secondary = Secondary;
 
@@ -226,26 +336,26 @@ where
         n: usize,
         count: usize,
     ) -> Point {
-        let (primary_size, _) = Self::destructure_size(size);
-
-        if n == 0 {
-            Point::new(
-                Secondary::First::default().align(bounds, previous),
-                self.spacing
-                    .align(vertical::Top, bounds, previous, n, count, primary_size),
-            )
-        } else {
-            Point::new(
-                Secondary::default().align(bounds, previous),
-                self.spacing.align(
-                    vertical::TopToBottom,
-                    bounds,
-                    previous,
-                    n,
-                    count,
-                    primary_size,
-                ),
-            )
-        }
+        spacing_offset(self, bounds, size, previous, n, count)
+    }
+}
+
+impl<Secondary, Spacing> OrientationExt for Vertical<Secondary, Spacing>
+where
+    Secondary: SecondaryAlignment + HorizontalAlignment,
+    Spacing: Arrangement,
+{
+    type Spacing = Spacing;
+
+    const AXIS: Axis = Axis::Vertical;
+
+    #[inline]
+    fn create_point(primary: i32, secondary: i32) -> Point {
+        Point::new(secondary, primary)
+    }
+
+    #[inline]
+    fn spacing(&self) -> Spacing {
+        self.spacing
     }
 }
@@ -0,0 +1,234 @@
+//! Placing the views of a linear layout one at a time
+use embedded_graphics::{
+    prelude::{Point, Size, Transform},
+    primitives::Rectangle,
+};
+
+use crate::{
+    align::{bottom_row, grid_offset, Alignment, AlignmentPosition},
+    layout::{
+        linear::{
+            orientation::OrientationExt, secondary_alignment::SecondaryAlignment, LinearLayout,
+        },
+        Axis,
+    },
+    utils::rect_helper::union_non_empty,
+    view_group::{inner_margins, Items, ViewGroup},
+};
+
+/// Computes where a linear layout places its items, one item at a time.
+///
+/// Each item is placed relative to the previous one, then aligned within the span of all items
+/// (if the layout aligns its views uniformly) and moved onto the baseline grid (if `snap` is set
+/// and the layout has one). The result doesn't depend on where the items are, only on their sizes,
+/// margins and pinning.
+pub(crate) struct Placement<'a, LD, VG> {
+    layout: &'a LinearLayout<LD, VG>,
+    start: Point,
+    size: Size,
+    count: usize,
+    pin_span: Option<Rectangle>,
+    cross_span: Option<Rectangle>,
+    snap: bool,
+    next: usize,
+    previous: Rectangle,
+    carry: i32,
+}
+
+impl<LD, VG> Clone for Placement<'_, LD, VG> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, LD, VG> Placement<'a, LD, VG>
+where
+    LD: OrientationExt,
+    VG: ViewGroup,
+{
+    /// Prepare placing `items`, starting at `start`.
+    pub(crate) fn new(
+        layout: &'a LinearLayout<LD, VG>,
+        items: &impl Items,
+        start: Point,
+        snap: bool,
+    ) -> Self {
+        let count = items.item_count();
+
+        // measure, ignoring empty views
+        let size = (0..count)
+            .map(|i| items.item_bounds(i))
+            .filter(|bounds| !bounds.is_zero_sized())
+            .map(|bounds| bounds.size)
+            .reduce(LD::Secondary::measure)
+            .unwrap_or_else(Size::zero);
+
+        let mut placement = Self {
+            layout,
+            start,
+            size,
+            count,
+            pin_span: layout.pin_span(items, 0, start),
+            cross_span: None,
+            snap: false,
+            next: 0,
+            previous: Rectangle::new(start, size),
+            carry: 0,
+        };
+
+        if layout.options.uniform_cross_alignment {
+            // The views are aligned within the space that they take up along the primary axis.
+            let mut dry_run = placement.clone();
+            placement.cross_span = (0..count)
+                .map(|_| dry_run.place_next(items))
+                .reduce(union_non_empty);
+        }
+
+        placement.snap = snap;
+        placement
+    }
+
+    /// Returns the bounds of the next item after it is placed, or `None` if every item is placed.
+    pub(crate) fn next(&mut self, items: &impl Items) -> Option<Rectangle> {
+        if self.next >= self.count {
+            return None;
+        }
+
+        let mut bounds = self.place_next(items);
+
+        if let Some(span) = self.cross_span {
+            let offset =
+                <LD::Secondary as SecondaryAlignment>::First::default().align(bounds, span);
+            bounds = bounds.translate(LD::create_point(0, offset));
+        }
+
+        if let Some(pitch) = self.layout.options.baseline_grid.filter(|_| self.snap) {
+            // Moving a view down moves every view after it in a vertical layout.
+            let offset = if bounds.is_zero_sized() {
+                0
+            } else {
+                grid_offset(
+                    bottom_row(bounds) + self.carry,
+                    pitch,
+                    self.layout.position.y,
+                )
+            };
+            bounds = bounds.translate(Point::new(0, self.carry + offset));
+
+            if LD::AXIS == Axis::Vertical {
+                self.carry += offset;
+            }
+        }
+
+        Some(bounds)
+    }
+
+    /// Places the next item relative to the previous one, along both axes.
+    fn place_next(&mut self, items: &impl Items) -> Rectangle {
+        let i = self.next;
+        let current = items.item_bounds(i);
+
+        let offset = self.layout.placement_offset(
+            items,
+            self.start,
+            self.size,
+            self.previous,
+            i,
+            self.count,
+        ) + LinearLayout::<LD, VG>::collapsed_margin(items, i);
+        let offset =
+            LinearLayout::<LD, VG>::pin(items.item_pinned(i), current, offset, self.pin_span);
+
+        self.previous = current.translate(offset);
+        self.next += 1;
+        self.previous
+    }
+}
+
+/// The views of a layout, repeated to preview a different number of views.
+///
+/// The items after the last view are copies of the last view. A view group without views has
+/// no items.
+struct Preview<'a, VG> {
+    views: &'a VG,
+    count: usize,
+}
+
+impl<VG> Preview<'_, VG>
+where
+    VG: ViewGroup,
+{
+    fn view(&self, idx: usize) -> usize {
+        idx.min(self.views.len() - 1)
+    }
+}
+
+impl<VG> Items for Preview<'_, VG>
+where
+    VG: ViewGroup,
+{
+    fn item_count(&self) -> usize {
+        if self.views.len() == 0 {
+            0
+        } else {
+            self.count
+        }
+    }
+
+    fn item_bounds(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(self.view(idx))
+    }
+
+    fn item_margins(&self, idx: usize) -> (u32, u32) {
+        self.views.at(self.view(idx)).margins()
+    }
+
+    fn item_pinned(&self, idx: usize) -> Option<AlignmentPosition> {
+        self.views.at(self.view(idx)).pinned()
+    }
+
+    fn item_inner_margins(&self, idx: usize, axis: Axis) -> (i32, i32) {
+        inner_margins(self.views.at(self.view(idx)), axis)
+    }
+}
+
+/// The positions returned by [`LinearLayout::positions_for`].
+///
+/// Yields the top left corner of each previewed view, in order.
+pub struct PreviewPositions<'a, LD, VG> {
+    placement: Placement<'a, LD, VG>,
+    preview: Preview<'a, VG>,
+}
+
+impl<'a, LD, VG> PreviewPositions<'a, LD, VG>
+where
+    LD: OrientationExt,
+    VG: ViewGroup,
+{
+    pub(crate) fn new(layout: &'a LinearLayout<LD, VG>, count: usize) -> Self {
+        let preview = Preview {
+            views: &layout.views,
+            count,
+        };
+
+        Self {
+            placement: Placement::new(layout, &preview, layout.position, true),
+            preview,
+        }
+    }
+}
+
+impl<LD, VG> Iterator for PreviewPositions<'_, LD, VG>
+where
+    LD: OrientationExt,
+    VG: ViewGroup,
+{
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Point> {
+        self.placement
+            .next(&self.preview)
+            .map(|bounds| bounds.top_left)
+    }
+}
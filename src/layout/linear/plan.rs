@@ -0,0 +1,412 @@
+use embedded_graphics::primitives::Rectangle;
+
+use crate::{
+    align::{horizontal, vertical, HorizontalAlignment, VerticalAlignment},
+    layout::linear::{
+        spacing::Tight, ArrangeOptions, Arrangement, Horizontal, LinearLayout, OrientationExt,
+        PlannedOffsets, SecondaryAlignment, Vertical,
+    },
+    view_group::{EmptyViewGroup, ViewGroup},
+};
+
+/// Error returned when a [`LayoutPlan`] is applied to a [`ViewGroup`] of the wrong length.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The number of views the plan was built for.
+    pub expected: usize,
+
+    /// The number of views in the view group.
+    pub actual: usize,
+}
+
+/// A linear layout description that is independent of the views it arranges.
+///
+/// A `LayoutPlan` holds the same properties as a [`LinearLayout`], but it does not own any views.
+/// Instead, it can be applied to any [`ViewGroup`] that contains exactly `N` views, which makes it
+/// possible to build the plan once and use it to arrange different sets of views.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::prelude::*;
+/// # use embedded_layout::layout::linear::{
+/// #     spacing::{FixedMargin, Tight},
+/// #     LayoutPlan, Vertical,
+/// # };
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// const PLAN: LayoutPlan<Vertical<horizontal::Left, Tight>, 2> = LayoutPlan::vertical();
+/// let plan = PLAN.with_spacing(FixedMargin(2));
+///
+/// let mut views = [
+///     Rectangle::new(Point::zero(), Size::new(10, 5)),
+///     Rectangle::new(Point::zero(), Size::new(10, 5)),
+/// ];
+///
+/// let bounds = plan.apply(&mut Views::new(&mut views)).unwrap();
+/// assert_eq!(Point::new(0, 7), bounds[1].top_left);
+/// ```
+#[derive(Copy, Clone)]
+pub struct LayoutPlan<LD, const N: usize> {
+    direction: LD,
+    options: ArrangeOptions,
+}
+
+impl<const N: usize> LayoutPlan<Horizontal<vertical::Bottom, Tight>, N> {
+    /// Create a new [`LayoutPlan`] that places views left to right
+    #[inline]
+    #[must_use]
+    pub const fn horizontal() -> Self {
+        Self {
+            direction: Horizontal {
+                secondary: vertical::Bottom,
+                spacing: Tight,
+            },
+            options: ArrangeOptions::new(),
+        }
+    }
+}
+
+impl<const N: usize> LayoutPlan<Vertical<horizontal::Left, Tight>, N> {
+    /// Create a new [`LayoutPlan`] that places views top to bottom
+    #[inline]
+    #[must_use]
+    pub const fn vertical() -> Self {
+        Self {
+            direction: Vertical {
+                secondary: horizontal::Left,
+                spacing: Tight,
+            },
+            options: ArrangeOptions::new(),
+        }
+    }
+}
+
+impl<S, ELS, const N: usize> LayoutPlan<Horizontal<S, ELS>, N>
+where
+    S: SecondaryAlignment + VerticalAlignment,
+    ELS: Arrangement,
+{
+    /// Change the secondary alignment for this [`LayoutPlan`] object.
+    ///
+    /// See [`LinearLayout::with_alignment`] for more information.
+    #[inline]
+    pub fn with_alignment<Sec>(self, alignment: Sec) -> LayoutPlan<Horizontal<Sec, ELS>, N>
+    where
+        Sec: SecondaryAlignment + VerticalAlignment,
+    {
+        LayoutPlan {
+            direction: self.direction.with_secondary_alignment(alignment),
+            options: self.options,
+        }
+    }
+
+    /// Change the element spacing
+    ///
+    /// See [`LinearLayout::with_spacing`] for more information.
+    #[inline]
+    pub fn with_spacing<ES>(self, spacing: ES) -> LayoutPlan<Horizontal<S, ES>, N>
+    where
+        ES: Arrangement,
+    {
+        LayoutPlan {
+            direction: self.direction.with_spacing(spacing),
+            options: self.options,
+        }
+    }
+}
+
+impl<S, ELS, const N: usize> LayoutPlan<Vertical<S, ELS>, N>
+where
+    S: SecondaryAlignment + HorizontalAlignment,
+    ELS: Arrangement,
+{
+    /// Change the secondary alignment for this [`LayoutPlan`] object.
+    ///
+    /// See [`LinearLayout::with_alignment`] for more information.
+    #[inline]
+    pub fn with_alignment<Sec>(self, alignment: Sec) -> LayoutPlan<Vertical<Sec, ELS>, N>
+    where
+        Sec: SecondaryAlignment + HorizontalAlignment,
+    {
+        LayoutPlan {
+            direction: self.direction.with_secondary_alignment(alignment),
+            options: self.options,
+        }
+    }
+
+    /// Change the element spacing
+    ///
+    /// See [`LinearLayout::with_spacing`] for more information.
+    #[inline]
+    pub fn with_spacing<ES>(self, spacing: ES) -> LayoutPlan<Vertical<S, ES>, N>
+    where
+        ES: Arrangement,
+    {
+        LayoutPlan {
+            direction: self.direction.with_spacing(spacing),
+            options: self.options,
+        }
+    }
+}
+
+impl<LD, const N: usize> LayoutPlan<LD, N>
+where
+    LD: OrientationExt,
+{
+    /// Align every view to a shared rectangle along the secondary axis.
+    ///
+    /// See [`LinearLayout::with_uniform_cross_alignment`] for more information.
+    #[inline]
+    #[must_use]
+    pub const fn with_uniform_cross_alignment(mut self) -> Self {
+        self.options.uniform_cross_alignment = true;
+        self
+    }
+
+    /// Make the arranged views at least `span` pixels long along the primary axis.
+    ///
+    /// See [`LinearLayout::with_span`] for more information.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: u32) -> Self {
+        self.options.span = Some(span);
+        self
+    }
+
+    /// Move the views down so that their bottom edges land on a grid.
+    ///
+    /// The grid starts at the top edge of the first view, before it is arranged. See
+    /// [`LinearLayout::with_baseline_grid`] for more information.
+    #[inline]
+    #[must_use]
+    pub const fn with_baseline_grid(mut self, pitch: u32) -> Self {
+        self.options.baseline_grid = Some(pitch);
+        self
+    }
+
+    /// Arrange the views of `view_group` in place and return their bounds.
+    ///
+    /// The arrangement starts at the top left corner of the first view. Returns an error
+    /// without touching the views if `view_group` does not contain exactly `N` views.
+    #[inline]
+    pub fn apply(&self, view_group: &mut impl ViewGroup) -> Result<[Rectangle; N], LengthMismatch> {
+        if view_group.len() != N {
+            return Err(LengthMismatch {
+                expected: N,
+                actual: view_group.len(),
+            });
+        }
+
+        let mut bounds = [Rectangle::zero(); N];
+        if N == 0 {
+            return Ok(bounds);
+        }
+
+        let position = view_group.bounds_of(0).top_left;
+        LinearLayout::from_parts(position, self.direction, EmptyViewGroup, self.options)
+            .place_group(view_group);
+
+        for (i, view_bounds) in bounds.iter_mut().enumerate() {
+            *view_bounds = view_group.bounds_of(i);
+        }
+
+        Ok(bounds)
+    }
+}
+
+/// Computes where a linear layout would place views of the given sizes, without any views.
+///
+/// `sizes` are the extents of the views along the primary axis of the layout, i.e. their widths
+/// for a horizontal layout or their heights for a vertical one. Views that are empty along the
+/// secondary axis don't take up any space and should be given as `0`.
+///
+/// Returns the position of each view along the primary axis, relative to the first view. The
+/// positions are the same as the ones [`LinearLayout::arrange`] produces for the same sizes and
+/// spacing. Use [`plan_linear_size`] to get the size of the arranged views.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::layout::linear::{plan_linear, spacing::FixedMargin};
+/// let rows = [12, 12, 12];
+///
+/// let mut offsets = [0; 3];
+/// for (offset, planned) in offsets.iter_mut().zip(plan_linear(rows.iter().copied(), FixedMargin(2))) {
+///     *offset = planned;
+/// }
+///
+/// assert_eq!([0, 14, 28], offsets);
+/// ```
+#[inline]
+pub fn plan_linear<I, S>(sizes: I, spacing: S) -> PlannedOffsets<I, S>
+where
+    I: Iterator<Item = u32> + Clone,
+    S: Arrangement,
+{
+    spacing.place(sizes, None)
+}
+
+/// Computes the size along the primary axis of a linear layout with views of the given sizes.
+///
+/// See [`plan_linear`] for more information.
+#[inline]
+pub fn plan_linear_size<I, S>(sizes: I, spacing: S) -> u32
+where
+    I: Iterator<Item = u32> + Clone,
+    S: Arrangement,
+{
+    let span = plan_linear(sizes.clone(), spacing)
+        .zip(sizes)
+        .filter(|&(_, size)| size != 0)
+        .fold(None, |span, (offset, size)| {
+            let end = offset + size as i32;
+            match span {
+                Some((start, span_end)) => Some((offset.min(start), end.max(span_end))),
+                None => Some((offset, end)),
+            }
+        });
+    let size = span.map_or(0, |(start, end)| (end - start) as u32);
+
+    match spacing.track_size() {
+        Some(track) => size.max(track),
+        None => size,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::spacing::{
+            ClosureSpacing, DistributeFill, ElementSpacing, FixedMargin, FixedSizes,
+            ProportionalGap,
+        },
+        prelude::*,
+        view_group::EmptyViewGroup,
+    };
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    #[test]
+    fn plan_applies_to_different_groups() {
+        let plan = LayoutPlan::<_, 3>::vertical()
+            .with_spacing(FixedMargin(2))
+            .with_alignment(horizontal::Center);
+
+        let mut small = [
+            Rectangle::new(Point::new(5, 5), Size::new(4, 3)),
+            Rectangle::new(Point::zero(), Size::new(2, 2)),
+            Rectangle::new(Point::zero(), Size::new(6, 1)),
+        ];
+        let mut large = Chain::new(Rectangle::new(Point::new(-10, 0), Size::new(20, 10)))
+            .append(Rectangle::new(Point::zero(), Size::new(10, 30)))
+            .append(Rectangle::new(Point::zero(), Size::new(30, 20)));
+
+        let small_bounds = plan.apply(&mut Views::new(&mut small)).unwrap();
+        let large_bounds = plan.apply(&mut large).unwrap();
+
+        for bounds in [small_bounds, large_bounds] {
+            for pair in bounds.windows(2) {
+                let previous_bottom = pair[0].top_left.y + pair[0].size.height as i32;
+                assert_eq!(previous_bottom + 2, pair[1].top_left.y);
+                assert_eq!(pair[0].center().x, pair[1].center().x);
+            }
+        }
+
+        assert_eq!(small_bounds[0].top_left, Point::new(6, 5));
+        assert_eq!(large_bounds[0].top_left, Point::new(-5, 0));
+        assert_eq!(small_bounds[2], small[2]);
+    }
+
+    #[test]
+    fn plan_rejects_wrong_length() {
+        let mut views = [Rectangle::new(Point::new(5, 5), Size::new(4, 3))];
+
+        let result = LayoutPlan::<_, 2>::horizontal().apply(&mut Views::new(&mut views));
+
+        assert_eq!(
+            Err(LengthMismatch {
+                expected: 2,
+                actual: 1
+            }),
+            result
+        );
+        assert_eq!(Point::new(5, 5), views[0].top_left);
+    }
+
+    #[test]
+    fn plan_uses_layout_options() {
+        fn views() -> [Rectangle; 3] {
+            [
+                Rectangle::new(Point::zero(), Size::new(5, 3)),
+                Rectangle::new(Point::zero(), Size::new(2, 6)),
+                Rectangle::new(Point::zero(), Size::new(6, 1)),
+            ]
+        }
+
+        let plan = LayoutPlan::<_, 3>::vertical().with_alignment(horizontal::Center);
+        let mut plain = views();
+        plan.apply(&mut Views::new(&mut plain)).unwrap();
+
+        let mut planned = views();
+        let bounds = plan
+            .with_uniform_cross_alignment()
+            .with_baseline_grid(4)
+            .apply(&mut Views::new(&mut planned))
+            .unwrap();
+
+        let mut arranged = views();
+        LinearLayout::vertical(EmptyViewGroup)
+            .with_alignment(horizontal::Center)
+            .with_uniform_cross_alignment()
+            .with_baseline_grid(4)
+            .arrange_group(&mut Views::new(&mut arranged));
+
+        assert_ne!(plain, planned);
+        assert_eq!(arranged, planned);
+        assert_eq!(arranged, bounds);
+    }
+
+    fn assert_plan_matches_arrange(spacing: impl ElementSpacing) {
+        const HEIGHTS: [u32; 5] = [12, 3, 0, 7, 12];
+
+        let mut views = HEIGHTS.map(|height| Rectangle::new(Point::zero(), Size::new(5, height)));
+        let layout = LinearLayout::vertical(Views::new(&mut views))
+            .with_spacing(spacing)
+            .arrange();
+        let size = layout.size().height;
+
+        let planned = plan_linear(HEIGHTS.iter().copied(), spacing);
+        for (view, offset) in views.iter().zip(planned) {
+            assert_eq!(view.top_left.y, offset);
+        }
+        assert_eq!(size, plan_linear_size(HEIGHTS.iter().copied(), spacing));
+    }
+
+    #[test]
+    fn plan_linear_agrees_with_arrange() {
+        assert_plan_matches_arrange(Tight);
+        assert_plan_matches_arrange(FixedMargin(3));
+        assert_plan_matches_arrange(FixedMargin(-2));
+        assert_plan_matches_arrange(ClosureSpacing(|n| n as i32));
+        assert_plan_matches_arrange(DistributeFill(50));
+        assert_plan_matches_arrange(DistributeFill(20));
+        assert_plan_matches_arrange(ProportionalGap::new(250));
+        assert_plan_matches_arrange(FixedSizes(&[15, 4]));
+    }
+
+    #[test]
+    fn plan_linear_single_and_no_views() {
+        assert_eq!(
+            Some(0),
+            plan_linear([12].into_iter(), FixedMargin(3)).next()
+        );
+        assert_eq!(12, plan_linear_size([12].into_iter(), FixedMargin(3)));
+        assert_eq!(30, plan_linear_size([12].into_iter(), DistributeFill(30)));
+
+        assert_eq!(None, plan_linear([].into_iter(), Tight).next());
+        assert_eq!(0, plan_linear_size([].into_iter(), Tight));
+    }
+}
@@ -8,7 +8,15 @@
 //!
 //! [`LinearLayout::with_spacing`]: crate::layout::linear::LinearLayout::with_spacing
 
-use crate::align::Alignment;
+use crate::{
+    align::Alignment,
+    layout::{
+        linear::{Arrangement, Slot},
+        Axis,
+    },
+    math::Fraction,
+    utils::rounding::split,
+};
 use embedded_graphics::primitives::Rectangle;
 
 /// `ElementSpacing` base trait
@@ -23,10 +31,52 @@ pub trait ElementSpacing: Copy + Clone {
         objects: usize,
         total_size: u32,
     ) -> i32;
+
+    /// Align `view` to the previous view, `reference`, using the element spacing rules
+    ///
+    /// Layouts call this method for every view except the first one. `previous_extent` is the size
+    /// of `reference` along the layout orientation. By default, this method calls
+    /// [`align`](ElementSpacing::align); spacings that depend on the size of the previous view
+    /// override it.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn align_after(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        previous_extent: u32,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        let _ = previous_extent;
+        self.align(alignment, view, reference, n, objects, total_size)
+    }
+
+    /// Returns the size the layout occupies along its orientation, if the spacing defines one.
+    ///
+    /// A layout is never smaller than this size, even if its views don't fill it, e.g. when there
+    /// is only a single view.
+    #[inline]
+    fn track_size(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns `true` if the spacing distributes views in a space of a given size.
+    ///
+    /// Such spacings can't derive the size of the layout from its views, so they must return the
+    /// size from [`track_size`](ElementSpacing::track_size). Layouts check this in debug builds.
+    #[inline]
+    fn requires_total_size(&self) -> bool {
+        false
+    }
 }
 
 /// Lay out objects tightly, leaving no space between them
 ///
+/// This is the default spacing.
+///
 /// # Example:
 /// ```rust
 /// use embedded_layout::{
@@ -44,7 +94,7 @@ pub trait ElementSpacing: Copy + Clone {
 ///     )
 ///     .with_spacing(Tight);
 /// ```
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Default)]
 pub struct Tight;
 impl ElementSpacing for Tight {
     #[inline]
@@ -63,7 +113,8 @@ impl ElementSpacing for Tight {
 
 /// Lay out objects with fixed margin between them
 ///
-/// The margin can be negative, in which case the elements will be placed over one another.
+/// The margin can be negative, in which case the elements will be placed over one another. The
+/// default margin is 0, which places the elements like [`Tight`].
 ///
 /// # Example:
 /// ```
@@ -83,7 +134,7 @@ impl ElementSpacing for Tight {
 ///     )
 ///     .with_spacing(FixedMargin(3));
 /// ```
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Default)]
 pub struct FixedMargin(pub i32);
 impl ElementSpacing for FixedMargin {
     #[inline]
@@ -101,9 +152,125 @@ impl ElementSpacing for FixedMargin {
     }
 }
 
+/// Whitespace that a view has inside its bounds, at its start and end along a layout axis.
+///
+/// [`CollapseWithPadding`] overlaps this whitespace with the gap between neighbouring views.
+/// Layouts find the implementation of a view through [`View::as_has_margins`]. Both margins are
+/// 0 by default.
+///
+/// [`View::as_has_margins`]: crate::View::as_has_margins
+pub trait HasMargins {
+    /// Returns the whitespace at the start of the view along `axis`, i.e. left or top.
+    #[inline]
+    fn leading_margin(&self, _axis: Axis) -> i32 {
+        0
+    }
+
+    /// Returns the whitespace at the end of the view along `axis`, i.e. right or bottom.
+    #[inline]
+    fn trailing_margin(&self, _axis: Axis) -> i32 {
+        0
+    }
+}
+
+/// Lay out objects with a margin that collapses with their own [margins](HasMargins)
+///
+/// The visible gap between two objects is the largest of the trailing margin of the first one,
+/// the leading margin of the second one and `base_margin`, instead of their sum. This is how CSS
+/// collapses margins: two [`Padding`]-wrapped views are placed so that their insets overlap, and
+/// views without margins are placed `base_margin` pixels apart like with [`FixedMargin`].
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::CollapseWithPadding, LinearLayout},
+///     prelude::*,
+///     views::Padding,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Rectangle};
+///
+/// let button = Rectangle::new(Point::zero(), Size::new(10, 4));
+///
+/// let layout = LinearLayout::horizontal(
+///     Chain::new(Padding::new(button, 3)).append(Padding::new(button, 2)),
+/// )
+/// .with_spacing(CollapseWithPadding(1))
+/// .arrange();
+///
+/// // The buttons are 3 pixels apart, not 3 + 1 + 2
+/// let first = layout.inner().parent.object.child_bounds();
+/// let second = layout.inner().object.child_bounds();
+/// assert_eq!(3, second.top_left.x - first.bottom_right().unwrap().x - 1);
+/// ```
+///
+/// [`Padding`]: crate::views::Padding
+#[derive(Copy, Clone, Default)]
+pub struct CollapseWithPadding(pub i32);
+impl Arrangement for CollapseWithPadding {
+    #[inline]
+    fn position(&self, slot: Slot) -> i32 {
+        if slot.n == 0 {
+            return 0;
+        }
+
+        let (before, after) = (slot.previous_trailing_margin, slot.leading_margin);
+        let gap = self.0.max(before).max(after) - before - after;
+
+        slot.previous_start + slot.previous_extent as i32 + gap
+    }
+}
+
+/// Lay out objects with a margin that depends on the index of the object
+///
+/// The closure receives the index of a view (starting from 1) and returns the margin between it
+/// and the previous view. The closure may be called any number of times during arrangement, so it
+/// must return the same margin every time it's called with the same index.
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::ClosureSpacing, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Fan the objects out with growing gaps
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(ClosureSpacing(|n| n as i32 * 2));
+/// ```
+#[derive(Copy, Clone)]
+pub struct ClosureSpacing<F>(pub F)
+where
+    F: Fn(usize) -> i32 + Copy;
+impl<F> ElementSpacing for ClosureSpacing<F>
+where
+    F: Fn(usize) -> i32 + Copy,
+{
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        _objects: usize,
+        _total_size: u32,
+    ) -> i32 {
+        let offset = if n == 0 { 0 } else { (self.0)(n) };
+        alignment.align_with_offset(view, reference, offset)
+    }
+}
+
 /// Distribute views to fill a given space
 ///
-/// Forces the layout to be as high or wide as set for this spacing
+/// Forces the layout to be as high or wide as set for this spacing. The first view is placed at
+/// the start of the space and the last view at the end. A single view is placed at the start.
 ///
 /// # Example:
 /// ```rust
@@ -136,18 +303,162 @@ impl ElementSpacing for DistributeFill {
         objects: usize,
         total_size: u32,
     ) -> i32 {
-        // bit of a mess, but calculate using i32 in case the views don't fit the space
+        if n == 0 || objects < 2 {
+            return alignment.align_with_offset(view, reference, 0);
+        }
+
+        // calculate using i32 in case the views don't fit the space
         let empty_space = self.0 as i32 - total_size as i32;
-        let base = empty_space / (objects - 1) as i32;
-        let remainder = empty_space % (objects - 1) as i32;
-
-        let offset = if n == 0 {
-            0
-        } else if n as i32 <= remainder {
-            base + 1
-        } else {
-            base
+        let (_, gap) = split(empty_space, (objects - 1) as i32, n as i32 - 1);
+
+        alignment.align_with_offset(view, reference, gap)
+    }
+
+    #[inline]
+    fn track_size(&self) -> Option<u32> {
+        Some(self.0)
+    }
+
+    #[inline]
+    fn requires_total_size(&self) -> bool {
+        true
+    }
+}
+
+/// Lay out objects with a gap proportional to the size of the previous object
+///
+/// The gap after each object is the given per-mille (1/1000) of the object's size along the
+/// layout orientation, rounded down. Bigger objects get more space after them.
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::ProportionalGap, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Rectangle};
+///
+/// let mut sections = [
+///     Rectangle::new(Point::zero(), Size::new(10, 20)),
+///     Rectangle::new(Point::zero(), Size::new(10, 40)),
+///     Rectangle::new(Point::zero(), Size::new(10, 10)),
+/// ];
+///
+/// // Leave a gap of 25% of the height of each section
+/// let _ = LinearLayout::vertical(Views::new(&mut sections))
+///     .with_spacing(ProportionalGap::new(250))
+///     .arrange();
+///
+/// assert_eq!(25, sections[1].top_left.y);
+/// assert_eq!(75, sections[2].top_left.y);
+/// ```
+#[derive(Copy, Clone)]
+pub struct ProportionalGap(pub Fraction);
+
+impl ProportionalGap {
+    /// Create a new [`ProportionalGap`]. Plain integers are per-mille values of the view size.
+    #[inline]
+    pub fn new(gap: impl Into<Fraction>) -> Self {
+        Self(gap.into())
+    }
+}
+
+impl ElementSpacing for ProportionalGap {
+    /// Align `view` to `reference` without a gap, as the size of the previous object is unknown.
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        _n: usize,
+        _objects: usize,
+        _total_size: u32,
+    ) -> i32 {
+        alignment.align_with_offset(view, reference, 0)
+    }
+
+    #[inline]
+    fn align_after(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        previous_extent: u32,
+        _n: usize,
+        _objects: usize,
+        _total_size: u32,
+    ) -> i32 {
+        let gap = self.0.scale(previous_extent);
+        alignment.align_with_offset(view, reference, gap as i32)
+    }
+}
+
+/// Lay out objects in slots of fixed lengths
+///
+/// Each object occupies the slot with the same index, regardless of its own size along the layout
+/// orientation. Objects are placed at the start of their slots. If there are more objects than
+/// slots, the last slot length is used for the remaining objects, and an empty list places the
+/// objects like [`Tight`].
+///
+/// See also [`LinearLayout::with_fixed_sizes`].
+///
+/// [`LinearLayout::with_fixed_sizes`]: crate::layout::linear::LinearLayout::with_fixed_sizes
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::FixedSizes, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Rectangle};
+///
+/// let mut segments = [Rectangle::new(Point::zero(), Size::new(4, 4)); 3];
+///
+/// let _ = LinearLayout::horizontal(Views::new(&mut segments))
+///     .with_spacing(FixedSizes(&[10, 20]))
+///     .arrange();
+///
+/// assert_eq!(10, segments[1].top_left.x);
+/// assert_eq!(30, segments[2].top_left.x);
+/// ```
+#[derive(Copy, Clone)]
+pub struct FixedSizes<'a>(pub &'a [u32]);
+impl FixedSizes<'_> {
+    fn slot(&self, n: usize) -> Option<u32> {
+        self.0.get(n).or_else(|| self.0.last()).copied()
+    }
+}
+impl ElementSpacing for FixedSizes<'_> {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        _n: usize,
+        _objects: usize,
+        _total_size: u32,
+    ) -> i32 {
+        alignment.align_with_offset(view, reference, 0)
+    }
+
+    #[inline]
+    fn align_after(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        previous_extent: u32,
+        n: usize,
+        _objects: usize,
+        _total_size: u32,
+    ) -> i32 {
+        // The previous view starts its slot, so the rest of the slot is the gap.
+        let gap = match self.slot(n - 1) {
+            Some(slot) => slot as i32 - previous_extent as i32,
+            None => 0,
         };
-        alignment.align_with_offset(view, reference, offset)
+        alignment.align_with_offset(view, reference, gap)
     }
 }
@@ -44,4 +44,199 @@
 //! [`ViewGroup`]: crate::view_group::ViewGroup
 //! [`LinearLayout`]: crate::layout::linear::LinearLayout
 
+use embedded_graphics::{prelude::Size, primitives::Rectangle};
+
+pub mod absolute;
+pub mod clock;
+pub mod constraint;
+pub mod grid;
 pub mod linear;
+pub mod overlap;
+pub mod tiles;
+pub mod virtual_list;
+
+/// The axis along which a layout places its views.
+///
+/// The primary axis is the one views are placed along, the cross axis is perpendicular to it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Views are placed left to right.
+    Horizontal,
+
+    /// Views are placed top to bottom.
+    Vertical,
+}
+
+impl Axis {
+    /// Returns the extent of `size` along the primary axis.
+    #[inline]
+    #[must_use]
+    pub const fn primary_size(&self, size: Size) -> u32 {
+        match self {
+            Axis::Horizontal => size.width,
+            Axis::Vertical => size.height,
+        }
+    }
+
+    /// Returns the extent of `size` along the cross axis.
+    #[inline]
+    #[must_use]
+    pub const fn cross_size(&self, size: Size) -> u32 {
+        match self {
+            Axis::Horizontal => size.height,
+            Axis::Vertical => size.width,
+        }
+    }
+
+    /// Returns the perpendicular axis.
+    #[inline]
+    #[must_use]
+    pub const fn cross(&self) -> Axis {
+        match self {
+            Axis::Horizontal => Axis::Vertical,
+            Axis::Vertical => Axis::Horizontal,
+        }
+    }
+}
+
+/// The result of arranging a group of views.
+///
+/// The arrangement info doesn't borrow the arranged views, so they can be changed and drawn while
+/// the info is kept around. The positions of the individual views are stored in the views
+/// themselves, e.g. use [`ViewGroup::bounds_of`] to query them.
+///
+/// [`ViewGroup::bounds_of`]: crate::view_group::ViewGroup::bounds_of
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ArrangementInfo {
+    bounds: Rectangle,
+    len: usize,
+}
+
+impl ArrangementInfo {
+    pub(crate) const fn new(bounds: Rectangle, len: usize) -> Self {
+        Self { bounds, len }
+    }
+
+    /// Returns the bounding box of the arranged views.
+    #[inline]
+    #[must_use]
+    pub const fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns the size of the arranged views.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> Size {
+        self.bounds.size
+    }
+
+    /// Returns the number of arranged views.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no views were arranged.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Size limits a parent gives to a child view when measuring it.
+///
+/// See [`View::measure`](crate::View::measure).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Constraints {
+    /// The minimum size of the view.
+    pub min: Size,
+
+    /// The maximum size of the view.
+    pub max: Size,
+}
+
+impl Constraints {
+    /// Creates constraints that allow any size between `min` and `max`.
+    #[inline]
+    #[must_use]
+    pub const fn new(min: Size, max: Size) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates constraints that allow any size up to `max`.
+    #[inline]
+    #[must_use]
+    pub const fn loose(max: Size) -> Self {
+        Self::new(Size::zero(), max)
+    }
+
+    /// Creates constraints that only allow `size`.
+    #[inline]
+    #[must_use]
+    pub const fn tight(size: Size) -> Self {
+        Self::new(size, size)
+    }
+
+    /// Creates constraints that allow any size.
+    #[inline]
+    #[must_use]
+    pub const fn unbounded() -> Self {
+        Self::loose(Size::new(u32::MAX, u32::MAX))
+    }
+
+    /// Returns the size closest to `size` that satisfies the constraints.
+    ///
+    /// If `min` is larger than `max` along an axis, `max` wins.
+    #[inline]
+    #[must_use]
+    pub fn constrain(&self, size: Size) -> Size {
+        size.component_max(self.min).component_min(self.max)
+    }
+
+    /// Returns `true` if `size` is between `min` and `max`.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, size: Size) -> bool {
+        self.constrain(size) == size
+    }
+}
+
+impl Default for Constraints {
+    #[inline]
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Axis, Constraints};
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn size_accessors() {
+        let size = Size::new(3, 5);
+
+        assert_eq!(3, Axis::Horizontal.primary_size(size));
+        assert_eq!(5, Axis::Horizontal.cross_size(size));
+        assert_eq!(5, Axis::Vertical.primary_size(size));
+        assert_eq!(3, Axis::Vertical.cross_size(size));
+        assert_eq!(Axis::Vertical, Axis::Horizontal.cross());
+    }
+
+    #[test]
+    fn constrain_clamps_size() {
+        let constraints = Constraints::new(Size::new(5, 5), Size::new(10, 20));
+
+        assert_eq!(Size::new(5, 7), constraints.constrain(Size::new(2, 7)));
+        assert_eq!(Size::new(10, 20), constraints.constrain(Size::new(30, 30)));
+        assert!(constraints.contains(Size::new(10, 5)));
+        assert!(!constraints.contains(Size::new(11, 5)));
+
+        let conflicting = Constraints::new(Size::new(8, 8), Size::new(4, 4));
+        assert_eq!(Size::new(4, 4), conflicting.constrain(Size::zero()));
+    }
+}
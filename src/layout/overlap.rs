@@ -0,0 +1,181 @@
+//! Overlap resolution
+//!
+//! Views that are placed by hand, e.g. dragged around by the user, may end up on top of each
+//! other. [`resolve_overlaps`] nudges them apart after they have been placed.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_layout::{layout::overlap::resolve_overlaps, prelude::*, view_group::Direction};
+//! # use embedded_graphics::{prelude::*, primitives::Rectangle};
+//! let widget = |x| Rectangle::new(Point::new(x, 0), Size::new(10, 10));
+//! let mut widgets = [widget(0), widget(4), widget(6)];
+//!
+//! let screen = Rectangle::new(Point::zero(), Size::new(64, 10));
+//! let resolved = resolve_overlaps(&mut Views::new(&mut widgets), screen, Direction::Right);
+//!
+//! assert!(resolved);
+//! assert_eq!([0, 10, 20], widgets.map(|widget| widget.top_left.x));
+//! ```
+use embedded_graphics::{prelude::Point, primitives::Rectangle};
+
+use crate::view_group::{Direction, ViewGroup};
+
+/// The maximum number of passes [`resolve_overlaps`] makes over the views.
+pub const MAX_PASSES: usize = 8;
+
+/// Move views apart until none of them overlap, keeping them inside `bounds`.
+///
+/// Every pair of views is checked in order. If the bounding boxes of two views overlap, the later
+/// one is moved in the given `direction`, just enough to clear the earlier one. The moved view is
+/// then clamped so that it stays inside `bounds`, or at the top left corner of `bounds` if it is
+/// larger.
+///
+/// Moving a view may create new overlaps, so the views are checked repeatedly until a pass moves
+/// nothing, but at most [`MAX_PASSES`] times. The result is not necessarily the smallest possible
+/// rearrangement, but it is deterministic.
+///
+/// Returns `true` if no views overlap afterwards. If the views don't fit into `bounds`, or more
+/// passes would be needed, some views are left overlapping and `false` is returned.
+#[inline]
+pub fn resolve_overlaps<VG>(views: &mut VG, bounds: Rectangle, direction: Direction) -> bool
+where
+    VG: ViewGroup + ?Sized,
+{
+    for _ in 0..MAX_PASSES {
+        let mut moved = false;
+
+        for later in 1..views.len() {
+            for earlier in 0..later {
+                let fixed = views.bounds_of(earlier);
+                let view = views.bounds_of(later);
+                if !overlap(fixed, view) {
+                    continue;
+                }
+
+                let target = clamp(push(fixed, view, direction), view, bounds);
+                let by = target - view.top_left;
+                if by != Point::zero() {
+                    views.translate_child(later, by);
+                    moved = true;
+                }
+            }
+        }
+
+        if !moved {
+            break;
+        }
+    }
+
+    !has_overlaps(views)
+}
+
+fn has_overlaps<VG>(views: &VG) -> bool
+where
+    VG: ViewGroup + ?Sized,
+{
+    (1..views.len()).any(|later| {
+        (0..later).any(|earlier| overlap(views.bounds_of(earlier), views.bounds_of(later)))
+    })
+}
+
+fn overlap(a: Rectangle, b: Rectangle) -> bool {
+    let intersection = a.intersection(&b);
+
+    intersection.size.width > 0 && intersection.size.height > 0
+}
+
+/// Returns the top left corner that places `view` next to `fixed`, in `direction`.
+fn push(fixed: Rectangle, view: Rectangle, direction: Direction) -> Point {
+    let Point { x, y } = view.top_left;
+
+    match direction {
+        Direction::Up => Point::new(x, fixed.top_left.y - view.size.height as i32),
+        Direction::Down => Point::new(x, fixed.top_left.y + fixed.size.height as i32),
+        Direction::Left => Point::new(fixed.top_left.x - view.size.width as i32, y),
+        Direction::Right => Point::new(fixed.top_left.x + fixed.size.width as i32, y),
+    }
+}
+
+/// Returns the point closest to `top_left` that keeps `view` inside `bounds`.
+fn clamp(top_left: Point, view: Rectangle, bounds: Rectangle) -> Point {
+    let clamp_axis = |value: i32, start: i32, extent: u32, size: u32| {
+        let end = start + extent.saturating_sub(size) as i32;
+        value.max(start).min(end)
+    };
+
+    Point::new(
+        clamp_axis(
+            top_left.x,
+            bounds.top_left.x,
+            bounds.size.width,
+            view.size.width,
+        ),
+        clamp_axis(
+            top_left.y,
+            bounds.top_left.y,
+            bounds.size.height,
+            view.size.height,
+        ),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    fn square(x: i32, y: i32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(10, 10))
+    }
+
+    #[test]
+    fn chain_of_overlaps_is_resolved() {
+        let mut views = [square(20, 20), square(24, 23), square(27, 18)];
+        let bounds = Rectangle::new(Point::zero(), Size::new(64, 64));
+
+        assert!(resolve_overlaps(
+            &mut Views::new(&mut views),
+            bounds,
+            Direction::Down
+        ));
+        assert_eq!([square(20, 20), square(24, 30), square(27, 40)], views);
+
+        let mut views = [square(20, 20), square(24, 23), square(27, 18)];
+        assert!(resolve_overlaps(
+            &mut Views::new(&mut views),
+            bounds,
+            Direction::Left
+        ));
+        assert_eq!([square(20, 20), square(10, 23), square(0, 18)], views);
+    }
+
+    #[test]
+    fn overlap_remains_when_bounds_are_too_small() {
+        let mut views = [square(0, 0), square(4, 0), square(6, 0)];
+        let bounds = Rectangle::new(Point::zero(), Size::new(25, 10));
+
+        assert!(!resolve_overlaps(
+            &mut Views::new(&mut views),
+            bounds,
+            Direction::Right
+        ));
+
+        // The last view is kept inside the bounds, overlapping the middle one
+        assert_eq!([square(0, 0), square(10, 0), square(15, 0)], views);
+    }
+
+    #[test]
+    fn touching_views_are_not_moved() {
+        let mut views = [square(0, 0), square(10, 0), square(0, 10)];
+        let bounds = Rectangle::new(Point::zero(), Size::new(20, 20));
+
+        assert!(resolve_overlaps(
+            &mut Views::new(&mut views),
+            bounds,
+            Direction::Right
+        ));
+        assert_eq!([square(0, 0), square(10, 0), square(0, 10)], views);
+    }
+}
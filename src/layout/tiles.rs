@@ -0,0 +1,137 @@
+//! Tiles
+//!
+//! Split a rectangle into a grid of equally sized tiles, e.g. the quadrants of a dashboard. The
+//! tiles cover the rectangle exactly: if the size of the rectangle is not divisible by the number
+//! of rows or columns, the first rows or columns are one pixel larger than the rest.
+//!
+//! Views can be placed into the tiles using the usual alignment methods.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_layout::{layout::tiles::tiles, prelude::*};
+//! # use embedded_graphics::{prelude::*, primitives::Rectangle};
+//! let display_area = Rectangle::new(Point::zero(), Size::new(127, 64));
+//! let mut quadrants = tiles(display_area, 2, 2);
+//!
+//! assert_eq!(Some(Rectangle::new(Point::new(0, 0), Size::new(64, 32))), quadrants.next());
+//! assert_eq!(Some(Rectangle::new(Point::new(64, 0), Size::new(63, 32))), quadrants.next());
+//! assert_eq!(Some(Rectangle::new(Point::new(0, 32), Size::new(64, 32))), quadrants.next());
+//! assert_eq!(Some(Rectangle::new(Point::new(64, 32), Size::new(63, 32))), quadrants.next());
+//! assert_eq!(None, quadrants.next());
+//! ```
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+use crate::utils::rounding;
+
+/// Split `bounds` into `rows` × `cols` tiles, in row-major order.
+///
+/// If `rows` or `cols` is 0, no tiles are returned.
+#[inline]
+pub fn tiles(bounds: Rectangle, rows: u32, cols: u32) -> impl Iterator<Item = Rectangle> {
+    tiles_with_gutter(bounds, rows, cols, 0)
+}
+
+/// Split `bounds` into `rows` × `cols` tiles separated by `gutter` pixels, in row-major order.
+///
+/// The gutters are only placed between tiles, not around them, and are taken out of the space
+/// of the tiles. If the gutters don't fit into `bounds`, the tiles are empty.
+#[inline]
+pub fn tiles_with_gutter(
+    bounds: Rectangle,
+    rows: u32,
+    cols: u32,
+    gutter: u32,
+) -> impl Iterator<Item = Rectangle> {
+    (0..rows).flat_map(move |row| {
+        let (y, height) = split(bounds.size.height, rows, gutter, row);
+
+        (0..cols).map(move |col| {
+            let (x, width) = split(bounds.size.width, cols, gutter, col);
+
+            Rectangle::new(bounds.top_left + Point::new(x, y), Size::new(width, height))
+        })
+    })
+}
+
+/// Returns the offset and the size of the `idx`th of `count` segments of `extent`.
+fn split(extent: u32, count: u32, gutter: u32, idx: u32) -> (i32, u32) {
+    let available = extent.saturating_sub(gutter.saturating_mul(count - 1));
+    let (offset, size) = rounding::split(available as i32, count as i32, idx as i32);
+
+    (offset + (idx * gutter) as i32, size as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Asserts that the tiles exactly cover `bounds`, leaving `gutter` pixels between them.
+    fn assert_exact_cover(bounds: Rectangle, rows: u32, cols: u32, gutter: u32) {
+        let tiles = tiles_with_gutter(bounds, rows, cols, gutter).collect::<Vec<_>>();
+        assert_eq!((rows * cols) as usize, tiles.len());
+
+        for row in tiles.chunks(cols as usize) {
+            let widths = row.iter().map(|tile| tile.size.width).sum::<u32>();
+            assert_eq!(bounds.size.width, widths + gutter * (cols - 1));
+
+            assert_eq!(bounds.top_left.x, row[0].top_left.x);
+            for pair in row.windows(2) {
+                let right = pair[0].top_left.x + pair[0].size.width as i32;
+                assert_eq!(right + gutter as i32, pair[1].top_left.x);
+                assert_eq!(pair[0].top_left.y, pair[1].top_left.y);
+                assert_eq!(pair[0].size.height, pair[1].size.height);
+            }
+        }
+
+        let column = tiles.iter().step_by(cols as usize).collect::<Vec<_>>();
+        let heights = column.iter().map(|tile| tile.size.height).sum::<u32>();
+        assert_eq!(bounds.size.height, heights + gutter * (rows - 1));
+
+        assert_eq!(bounds.top_left.y, column[0].top_left.y);
+        for pair in column.windows(2) {
+            let bottom = pair[0].top_left.y + pair[0].size.height as i32;
+            assert_eq!(bottom + gutter as i32, pair[1].top_left.y);
+        }
+    }
+
+    #[test]
+    fn tiles_cover_bounds() {
+        let bounds = Rectangle::new(Point::new(3, -2), Size::new(128, 64));
+
+        assert_exact_cover(bounds, 2, 2, 0);
+        assert_exact_cover(bounds, 2, 3, 0);
+        assert_exact_cover(bounds, 3, 5, 2);
+        assert_exact_cover(Rectangle::new(Point::zero(), Size::new(127, 61)), 4, 5, 2);
+    }
+
+    #[test]
+    fn remainder_goes_to_first_tiles() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(128, 10));
+
+        let widths = tiles_with_gutter(bounds, 1, 5, 2)
+            .map(|tile| tile.size.width)
+            .collect::<Vec<_>>();
+
+        // 128 - 4 * 2 = 120 pixels for 5 tiles
+        assert_eq!(vec![24, 24, 24, 24, 24], widths);
+
+        let widths = tiles_with_gutter(bounds, 1, 5, 3)
+            .map(|tile| tile.size.width)
+            .collect::<Vec<_>>();
+
+        // 128 - 4 * 3 = 116 pixels for 5 tiles
+        assert_eq!(vec![24, 23, 23, 23, 23], widths);
+    }
+
+    #[test]
+    fn no_tiles() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(128, 10));
+
+        assert_eq!(0, tiles(bounds, 0, 3).count());
+        assert_eq!(0, tiles(bounds, 3, 0).count());
+    }
+}
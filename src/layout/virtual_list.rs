@@ -0,0 +1,217 @@
+//! Virtual lists
+//!
+//! A virtual list displays a long list of rows using only a small pool of reusable row views.
+//! Only the rows that are visible in the viewport are configured, positioned and drawn, so a list
+//! of hundreds of entries needs no more memory than a single screen of rows.
+//!
+//! The pool must hold at least [`pool_size`] views for every row to be visible. The rows are
+//! configured by a `render_row(index, &mut row)` callback that is called in
+//! [`VirtualList::layout`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_layout::{layout::virtual_list::{pool_size, VirtualList}, prelude::*};
+//! # use embedded_graphics::{
+//! #     mock_display::MockDisplay,
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     primitives::Rectangle,
+//! #     text::{Baseline, Text},
+//! # };
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+//! const LINES: [&str; 5] = ["one", "two", "three", "four", "five"];
+//!
+//! let viewport = Rectangle::new(Point::zero(), Size::new(40, 20));
+//! let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let mut pool = [Text::with_baseline("", Point::zero(), style, Baseline::Top); pool_size(20, 9)];
+//! let mut list = VirtualList::new(&mut pool, viewport, 9, LINES.len());
+//!
+//! list.scroll_to(12);
+//! list.layout(|index, row| row.text = LINES[index]);
+//! list.draw(&mut display).unwrap();
+//! ```
+use core::ops::Range;
+
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// Returns the number of row views required to fill a viewport of the given height.
+///
+/// Partially visible rows at both the top and the bottom of the viewport need a view, too.
+#[inline]
+#[must_use]
+pub const fn pool_size(viewport_height: u32, row_height: u32) -> usize {
+    if row_height == 0 {
+        return 0;
+    }
+
+    (viewport_height / row_height) as usize + 2
+}
+
+/// A list of fixed height rows that reuses a pool of row views.
+///
+/// For more information and an example see the [module level documentation](crate::layout::virtual_list).
+pub struct VirtualList<'a, R> {
+    pool: &'a mut [R],
+    viewport: Rectangle,
+    row_height: u32,
+    count: usize,
+    offset: u32,
+    positioned: Range<usize>,
+}
+
+impl<'a, R> VirtualList<'a, R>
+where
+    R: View,
+{
+    /// Create a new virtual list of `count` rows, displayed in `viewport`.
+    #[inline]
+    pub fn new(pool: &'a mut [R], viewport: Rectangle, row_height: u32, count: usize) -> Self {
+        Self {
+            pool,
+            viewport,
+            row_height,
+            count,
+            offset: 0,
+            positioned: 0..0,
+        }
+    }
+
+    /// Set the scroll offset, i.e. the distance between the top of the first row and the top of
+    /// the viewport, in pixels.
+    #[inline]
+    pub fn scroll_to(&mut self, offset: u32) {
+        self.offset = offset;
+    }
+
+    /// Change the number of rows in the list.
+    #[inline]
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+    }
+
+    /// Returns the range of row indices that are at least partially visible at the current offset.
+    #[inline]
+    pub fn visible_range(&self) -> Range<usize> {
+        if self.row_height == 0 {
+            return 0..0;
+        }
+
+        let first = (self.offset / self.row_height) as usize;
+        let end = self.offset + self.viewport.size.height;
+        let last = ((end + self.row_height - 1) / self.row_height) as usize;
+
+        first.min(self.count)..last.min(self.count)
+    }
+
+    /// Configure and position the row views for the visible rows.
+    ///
+    /// `render_row` is called once for every visible row with the row index and the pool view that
+    /// displays it. Rows that don't fit into the pool are not displayed.
+    ///
+    /// Returns the number of positioned rows.
+    #[inline]
+    pub fn layout(&mut self, mut render_row: impl FnMut(usize, &mut R)) -> usize {
+        let visible = self.visible_range();
+        let positioned = visible.start..visible.end.min(visible.start + self.pool.len());
+
+        for index in positioned.clone() {
+            let row = &mut self.pool[index % self.pool.len()];
+            render_row(index, row);
+
+            let top = index as i32 * self.row_height as i32 - self.offset as i32;
+            let position = self.viewport.top_left + Point::new(0, top);
+            row.translate_impl(position - row.bounds().top_left);
+        }
+
+        self.positioned = positioned;
+        self.positioned.len()
+    }
+}
+
+impl<C, R> Drawable for VirtualList<'_, R>
+where
+    C: PixelColor,
+    R: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut display = display.clipped(&self.viewport);
+        for index in self.positioned.clone() {
+            self.pool[index % self.pool.len()].draw(&mut display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{prelude::Size, primitives::Rectangle};
+
+    #[test]
+    fn visible_range_at_boundaries() {
+        let mut pool = [Rectangle::zero(); pool_size(20, 10)];
+        let viewport = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let mut list = VirtualList::new(&mut pool, viewport, 10, 500);
+
+        assert_eq!(0..2, list.visible_range());
+
+        list.scroll_to(1);
+        assert_eq!(0..3, list.visible_range());
+
+        list.scroll_to(9);
+        assert_eq!(0..3, list.visible_range());
+
+        list.scroll_to(10);
+        assert_eq!(1..3, list.visible_range());
+
+        list.scroll_to(4985);
+        assert_eq!(498..500, list.visible_range());
+
+        list.scroll_to(5000);
+        assert_eq!(500..500, list.visible_range());
+    }
+
+    #[test]
+    fn layout_positions_at_most_pool_size_rows() {
+        let mut pool = [Rectangle::zero(); pool_size(25, 10)];
+        let viewport = Rectangle::new(Point::new(5, 5), Size::new(10, 25));
+        let mut list = VirtualList::new(&mut pool, viewport, 10, 500);
+
+        for offset in 0..100 {
+            list.scroll_to(offset);
+
+            let mut rendered = 0;
+            let positioned = list.layout(|_, row| {
+                row.size = Size::new(10, 10);
+                rendered += 1;
+            });
+
+            assert!(positioned <= 4);
+            assert_eq!(positioned, rendered);
+        }
+
+        list.scroll_to(15);
+        list.layout(|_, _| {});
+        assert_eq!(Point::new(5, 5 - 5), pool[1].top_left);
+        assert_eq!(Point::new(5, 5 + 5), pool[2].top_left);
+        assert_eq!(Point::new(5, 5 + 15), pool[3].top_left);
+    }
+}
@@ -17,6 +17,12 @@
 //!   * `Views` to create view groups from arrays and slices (can only hold views of a single type)
 //!   * `derive(ViewGroup)` to turn any plain old Rust struct into a view group
 //!
+//! Additionally, [views] contains decorators that change how a wrapped view is measured or drawn,
+//! and [widgets] contains common compositions of views, like an icon with a caption.
+//!
+//! The most commonly used items, including [`LinearLayout`] and its spacing types, are available
+//! from the [prelude].
+//!
 //! # Views
 //!
 //! The term "view" refers to anything `embedded-layout` can work with. Basically, a view is an
@@ -27,6 +33,16 @@
 //! [`View`] is implemented for [`embedded-graphics`] display objects. There's also an example about
 //! how you can implement custom [`View`] objects.
 //!
+//! ## Translation invariance
+//!
+//! Alignments, layouts and the decorators in [views] only depend on the relative positions of the
+//! objects involved. Moving every input by the same offset moves the result by exactly that
+//! offset, anywhere on the plane, including at negative coordinates. This makes it possible to
+//! stage content off-screen and slide it in without the arrangement shifting by a pixel.
+//!
+//! The exceptions are operations that refer to fixed coordinates by design, e.g. snapping to a
+//! pixel grid or [absolute positions].
+//!
 //! ## Examples
 //!
 //! The examples are based on [the `embedded-graphics` simulator]. The simulator is built on top of
@@ -70,7 +86,7 @@
 //!     prelude::*,
 //!     text::Text,
 //! };
-//! use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+//! use embedded_layout::prelude::*;
 //!
 //! let display_area = display.bounding_box();
 //!
@@ -88,39 +104,110 @@
 //! .unwrap();
 //! ```
 //!
+//! ### Nested layouts
+//!
+//! This is the example from the README, drawn to a `MockDisplay` instead of the simulator.
+//!
+//! ```
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+//! # display.set_allow_out_of_bounds_drawing(true);
+//! # display.set_allow_overdraw(true);
+//! #
+//! use embedded_graphics::{
+//!     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{Circle, PrimitiveStyle, Triangle},
+//!     text::Text,
+//! };
+//! use embedded_layout::prelude::*;
+//!
+//! // Create a Rectangle from the display's dimensions
+//! let display_area = display.bounding_box();
+//!
+//! // Style objects
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let thin_stroke = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+//! let thick_stroke = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+//! let fill_on = PrimitiveStyle::with_fill(BinaryColor::On);
+//! let fill_off = PrimitiveStyle::with_fill(BinaryColor::Off);
+//!
+//! // Primitives to be displayed
+//! let triangle = Triangle::new(Point::new(0, 0), Point::new(12, 0), Point::new(6, 12))
+//!     .into_styled(thin_stroke);
+//!
+//! let circle = Circle::new(Point::zero(), 11).into_styled(thick_stroke);
+//! let circle2 = Circle::new(Point::zero(), 15).into_styled(fill_on);
+//! let triangle2 =
+//!     Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(5, 8)).into_styled(fill_off);
+//! let text = Text::new("embedded-layout", Point::zero(), text_style);
+//!
+//! // The layout
+//! LinearLayout::vertical(
+//!     Chain::new(text)
+//!         .append(LinearLayout::horizontal(Chain::new(triangle).append(circle)).arrange())
+//!         .append(
+//!             Chain::new(triangle2.align_to(&circle2, horizontal::Center, vertical::Top))
+//!                 .append(circle2),
+//!         ),
+//! )
+//! .with_alignment(horizontal::Center)
+//! .arrange()
+//! .align_to(&display_area, horizontal::Center, vertical::Center)
+//! .draw(&mut display)
+//! .unwrap();
+//! ```
+//!
 //! [`embedded-graphics`]: https://crates.io/crates/embedded-graphics/0.6.2
 //! [the `embedded-graphics` simulator]: https://crates.io/crates/embedded-graphics-simulator/0.2.1
 //! [fully qualified syntax]: https://doc.rust-lang.org/book/ch19-03-advanced-traits.html#fully-qualified-syntax-for-disambiguation-calling-methods-with-the-same-name
 //! [`View`]: crate::View
-//! [layouts]: crate::layout
+//! [layouts]: mod@crate::layout
 //! [`LinearLayout`]: crate::layout::linear::LinearLayout
 //! [simulator README]: https://github.com/jamwaffles/embedded-graphics/tree/v0.6/simulator#usage-without-sdl2
 //! [alignments]: crate::align
 //! [view groups]: crate::view_group
+//! [views]: crate::views
+//! [widgets]: crate::widgets
+//! [prelude]: crate::prelude
+//! [absolute positions]: crate::layout::absolute::Position::Absolute
 
 #![cfg_attr(not(test), no_std)]
 #![deny(missing_docs)]
 #![deny(clippy::missing_inline_in_public_items)]
 #![warn(clippy::all)]
 
+use align::AlignmentPosition;
 use embedded_graphics::{geometry::Point, prelude::*, primitives::Rectangle};
+use layout::{linear::spacing::HasMargins, Constraints};
+use views::Revision;
 
 pub use embedded_layout_macros::ViewGroup;
 
 pub mod align;
 pub mod layout;
+pub mod math;
 pub mod object_chain;
+pub mod theme;
 pub mod utils;
 pub mod view_group;
+pub mod views;
+pub mod widgets;
 
 /// The essentials. Also contains most of `embedded-graphics'` prelude.
 pub mod prelude {
     pub use crate::{
-        align::{horizontal, vertical, Align},
+        align::{horizontal, vertical, Align, AlignmentPosition, ScreenEdge},
         chain,
+        layout::linear::{
+            spacing::{DistributeFill, FixedMargin, Tight},
+            LinearLayout,
+        },
         object_chain::{Chain, Link},
-        utils::rect_helper::RectExt,
-        view_group::Views,
+        utils::{rect_helper::RectExt, view_ext::ViewExt},
+        view_group::{ViewGroup, Views},
         View,
     };
 }
@@ -168,6 +255,174 @@ pub trait View {
 
     /// Returns the bounding box of the `View` as a `Rectangle`
     fn bounds(&self) -> Rectangle;
+
+    /// Returns the area of the `View` that reacts to touch input.
+    ///
+    /// By default, this is the same as [`bounds`](View::bounds). Wrappers like
+    /// [`ExpandHitArea`](crate::views::ExpandHitArea) may report a larger area.
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        self.bounds()
+    }
+
+    /// Returns `true` if the `View` is empty, i.e. its size is zero along either axis.
+    ///
+    /// Empty views are skipped when computing the combined bounding box of multiple views.
+    #[inline]
+    fn is_zero_sized(&self) -> bool {
+        let size = self.size();
+        size.width == 0 || size.height == 0
+    }
+
+    /// Returns the area of the bounding box of the `View`, in pixels.
+    ///
+    /// The area saturates at `u32::MAX`.
+    #[inline]
+    fn area(&self) -> u32 {
+        let size = self.size();
+        size.width.saturating_mul(size.height)
+    }
+
+    /// Returns the ratio of the width and the height of the bounding box of the `View`.
+    ///
+    /// Returns `f32::INFINITY` if the height is 0, including for views without any area.
+    #[inline]
+    fn aspect_ratio(&self) -> f32 {
+        let size = self.size();
+        if size.height == 0 {
+            f32::INFINITY
+        } else {
+            size.width as f32 / size.height as f32
+        }
+    }
+
+    /// Returns the z-index of the `View`, used to order overlapping views when drawing.
+    ///
+    /// Views are 0 by default, see [`ZOrdered`](crate::views::ZOrdered) and
+    /// [`DrawableViewGroup::draw_z_ordered`](crate::view_group::DrawableViewGroup::draw_z_ordered).
+    #[inline]
+    fn z_index(&self) -> i32 {
+        0
+    }
+
+    /// Returns the identifier of the `View`, used to match views across frames.
+    ///
+    /// Views have no identifier by default, see [`Identified`](crate::views::Identified) and
+    /// [`ViewGroup::diff_by_id`](crate::view_group::ViewGroup::diff_by_id).
+    #[inline]
+    fn id(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns the margins before and after the `View`, in pixels.
+    ///
+    /// A [`LinearLayout`](crate::layout::linear::LinearLayout) keeps this much space between the
+    /// `View` and its neighbours along its primary axis. Adjacent margins collapse: the gap
+    /// between two views is the larger of the two margins, not their sum.
+    ///
+    /// Views have no margins by default, see [`Margin`](crate::views::Margin).
+    #[inline]
+    fn margins(&self) -> (u32, u32) {
+        (0, 0)
+    }
+
+    /// Returns the whitespace that the `View` has inside its bounds, if it has any.
+    ///
+    /// A [`LinearLayout`](crate::layout::linear::LinearLayout) using
+    /// [`CollapseWithPadding`](crate::layout::linear::spacing::CollapseWithPadding) collapses
+    /// these margins with the gaps between views.
+    ///
+    /// Views have no such margins by default, see [`Padding`](crate::views::Padding).
+    #[inline]
+    fn as_has_margins(&self) -> Option<&dyn HasMargins> {
+        None
+    }
+
+    /// Returns where the `View` is pinned along the primary axis of a layout, if it is pinned.
+    ///
+    /// A [`LinearLayout`](crate::layout::linear::LinearLayout) places a pinned `View` at this
+    /// position of its span instead of after the previous view, and the views after it follow it.
+    ///
+    /// Views are not pinned by default, see [`Pinned`](crate::views::Pinned).
+    #[inline]
+    fn pinned(&self) -> Option<AlignmentPosition> {
+        None
+    }
+
+    /// Returns the revision of the `View`, which changes whenever the `View` or any view inside
+    /// it changes.
+    ///
+    /// Containers combine the revisions of their views with their own, which changes when views
+    /// are added, removed or arranged. Views don't track their changes by default, see
+    /// [`Tracked`](crate::views::Tracked).
+    #[inline]
+    fn revision(&self) -> Revision {
+        Revision::ZERO
+    }
+
+    /// Measure the `View` within the size limits given by its parent.
+    ///
+    /// Views that can adapt to the available space, e.g. by wrapping or truncating their
+    /// contents, change their size here. By default, the size of the bounding box is returned,
+    /// clamped to `constraints`, so the reported size never exceeds [`Constraints::max`], even if
+    /// the `View` is drawn larger.
+    ///
+    /// [`Constraints::max`]: crate::layout::Constraints::max
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        constraints.constrain(self.size())
+    }
+
+    /// Returns the smallest translation that moves the `View` fully inside `viewport`.
+    ///
+    /// The result is zero if the `View` is already fully visible. If the `View` is larger than
+    /// `viewport` along an axis, its start edge (left or top) is aligned to the start of `viewport`
+    /// along that axis.
+    #[inline]
+    fn scroll_into_view_offset(&self, viewport: Rectangle) -> Point {
+        fn axis(start: i32, size: u32, viewport_start: i32, viewport_size: u32) -> i32 {
+            let end = start + size as i32;
+            let viewport_end = viewport_start + viewport_size as i32;
+
+            if size > viewport_size || start < viewport_start {
+                viewport_start - start
+            } else if end > viewport_end {
+                viewport_end - end
+            } else {
+                0
+            }
+        }
+
+        let bounds = self.bounds();
+        Point::new(
+            axis(
+                bounds.top_left.x,
+                bounds.size.width,
+                viewport.top_left.x,
+                viewport.size.width,
+            ),
+            axis(
+                bounds.top_left.y,
+                bounds.size.height,
+                viewport.top_left.y,
+                viewport.size.height,
+            ),
+        )
+    }
+
+    /// Returns `true` if the bounding box of the `View` overlaps `rect`.
+    ///
+    /// A `View` with a zero sized bounding box intersects `rect` if its position is inside `rect`.
+    #[inline]
+    fn intersects(&self, rect: Rectangle) -> bool {
+        let bounds = self.bounds();
+        if bounds.is_zero_sized() {
+            return rect.contains(bounds.top_left);
+        }
+
+        let intersection = bounds.intersection(&rect);
+        intersection.size.width > 0 && intersection.size.height > 0
+    }
 }
 
 impl<T> View for T
@@ -189,6 +444,73 @@ where
 mod test {
     use crate::prelude::*;
 
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
     #[allow(dead_code)]
     fn view_is_object_safe(_: &dyn View) {}
+
+    #[test]
+    fn intersects() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(10, 10));
+
+        let overlapping = Rectangle::new(Point::new(19, 5), Size::new(5, 6));
+        let touching = Rectangle::new(Point::new(20, 10), Size::new(5, 5));
+        let disjoint = Rectangle::new(Point::new(30, 30), Size::new(5, 5));
+
+        assert!(overlapping.intersects(area));
+        assert!(!touching.intersects(area));
+        assert!(!disjoint.intersects(area));
+    }
+
+    #[test]
+    fn zero_sized_view_intersects_if_inside() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(10, 10));
+
+        assert!(Rectangle::new(Point::new(15, 15), Size::zero()).intersects(area));
+        assert!(Rectangle::new(Point::new(19, 10), Size::new(0, 5)).intersects(area));
+        assert!(!Rectangle::new(Point::new(20, 15), Size::zero()).intersects(area));
+    }
+
+    #[test]
+    fn area_and_aspect_ratio() {
+        let square = Rectangle::new(Point::new(-3, 2), Size::new(7, 7));
+        let wide = Rectangle::new(Point::zero(), Size::new(30, 12));
+        let flat = Rectangle::new(Point::zero(), Size::new(8, 0));
+
+        assert_eq!(49, square.area());
+        assert_eq!(1.0, square.aspect_ratio());
+        assert_eq!(360, wide.area());
+        assert_eq!(2.5, wide.aspect_ratio());
+        assert_eq!(0, flat.area());
+        assert_eq!(f32::INFINITY, flat.aspect_ratio());
+        assert_eq!(f32::INFINITY, Rectangle::zero().aspect_ratio());
+        assert_eq!(
+            u32::MAX,
+            Rectangle::new(Point::zero(), Size::new(u32::MAX, 2)).area()
+        );
+    }
+
+    #[test]
+    fn scroll_into_view_offset() {
+        let viewport = Rectangle::new(Point::new(0, 10), Size::new(20, 20));
+
+        let visible = Rectangle::new(Point::new(5, 15), Size::new(5, 5));
+        let off_top = Rectangle::new(Point::new(5, 2), Size::new(5, 5));
+        let off_right = Rectangle::new(Point::new(18, 12), Size::new(5, 5));
+        let oversized = Rectangle::new(Point::new(-4, 25), Size::new(30, 5));
+
+        assert_eq!(Point::zero(), visible.scroll_into_view_offset(viewport));
+        assert_eq!(Point::new(0, 8), off_top.scroll_into_view_offset(viewport));
+        assert_eq!(
+            Point::new(-3, 0),
+            off_right.scroll_into_view_offset(viewport)
+        );
+        assert_eq!(
+            Point::new(4, 0),
+            oversized.scroll_into_view_offset(viewport)
+        );
+    }
 }
@@ -0,0 +1,241 @@
+//! Fixed-point helpers
+//!
+//! Placing and aligning views doesn't use floating point numbers, so it works the same on targets
+//! without an FPU. Fractional values, like the position of a view relative to its container, the
+//! gap of a [`ProportionalGap`] or the progress of a [`Transition`], are given as a [`Fraction`].
+//!
+//! A few APIs do use `f32`: [`LinearLayout::scroll_fractional`] accumulates scroll offsets,
+//! [`View::aspect_ratio`] returns a ratio, and [`TightArc`] computes the extent of an arc from its
+//! angles, which embedded-graphics stores as `f32`. On targets without an FPU these are emulated
+//! in software.
+//!
+//! APIs that take a fraction accept `impl Into<Fraction>`, and plain integers are converted as
+//! per-mille (1/1000) values, so `500` is one half.
+//!
+//! [`ProportionalGap`]: crate::layout::linear::spacing::ProportionalGap
+//! [`Transition`]: crate::views::Transition
+//! [`LinearLayout::scroll_fractional`]: crate::layout::linear::LinearLayout::scroll_fractional
+//! [`View::aspect_ratio`]: crate::View::aspect_ratio
+//! [`TightArc`]: crate::views::TightArc
+
+use core::cmp::Ordering;
+
+/// A non-negative rational number, used to scale pixel distances.
+///
+/// The numerator and the denominator are kept as given, so per-mille and other decimal fractions
+/// are exact. Fractions are compared by their value, e.g. `1/2` is equal to `500/1000`.
+///
+/// Scaling rounds towards zero, unless noted otherwise, and saturates instead of overflowing.
+///
+/// # Example
+///
+/// ```rust
+/// use embedded_layout::math::Fraction;
+///
+/// let third = Fraction::new(1, 3);
+/// assert_eq!(33, third.scale(100));
+/// assert_eq!(-33, third.scale_signed(-100));
+///
+/// // Integers are per-mille values
+/// assert_eq!(Fraction::new(1, 4), Fraction::from(250));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Fraction {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Fraction {
+    /// Zero
+    pub const ZERO: Self = Self::new(0, 1);
+
+    /// One, i.e. the whole distance
+    pub const ONE: Self = Self::new(1, 1);
+
+    /// Create a new fraction with the value `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0.
+    #[inline]
+    pub const fn new(numerator: u32, denominator: u32) -> Self {
+        assert!(
+            denominator != 0,
+            "the denominator of a fraction must not be 0"
+        );
+
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Create a new fraction from a per-mille (1/1000) value.
+    #[inline]
+    pub const fn per_mille(per_mille: u32) -> Self {
+        Self::new(per_mille, 1000)
+    }
+
+    /// Returns the numerator of the fraction.
+    #[inline]
+    pub const fn numerator(self) -> u32 {
+        self.numerator
+    }
+
+    /// Returns the denominator of the fraction.
+    #[inline]
+    pub const fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Returns `value` multiplied by the fraction, rounded towards zero.
+    #[inline]
+    pub fn scale(self, value: u32) -> u32 {
+        saturate_u32(u64::from(value) * u64::from(self.numerator) / u64::from(self.denominator))
+    }
+
+    /// Returns `value` multiplied by the fraction, rounded to the nearest integer.
+    ///
+    /// Values halfway between two integers are rounded up.
+    #[inline]
+    pub fn scale_nearest(self, value: u32) -> u32 {
+        let denominator = u64::from(self.denominator);
+        let scaled = u64::from(value) * u64::from(self.numerator);
+
+        saturate_u32((scaled + denominator / 2) / denominator)
+    }
+
+    /// Returns `value` multiplied by the fraction, rounded towards zero.
+    #[inline]
+    pub fn scale_signed(self, value: i32) -> i32 {
+        let scaled = i64::from(value) * i64::from(self.numerator) / i64::from(self.denominator);
+
+        scaled.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+    }
+
+    /// Returns `value` divided by the fraction, rounded towards zero.
+    ///
+    /// Dividing by zero returns `u32::MAX`, unless `value` is 0.
+    #[inline]
+    pub fn divide(self, value: u32) -> u32 {
+        if value == 0 {
+            0
+        } else if self.numerator == 0 {
+            u32::MAX
+        } else {
+            saturate_u32(u64::from(value) * u64::from(self.denominator) / u64::from(self.numerator))
+        }
+    }
+
+    /// Returns the two numerators of the fractions, scaled to a common denominator.
+    fn cross(self, other: Self) -> (u64, u64) {
+        (
+            u64::from(self.numerator) * u64::from(other.denominator),
+            u64::from(other.numerator) * u64::from(self.denominator),
+        )
+    }
+}
+
+/// Converts a per-mille (1/1000) value to a fraction.
+impl From<u32> for Fraction {
+    #[inline]
+    fn from(per_mille: u32) -> Self {
+        Self::per_mille(per_mille)
+    }
+}
+
+impl PartialEq for Fraction {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let (this, other) = self.cross(*other);
+        this == other
+    }
+}
+
+impl Eq for Fraction {}
+
+impl PartialOrd for Fraction {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (this, other) = self.cross(*other);
+        this.cmp(&other)
+    }
+}
+
+fn saturate_u32(value: u64) -> u32 {
+    value.min(u64::from(u32::MAX)) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn per_mille() {
+        assert_eq!(2, Fraction::per_mille(250).scale(9));
+        assert_eq!(3, Fraction::per_mille(300).scale_nearest(9));
+        assert_eq!(2, Fraction::per_mille(249).scale_nearest(9));
+        assert_eq!(u32::MAX, Fraction::per_mille(1000).scale(u32::MAX));
+        assert_eq!(Fraction::per_mille(500), Fraction::from(500));
+    }
+
+    #[test]
+    fn scale_rounds_towards_zero() {
+        let two_thirds = Fraction::new(2, 3);
+
+        assert_eq!(6, two_thirds.scale(10));
+        assert_eq!(7, two_thirds.scale_nearest(10));
+        assert_eq!(6, two_thirds.scale_signed(10));
+        assert_eq!(-6, two_thirds.scale_signed(-10));
+        assert_eq!(15, two_thirds.divide(10));
+    }
+
+    #[test]
+    fn halfway_rounds_up() {
+        assert_eq!(3, Fraction::new(1, 2).scale_nearest(5));
+        assert_eq!(1, Fraction::new(1, 2).scale_nearest(1));
+        assert_eq!(0, Fraction::new(1, 3).scale_nearest(1));
+    }
+
+    #[test]
+    fn saturates_at_extremes() {
+        let large = Fraction::new(u32::MAX, 1);
+
+        assert_eq!(u32::MAX, large.scale(2));
+        assert_eq!(u32::MAX, large.scale_nearest(u32::MAX));
+        assert_eq!(i32::MAX, large.scale_signed(2));
+        assert_eq!(i32::MIN, large.scale_signed(-2));
+        assert_eq!(i32::MIN, Fraction::ONE.scale_signed(i32::MIN));
+        assert_eq!(u32::MAX, Fraction::new(1, u32::MAX).divide(2));
+        assert_eq!(0, Fraction::new(1, u32::MAX).scale(u32::MAX - 1));
+    }
+
+    #[test]
+    fn divide_by_zero() {
+        assert_eq!(u32::MAX, Fraction::ZERO.divide(1));
+        assert_eq!(0, Fraction::ZERO.divide(0));
+        assert_eq!(0, Fraction::ZERO.scale(u32::MAX));
+    }
+
+    #[test]
+    fn compare_by_value() {
+        assert_eq!(Fraction::new(1, 2), Fraction::new(500, 1000));
+        assert_eq!(Fraction::ZERO, Fraction::new(0, 7));
+        assert!(Fraction::new(1, 3) < Fraction::per_mille(334));
+        assert!(Fraction::new(1, 3) > Fraction::per_mille(333));
+        assert_eq!(Fraction::ONE, Fraction::per_mille(1200).min(Fraction::ONE));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_denominator() {
+        let _ = Fraction::new(1, 0);
+    }
+}
@@ -36,6 +36,24 @@ impl<V, C: ChainElement> Link<V, C> {
             parent: self,
         }
     }
+
+    /// Prepend an object to the chain, making it the first element
+    #[inline]
+    pub fn prepend<T>(self, item: T) -> <Self as Prepend<T>>::Output
+    where
+        Self: Prepend<T>,
+    {
+        Prepend::prepend(self, item)
+    }
+
+    /// Append every object of `other` to the chain, keeping their order
+    #[inline]
+    pub fn concat<O>(self, other: O) -> <Self as Concat<O>>::Output
+    where
+        Self: Concat<O>,
+    {
+        Concat::concat(self, other)
+    }
 }
 
 impl<V, C> Clone for Link<V, C>
@@ -76,6 +94,21 @@ impl<V> Chain<V> {
             parent: self,
         }
     }
+
+    /// Prepend an object to the chain, making it the first element
+    #[inline]
+    pub fn prepend<T>(self, item: T) -> Link<V, Chain<T>> {
+        Prepend::prepend(self, item)
+    }
+
+    /// Append every object of `other` to the chain, keeping their order
+    #[inline]
+    pub fn concat<O>(self, other: O) -> <Self as Concat<O>>::Output
+    where
+        Self: Concat<O>,
+    {
+        Concat::concat(self, other)
+    }
 }
 
 impl<V> Chain<V> {
@@ -104,6 +137,98 @@ impl<V> ChainElement for Chain<V> {
     }
 }
 
+/// Insert an object before the first element of a chain.
+///
+/// The first element of a chain is the innermost [`Chain`], so prepending re-nests the whole
+/// chain. Use [`Chain::prepend`] or [`Link::prepend`] instead of calling this trait directly.
+pub trait Prepend<T>: ChainElement {
+    /// The type of the chain with the object prepended
+    type Output: ChainElement;
+
+    /// Prepend `item` to the chain.
+    fn prepend(self, item: T) -> Self::Output;
+}
+
+impl<V, T> Prepend<T> for Chain<V> {
+    type Output = Link<V, Chain<T>>;
+
+    #[inline]
+    fn prepend(self, item: T) -> Self::Output {
+        Chain::new(item).append(self.object)
+    }
+}
+
+impl<V, C, T> Prepend<T> for Link<V, C>
+where
+    C: Prepend<T>,
+{
+    type Output = Link<V, C::Output>;
+
+    #[inline]
+    fn prepend(self, item: T) -> Self::Output {
+        Link {
+            object: self.object,
+            parent: self.parent.prepend(item),
+        }
+    }
+}
+
+/// Append the elements of another chain to a chain.
+///
+/// The elements of `other` follow the elements of the chain in their original order, as if they
+/// were appended one by one. Use [`Chain::concat`] or [`Link::concat`] instead of calling this
+/// trait directly.
+///
+/// # Example
+///
+/// ```rust
+/// use embedded_layout::prelude::*;
+///
+/// let header = Chain::new(1u8).append(2u16);
+/// let body = Chain::new(3u32).append(4u64);
+///
+/// let screen: chain! { u8, u16, u32, u64 } = header.concat(body);
+/// assert_eq!(4, screen.object);
+/// ```
+pub trait Concat<O>: ChainElement {
+    /// The type of the concatenated chain
+    type Output: ChainElement;
+
+    /// Append the elements of `other` to the chain.
+    fn concat(self, other: O) -> Self::Output;
+}
+
+impl<S, U> Concat<Chain<U>> for S
+where
+    S: ChainElement,
+{
+    type Output = Link<U, S>;
+
+    #[inline]
+    fn concat(self, other: Chain<U>) -> Self::Output {
+        Link {
+            object: other.object,
+            parent: self,
+        }
+    }
+}
+
+impl<S, U, C> Concat<Link<U, C>> for S
+where
+    S: Concat<C>,
+    C: ChainElement,
+{
+    type Output = Link<U, S::Output>;
+
+    #[inline]
+    fn concat(self, other: Link<U, C>) -> Self::Output {
+        Link {
+            object: other.object,
+            parent: self.concat(other.parent),
+        }
+    }
+}
+
 /// Internal implementation of chain macro
 #[doc(hidden)]
 #[macro_export(local_inner_macros)]
@@ -205,6 +330,34 @@ mod test {
         assert_eq!(1, Chain::new(0).len());
         assert_eq!(3, Chain::new(0u8).append(1u16).append(2u32).len());
     }
+
+    #[test]
+    pub fn test_prepend() {
+        let single: chain! { u16, u8 } = Chain::new(1u8).prepend(0u16);
+        assert_eq!((0, 1), (single.parent.object, single.object));
+
+        let chain: chain! { u64, u8, u16, u32 } =
+            Chain::new(0u8).append(1u16).append(2u32).prepend(3u64);
+        assert_eq!(4, chain.len());
+        assert_eq!(3, chain.parent.parent.parent.object);
+        assert_eq!(0, chain.parent.parent.object);
+        assert_eq!(2, chain.object);
+    }
+
+    #[test]
+    pub fn test_concat() {
+        let single: chain! { u8, u16 } = Chain::new(0u8).concat(Chain::new(1u16));
+        assert_eq!((0, 1), (single.parent.object, single.object));
+
+        let chain: chain! { u8, u16, u32, u64, i8 } = Chain::new(0u8)
+            .append(1u16)
+            .concat(Chain::new(2u32).append(3u64).append(4i8));
+        assert_eq!(5, chain.len());
+        assert_eq!(4, chain.object);
+        assert_eq!(3, chain.parent.object);
+        assert_eq!(2, chain.parent.parent.object);
+        assert_eq!(0, chain.parent.parent.parent.parent.object);
+    }
 }
 
 #[cfg(test)]
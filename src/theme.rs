@@ -0,0 +1,275 @@
+//! Restyling views
+//!
+//! The [`Restyle`] trait replaces the style of a view without touching its position. It is
+//! implemented for styled primitives and text, and forwarded through view groups, layouts and
+//! wrapper views, so an already arranged screen can be restyled (e.g. switched to a dark theme)
+//! in a single [`apply`] call.
+//!
+//! Only the views that use a style of the same type are restyled, every other view is left alone.
+//! Which styles a style of type `S` replaces is decided at compile time by [`ReplaceStyle`]: a
+//! layout that mixes [`Text`] using a [`MonoTextStyle`] and [`Styled`] primitives using a
+//! [`PrimitiveStyle`] can be restyled with either style, one after the other.
+//!
+//! Views that have no style implement [`Restyle`] with its provided method, which does nothing.
+//! Custom views can do the same with an empty `impl<S> Restyle<S> for MyView {}` to be used inside
+//! restyled containers.
+use embedded_graphics::{
+    mono_font::MonoTextStyle,
+    pixelcolor::PixelColor,
+    primitives::{
+        Arc, Circle, Ellipse, Line, Polyline, PrimitiveStyle, Rectangle, RoundedRectangle, Sector,
+        Styled, Triangle,
+    },
+    text::Text,
+};
+
+/// Replace the style of a view, and of every view inside it, with a new one of type `S`.
+///
+/// Views that use a style of a different type keep their style. The provided method does
+/// nothing, which is right for views without a style.
+pub trait Restyle<S> {
+    /// Replace the style of the view (and of its members) with `style`, where the types match.
+    #[inline]
+    fn restyle(&mut self, _style: &S) {}
+}
+
+/// A style that is replaced by a style of type `S` when a view is restyled.
+///
+/// Every style is replaced by a style of the same type. Styles of different types keep their
+/// value: [`PrimitiveStyle`] and [`MonoTextStyle`] leave each other alone. A custom style type
+/// can be mixed with these by implementing this trait for both directions, returning `None`.
+pub trait ReplaceStyle<S>: Sized {
+    /// Returns the style that replaces the current one when restyling with `style`, or `None` if
+    /// the current style is kept.
+    fn replacement(style: &S) -> Option<Self>;
+}
+
+impl<S> ReplaceStyle<S> for S
+where
+    S: Clone,
+{
+    #[inline]
+    fn replacement(style: &S) -> Option<Self> {
+        Some(style.clone())
+    }
+}
+
+impl<C> ReplaceStyle<MonoTextStyle<'_, C>> for PrimitiveStyle<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn replacement(_style: &MonoTextStyle<'_, C>) -> Option<Self> {
+        None
+    }
+}
+
+impl<C> ReplaceStyle<PrimitiveStyle<C>> for MonoTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn replacement(_style: &PrimitiveStyle<C>) -> Option<Self> {
+        None
+    }
+}
+
+/// Restyle every member of `view` using `style`.
+#[inline]
+pub fn apply<S>(view: &mut impl Restyle<S>, style: &S) {
+    view.restyle(style);
+}
+
+impl<T, S, SV> Restyle<S> for Styled<T, SV>
+where
+    SV: ReplaceStyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        if let Some(style) = SV::replacement(style) {
+            self.style = style;
+        }
+    }
+}
+
+impl<S, SV> Restyle<S> for Text<'_, SV>
+where
+    SV: ReplaceStyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        if let Some(style) = SV::replacement(style) {
+            self.character_style = style;
+        }
+    }
+}
+
+macro_rules! unstyled {
+    ($($view:ty),+ $(,)?) => {
+        $(impl<S> Restyle<S> for $view {})+
+    };
+}
+
+unstyled!(
+    Rectangle,
+    RoundedRectangle,
+    Circle,
+    Ellipse,
+    Arc,
+    Sector,
+    Line,
+    Polyline<'_>,
+    Triangle,
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        layout::linear::LinearLayout,
+        prelude::*,
+        theme,
+        utils::rotation::Rotation,
+        views::{DrawRotated, Lazy, Padding},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X9, MonoTextStyle},
+        pixelcolor::BinaryColor,
+        prelude::{Point, Primitive, Size},
+        primitives::{PrimitiveStyle, Rectangle},
+        text::Text,
+        Drawable,
+    };
+
+    #[test]
+    fn restyle_with_borrowed_style() {
+        // The font doesn't live for `'static`
+        let font = FONT_6X9;
+        let on = MonoTextStyle::new(&font, BinaryColor::On);
+        let off = MonoTextStyle::new(&font, BinaryColor::Off);
+        let rect_style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        let mut layout = LinearLayout::horizontal(
+            Chain::new(Text::new("A", Point::zero(), on))
+                .append(DrawRotated::new(
+                    Text::new("B", Point::zero(), on),
+                    Rotation::Quarter,
+                ))
+                .append(Lazy::new(Size::new(2, 2), move || {
+                    Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(rect_style)
+                })),
+        )
+        .arrange();
+        layout.inner_mut().object.force();
+
+        theme::apply(&mut layout, &off);
+
+        let chain = layout.inner_mut();
+        assert_eq!(off, chain.parent.parent.object.character_style);
+        assert_eq!(off, chain.parent.object.inner().character_style);
+        assert_eq!(rect_style, chain.object.force().style);
+
+        let dark = PrimitiveStyle::with_fill(BinaryColor::Off);
+        theme::apply(&mut layout, &dark);
+        assert_eq!(dark, layout.inner_mut().object.force().style);
+    }
+
+    #[test]
+    fn restyle_nested_layout() {
+        let light = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let dark = PrimitiveStyle::with_stroke(BinaryColor::Off, 1);
+
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 3)).into_styled(light);
+
+        let mut layout = LinearLayout::horizontal(
+            Chain::new(rect).append(
+                LinearLayout::vertical(Chain::new(rect).append(rect))
+                    .arrange()
+                    .into_inner(),
+            ),
+        )
+        .arrange();
+
+        let bounds = layout.bounds();
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "   ###", //
+                "   # #", //
+                "   ###", //
+                "######", //
+                "# ## #", //
+                "######", //
+            ])
+        );
+
+        theme::apply(&mut layout, &dark);
+
+        assert_eq!(bounds, layout.bounds());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "   ...", //
+                "   . .", //
+                "   ...", //
+                "......", //
+                ". .. .", //
+                "......", //
+            ])
+        );
+    }
+
+    #[test]
+    fn restyle_mixed_layout() {
+        let light = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let dark = PrimitiveStyle::with_stroke(BinaryColor::Off, 1);
+        let on = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let off = MonoTextStyle::new(&FONT_6X9, BinaryColor::Off);
+
+        let build = |rect_style, text_style| {
+            let rect = Rectangle::new(Point::zero(), Size::new(3, 3)).into_styled(rect_style);
+            let text = Text::new("Hi", Point::zero(), text_style);
+
+            LinearLayout::horizontal(
+                Chain::new(rect).append(Padding::new(
+                    LinearLayout::vertical(Chain::new(text).append(rect))
+                        .arrange()
+                        .into_inner(),
+                    1,
+                )),
+            )
+            .arrange()
+        };
+
+        let draw = |layout: &dyn Fn(&mut MockDisplay<BinaryColor>)| {
+            let mut display = MockDisplay::new();
+            layout(&mut display);
+            display
+        };
+
+        let mut layout = build(light, on);
+        let bounds = layout.bounds();
+
+        // Only the primitives use a `PrimitiveStyle`, the text keeps its style
+        theme::apply(&mut layout, &dark);
+        assert_eq!(bounds, layout.bounds());
+        assert_eq!(
+            draw(&|display| layout.draw(display).unwrap()),
+            draw(&|display| build(dark, on).draw(display).unwrap())
+        );
+
+        // ... and the other way around
+        theme::apply(&mut layout, &off);
+        assert_eq!(bounds, layout.bounds());
+        assert_eq!(
+            draw(&|display| layout.draw(display).unwrap()),
+            draw(&|display| build(dark, off).draw(display).unwrap())
+        );
+    }
+}
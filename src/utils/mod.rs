@@ -1,3 +1,7 @@
 //! Utility collection module
 
 pub mod rect_helper;
+pub mod rotation;
+pub mod rounding;
+pub(crate) mod trig;
+pub mod view_ext;
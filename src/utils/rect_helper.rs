@@ -29,6 +29,19 @@ impl RectExt for Rectangle {
     }
 }
 
+/// Returns the bounding box of `first` and `second`, ignoring empty rectangles.
+///
+/// If both rectangles are empty, `first` is returned.
+pub(crate) fn union_non_empty(first: Rectangle, second: Rectangle) -> Rectangle {
+    if second.is_zero_sized() {
+        first
+    } else if first.is_zero_sized() {
+        second
+    } else {
+        first.enveloping(&second)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -0,0 +1,335 @@
+//! Display rotation
+//!
+//! Displays are often mounted rotated relative to their framebuffer. [`Rotation`] describes how
+//! the displayed content is rotated, so logical directions (e.g. "top") can be mapped to the
+//! physical ones.
+//!
+//! Layouts can be authored in logical orientation and either drawn through a [`Rotated`] draw
+//! target, or remapped to physical coordinates using [`rotate_layout`].
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::OriginDimensions,
+    prelude::{Point, Size},
+    primitives::Rectangle,
+    Pixel,
+};
+
+use crate::view_group::ViewGroup;
+
+/// Clockwise rotation of the displayed content, in quarter turns.
+///
+/// No rotation is represented by `None` where an `Option<Rotation>` is expected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    /// 90° clockwise
+    Quarter,
+
+    /// 180°
+    Half,
+
+    /// 270° clockwise, or 90° counter-clockwise
+    ThreeQuarter,
+}
+
+impl Rotation {
+    /// Returns the number of clockwise quarter turns.
+    #[inline]
+    pub const fn quarter_turns(self) -> u8 {
+        match self {
+            Rotation::Quarter => 1,
+            Rotation::Half => 2,
+            Rotation::ThreeQuarter => 3,
+        }
+    }
+
+    /// Rotate a direction vector clockwise around the origin.
+    ///
+    /// Note that the display coordinate system's Y axis points down, so rotating `(1, 0)` (right)
+    /// by a quarter turn results in `(0, 1)` (down).
+    #[inline]
+    pub const fn rotate_vector(self, vector: Point) -> Point {
+        match self {
+            Rotation::Quarter => Point::new(-vector.y, vector.x),
+            Rotation::Half => Point::new(-vector.x, -vector.y),
+            Rotation::ThreeQuarter => Point::new(vector.y, -vector.x),
+        }
+    }
+
+    /// Returns the size of a rotated area.
+    ///
+    /// Quarter turns swap the width and the height.
+    #[inline]
+    pub const fn rotate_size(self, size: Size) -> Size {
+        match self {
+            Rotation::Half => size,
+            Rotation::Quarter | Rotation::ThreeQuarter => Size::new(size.height, size.width),
+        }
+    }
+
+    /// Map a logical pixel to the physical pixel that displays it.
+    ///
+    /// `size` is the logical size of the display.
+    #[inline]
+    pub const fn rotate_point(self, point: Point, size: Size) -> Point {
+        let (width, height) = (size.width as i32, size.height as i32);
+        match self {
+            Rotation::Quarter => Point::new(height - 1 - point.y, point.x),
+            Rotation::Half => Point::new(width - 1 - point.x, height - 1 - point.y),
+            Rotation::ThreeQuarter => Point::new(point.y, width - 1 - point.x),
+        }
+    }
+
+    /// Map a logical area to the physical area that displays it.
+    ///
+    /// `size` is the logical size of the display.
+    #[inline]
+    pub const fn rotate_rect(self, rect: Rectangle, size: Size) -> Rectangle {
+        let (width, height) = (size.width as i32, size.height as i32);
+        let right = rect.top_left.x + rect.size.width as i32;
+        let bottom = rect.top_left.y + rect.size.height as i32;
+        let top_left = match self {
+            Rotation::Quarter => Point::new(height - bottom, rect.top_left.x),
+            Rotation::Half => Point::new(width - right, height - bottom),
+            Rotation::ThreeQuarter => Point::new(rect.top_left.y, width - right),
+        };
+
+        Rectangle::new(top_left, self.rotate_size(rect.size))
+    }
+}
+
+/// Draw target adapter that rotates everything drawn to it.
+///
+/// `Rotated` wraps the physical display and exposes its logical size, so content can be drawn in
+/// logical coordinates.
+///
+/// # Example
+///
+/// ```
+/// use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_layout::utils::rotation::{Rotated, Rotation};
+///
+/// let display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// let mut rotated = Rotated::new(display, Rotation::Quarter);
+///
+/// // The logical top left pixel is displayed at the physical top right corner
+/// Pixel(Point::zero(), BinaryColor::On).draw(&mut rotated).unwrap();
+///
+/// let display = rotated.into_inner();
+/// assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(63, 0)));
+/// ```
+pub struct Rotated<D> {
+    display: D,
+    rotation: Rotation,
+}
+
+impl<D> Rotated<D>
+where
+    D: DrawTarget + OriginDimensions,
+{
+    /// Wrap the physical `display`, whose content is rotated by `rotation`.
+    #[inline]
+    pub fn new(display: D, rotation: Rotation) -> Self {
+        Self { display, rotation }
+    }
+
+    /// Returns the rotation of the displayed content.
+    #[inline]
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Returns a reference to the wrapped display.
+    #[inline]
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Returns a mutable reference to the wrapped display.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter and return the wrapped display.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D> OriginDimensions for Rotated<D>
+where
+    D: DrawTarget + OriginDimensions,
+{
+    #[inline]
+    fn size(&self) -> Size {
+        self.rotation.rotate_size(self.display.size())
+    }
+}
+
+impl<D> DrawTarget for Rotated<D>
+where
+    D: DrawTarget + OriginDimensions,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    #[inline]
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let size = self.size();
+        let rotation = self.rotation;
+
+        self.display.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(rotation.rotate_point(point, size), color)),
+        )
+    }
+
+    #[inline]
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.rotation.rotate_rect(*area, self.size());
+        self.display.fill_solid(&area, color)
+    }
+}
+
+/// Move the views of an arranged view group to their physical positions.
+///
+/// `display_size` is the physical size of the display, as exposed by its driver. Views can only be
+/// moved, not rotated, so each view is moved to the top left corner of the physical area that
+/// displays it. After this, hit testing and dirty areas are in physical coordinates.
+///
+/// For quarter turns, the physical area is the view's bounding box with its width and height
+/// swapped. Use [`Rotation::rotate_rect`] to compute it.
+#[inline]
+pub fn rotate_layout<VG>(arranged: &mut VG, rotation: Option<Rotation>, display_size: Size)
+where
+    VG: ViewGroup + ?Sized,
+{
+    let rotation = match rotation {
+        Some(rotation) => rotation,
+        None => return,
+    };
+    let logical_size = rotation.rotate_size(display_size);
+
+    for idx in 0..arranged.len() {
+        let bounds = arranged.bounds_of(idx);
+        let physical = rotation.rotate_rect(bounds, logical_size);
+        arranged.translate_child(idx, physical.top_left - bounds.top_left);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PointsIter, PrimitiveStyle, Rectangle},
+        Drawable,
+    };
+
+    const ROTATIONS: [Option<Rotation>; 4] = [
+        None,
+        Some(Rotation::Quarter),
+        Some(Rotation::Half),
+        Some(Rotation::ThreeQuarter),
+    ];
+
+    #[test]
+    fn rotate_vector() {
+        let right = Point::new(1, 0);
+
+        assert_eq!(Point::new(0, 1), Rotation::Quarter.rotate_vector(right));
+        assert_eq!(Point::new(-1, 0), Rotation::Half.rotate_vector(right));
+        assert_eq!(
+            Point::new(0, -1),
+            Rotation::ThreeQuarter.rotate_vector(right)
+        );
+    }
+
+    #[test]
+    fn rotate_rect() {
+        // 40x30 logical display, a 10x5 view at (2, 3)
+        let size = Size::new(40, 30);
+        let rect = Rectangle::new(Point::new(2, 3), Size::new(10, 5));
+
+        assert_eq!(
+            Rectangle::new(Point::new(22, 2), Size::new(5, 10)),
+            Rotation::Quarter.rotate_rect(rect, size)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(28, 22), Size::new(10, 5)),
+            Rotation::Half.rotate_rect(rect, size)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(3, 28), Size::new(5, 10)),
+            Rotation::ThreeQuarter.rotate_rect(rect, size)
+        );
+    }
+
+    #[test]
+    fn rotate_rect_matches_rotated_pixels() {
+        let size = Size::new(40, 30);
+        let rect = Rectangle::new(Point::new(2, 3), Size::new(10, 5));
+
+        for &rotation in &[Rotation::Quarter, Rotation::Half, Rotation::ThreeQuarter] {
+            let rotated = rotation.rotate_rect(rect, size);
+            for point in rect.points() {
+                assert!(rotated.contains(rotation.rotate_point(point, size)));
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_draw_target() {
+        let rect = Rectangle::new(Point::new(1, 0), Size::new(3, 2));
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        for &rotation in &[Rotation::Quarter, Rotation::Half, Rotation::ThreeQuarter] {
+            let mut target = Rotated::new(MockDisplay::<BinaryColor>::new(), rotation);
+            assert_eq!(Size::new(64, 64), target.size());
+            rect.into_styled(style).draw(&mut target).unwrap();
+
+            let mut expected: MockDisplay<BinaryColor> = MockDisplay::new();
+            rotation
+                .rotate_rect(rect, Size::new(64, 64))
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            target.inner().assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn rotate_layout_in_all_rotations() {
+        // Logical 30x20 display on a 20x30 panel mounted rotated by a quarter turn
+        let expected = [
+            [Point::new(0, 0), Point::new(5, 0)],
+            [Point::new(15, 0), Point::new(15, 5)],
+            [Point::new(25, 15), Point::new(20, 15)],
+            [Point::new(0, 25), Point::new(0, 20)],
+        ];
+
+        for (&rotation, expected) in ROTATIONS.iter().zip(expected.iter()) {
+            let mut views = [Rectangle::new(Point::zero(), Size::new(5, 5)); 2];
+            let mut layout = LinearLayout::horizontal(Views::new(&mut views)).arrange();
+
+            let display_size = match rotation {
+                Some(rotation) => rotation.rotate_size(Size::new(30, 20)),
+                None => Size::new(30, 20),
+            };
+            rotate_layout(layout.inner_mut(), rotation, display_size);
+
+            assert_eq!(expected[0], views[0].top_left, "{:?}", rotation);
+            assert_eq!(expected[1], views[1].top_left, "{:?}", rotation);
+        }
+    }
+}
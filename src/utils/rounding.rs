@@ -0,0 +1,133 @@
+//! Rounding coordinates
+//!
+//! Some displays are faster to draw to when objects start at specific coordinates, e.g. on byte
+//! boundaries. [`RoundingMode`] describes in which direction a coordinate is moved to reach the
+//! closest multiple of a grid size.
+//!
+//! # Rounding rules
+//!
+//! Every place where embedded-layout divides pixel distances follows the same rules, so that
+//! positions don't shift between releases or between different parts of the crate:
+//!
+//!  - Halving a distance, e.g. to center a view or to grow a hit area on both sides, rounds
+//!    towards negative infinity. The extra pixel of an odd distance ends up after (right of or
+//!    below) the centered object.
+//!  - Centering a view to another view compares their [centers], which embedded-graphics places at
+//!    `top_left + (size - 1) / 2`, i.e. the left or top of the two middle pixels of an even size.
+//!  - Splitting a distance into equal parts, e.g. distributing free space between views or
+//!    splitting an area into tiles, makes the first `remainder` parts a pixel larger. Negative
+//!    distances are split the same way, so the parts always add up to the whole distance.
+//!  - Scaling by a [`Fraction`] (proportional gaps, progress) rounds towards zero, except for
+//!    [`Position::Relative`] which rounds to the nearest pixel.
+//!  - Views placed in proportion to their index (e.g. [`Justify::SpaceAround`]) round towards
+//!    negative infinity.
+//!
+//! [centers]: embedded_graphics::geometry::AnchorPoint::Center
+//! [`Fraction`]: crate::math::Fraction
+//! [`Position::Relative`]: crate::layout::absolute::Position::Relative
+//! [`Justify::SpaceAround`]: crate::layout::linear::Justify::SpaceAround
+
+/// The direction in which a value is rounded to a multiple of a step size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards negative infinity, i.e. left or up on the display.
+    Down,
+
+    /// Round towards positive infinity, i.e. right or down on the display.
+    Up,
+
+    /// Round to the closest multiple. Values halfway between two multiples are rounded up.
+    Nearest,
+}
+
+impl RoundingMode {
+    /// Round `value` to a multiple of `step`.
+    ///
+    /// A `step` of 0 leaves `value` unchanged.
+    #[inline]
+    #[must_use]
+    pub fn round(self, value: i32, step: u32) -> i32 {
+        if step == 0 {
+            return value;
+        }
+
+        let step = step as i32;
+        let down = value.div_euclid(step) * step;
+        let remainder = value - down;
+
+        match self {
+            RoundingMode::Down => down,
+            RoundingMode::Up if remainder == 0 => down,
+            RoundingMode::Up => down + step,
+            RoundingMode::Nearest if 2 * remainder >= step => down + step,
+            RoundingMode::Nearest => down,
+        }
+    }
+}
+
+/// Returns half of `value`, rounded towards negative infinity.
+pub(crate) fn half(value: i32) -> i32 {
+    value.div_euclid(2)
+}
+
+/// Returns `value / divisor`, rounded towards negative infinity.
+///
+/// `divisor` must be positive.
+pub(crate) fn div_floor(value: i32, divisor: i32) -> i32 {
+    value.div_euclid(divisor)
+}
+
+/// Splits `total` into `parts` parts that differ by at most a pixel, larger parts first.
+///
+/// Returns the sum of the parts before the `n`th part, and the size of the `n`th part. `parts`
+/// must be positive.
+pub(crate) fn split(total: i32, parts: i32, n: i32) -> (i32, i32) {
+    let base = total.div_euclid(parts);
+    let remainder = total.rem_euclid(parts);
+
+    let offset = base * n + n.min(remainder);
+    let size = if n < remainder { base + 1 } else { base };
+
+    (offset, size)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_to_multiple() {
+        assert_eq!(8, RoundingMode::Down.round(13, 8));
+        assert_eq!(16, RoundingMode::Up.round(13, 8));
+        assert_eq!(16, RoundingMode::Nearest.round(12, 8));
+        assert_eq!(8, RoundingMode::Nearest.round(11, 8));
+
+        assert_eq!(-8, RoundingMode::Down.round(-3, 8));
+        assert_eq!(0, RoundingMode::Up.round(-3, 8));
+        assert_eq!(16, RoundingMode::Up.round(16, 8));
+        assert_eq!(5, RoundingMode::Nearest.round(5, 0));
+    }
+
+    #[test]
+    fn halves_round_down() {
+        assert_eq!([2, 2, 0, -1, -2], [5, 4, 1, -1, -3].map(half));
+    }
+
+    #[test]
+    fn split_adds_up() {
+        for total in -13..=13 {
+            for parts in 1..=5 {
+                let sizes = (0..parts).map(|n| split(total, parts, n).1);
+                assert_eq!(total, sizes.sum::<i32>(), "{} / {}", total, parts);
+
+                let (offset, size) = split(total, parts, parts - 1);
+                assert_eq!(total, offset + size);
+            }
+        }
+        assert_eq!([(0, 3), (3, 2), (5, 2)], [0, 1, 2].map(|n| split(7, 3, n)));
+        assert_eq!(
+            [(0, -2), (-2, -3), (-5, -3)],
+            [0, 1, 2].map(|n| split(-8, 3, n))
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Integer trigonometry for placing views on circles
+//!
+//! Angles are whole degrees, measured clockwise from the 12 o'clock direction, which is how
+//! positions on a dial are usually described. Values are looked up from a table, so no floating
+//! point math is needed.
+
+use embedded_graphics::prelude::Point;
+
+/// The fixed point scale of the values in [`SINE`], i.e. the value of `sin(90°)`.
+const SCALE: i64 = 1 << 14;
+
+/// `sin(x) * SCALE` for every whole degree in the first quadrant.
+#[rustfmt::skip]
+const SINE: [u16; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563,
+    2845, 3126, 3406, 3686, 3964, 4240, 4516, 4790, 5063, 5334,
+    5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943,
+    8192, 8438, 8682, 8923, 9162, 9397, 9630, 9860, 10087, 10311,
+    10531, 10749, 10963, 11174, 11381, 11585, 11786, 11982, 12176, 12365,
+    12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296,
+    15396, 15491, 15582, 15668, 15749, 15826, 15897, 15964, 16026, 16083,
+    16135, 16182, 16225, 16262, 16294, 16322, 16344, 16362, 16374, 16382,
+    16384,
+];
+
+/// Returns `sin(degrees) * SCALE`.
+fn sin(degrees: i32) -> i64 {
+    let degrees = degrees.rem_euclid(360) as usize;
+    let value = i64::from(match degrees {
+        0..=90 => SINE[degrees],
+        91..=180 => SINE[180 - degrees],
+        181..=270 => SINE[degrees - 180],
+        _ => SINE[360 - degrees],
+    });
+
+    if degrees > 180 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Returns `cos(degrees) * SCALE`.
+fn cos(degrees: i32) -> i64 {
+    sin(degrees.rem_euclid(360) + 90)
+}
+
+/// Returns `value / SCALE`, rounded to the nearest integer, halfway cases away from zero.
+fn unscale(value: i64) -> i32 {
+    let half = if value < 0 { -SCALE / 2 } else { SCALE / 2 };
+    ((value + half) / SCALE) as i32
+}
+
+/// Returns the point at `angle` degrees on the circle of the given `radius` around `center`.
+///
+/// An angle of 0 points up, and angles grow clockwise.
+pub(crate) fn point_on_circle(center: Point, radius: u32, angle: i32) -> Point {
+    let radius = i64::from(radius);
+
+    center
+        + Point::new(
+            unscale(radius * sin(angle)),
+            // The Y axis of the display points down.
+            -unscale(radius * cos(angle)),
+        )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn quadrants() {
+        let center = Point::new(10, 10);
+
+        assert_eq!(Point::new(10, 0), point_on_circle(center, 10, 0));
+        assert_eq!(Point::new(20, 10), point_on_circle(center, 10, 90));
+        assert_eq!(Point::new(10, 20), point_on_circle(center, 10, 180));
+        assert_eq!(Point::new(0, 10), point_on_circle(center, 10, 270));
+        assert_eq!(Point::new(0, 10), point_on_circle(center, 10, -90));
+        assert_eq!(Point::new(10, 0), point_on_circle(center, 10, 720));
+    }
+
+    #[test]
+    fn symmetric() {
+        for angle in 0..=180 {
+            let right = point_on_circle(Point::zero(), 1000, angle);
+            let left = point_on_circle(Point::zero(), 1000, -angle);
+
+            assert_eq!(right.x, -left.x, "angle: {}", angle);
+            assert_eq!(right.y, left.y, "angle: {}", angle);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! `View` utility methods
+//!
+//! This module implements a few useful extensions to every [`View`].
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+use crate::{utils::rounding::RoundingMode, views::DrawTranslated, View};
+
+/// The trait that describes the extension methods.
+///
+/// This trait is blanket-implemented for all objects that implement [`View`].
+pub trait ViewExt: View {
+    /// Return the view moved so that its top left corner is on a multiple of `grid`.
+    ///
+    /// Each coordinate is rounded in the direction given by `rounding`.
+    fn snap_to_grid(self, grid: Size, rounding: RoundingMode) -> Self
+    where
+        Self: Sized;
+
+    /// Move the view so that its top left corner is on a multiple of `grid`.
+    ///
+    /// Each coordinate is rounded in the direction given by `rounding`.
+    fn snap_to_grid_mut(&mut self, grid: Size, rounding: RoundingMode) -> &mut Self
+    where
+        Self: Sized;
+
+    /// Return the view moved so that its top left corner is on the nearest multiple of `grid`.
+    ///
+    /// This is a shorthand for [`snap_to_grid`] with [`RoundingMode::Nearest`]. The sign of the
+    /// `grid` components is ignored, and a component of 0 leaves that coordinate unchanged.
+    ///
+    /// [`snap_to_grid`]: ViewExt::snap_to_grid
+    fn snap_to(self, grid: Point) -> Self
+    where
+        Self: Sized;
+
+    /// Return an adapter that draws the view moved by `offset`, without moving the view itself.
+    ///
+    /// See [`DrawTranslated`] for more information.
+    fn draw_translated(&self, offset: Point) -> DrawTranslated<'_, Self>
+    where
+        Self: Sized;
+}
+
+impl<T> ViewExt for T
+where
+    T: View,
+{
+    #[inline]
+    fn snap_to_grid(mut self, grid: Size, rounding: RoundingMode) -> Self {
+        self.snap_to_grid_mut(grid, rounding);
+        self
+    }
+
+    #[inline]
+    fn snap_to_grid_mut(&mut self, grid: Size, rounding: RoundingMode) -> &mut Self {
+        let top_left = self.bounds().top_left;
+        let snapped = snap_rectangle(self.bounds(), grid, rounding).top_left;
+
+        self.translate_mut(snapped - top_left)
+    }
+
+    #[inline]
+    fn snap_to(self, grid: Point) -> Self {
+        let grid = Size::new(grid.x.unsigned_abs(), grid.y.unsigned_abs());
+        self.snap_to_grid(grid, RoundingMode::Nearest)
+    }
+
+    #[inline]
+    fn draw_translated(&self, offset: Point) -> DrawTranslated<'_, Self> {
+        DrawTranslated::new(self, offset)
+    }
+}
+
+pub(crate) fn snap_rectangle(rect: Rectangle, grid: Size, rounding: RoundingMode) -> Rectangle {
+    let mut top_left = rect.top_left;
+    top_left.x = rounding.round(top_left.x, grid.width);
+    top_left.y = rounding.round(top_left.y, grid.height);
+
+    Rectangle::new(top_left, rect.size)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{prelude::*, utils::rounding::RoundingMode};
+    use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+    #[test]
+    fn snap_to_grid() {
+        let rect = Rectangle::new(Point::new(13, -3), Size::new(6, 6));
+
+        let down = rect.snap_to_grid(Size::new(8, 4), RoundingMode::Down);
+        let up = rect.snap_to_grid(Size::new(8, 4), RoundingMode::Up);
+
+        assert_eq!(Point::new(8, -4), down.top_left);
+        assert_eq!(Point::new(16, 0), up.top_left);
+        assert_eq!(rect.size, up.size);
+    }
+
+    #[test]
+    fn snap_to_nearest() {
+        let rect = Rectangle::new(Point::new(7, 7), Size::new(3, 3));
+
+        assert_eq!(Point::new(5, 5), rect.snap_to(Point::new(5, 5)).top_left);
+        assert_eq!(Point::new(8, 7), rect.snap_to(Point::new(4, 0)).top_left);
+    }
+}
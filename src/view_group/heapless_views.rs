@@ -0,0 +1,301 @@
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    theme::Restyle,
+    view_group::{DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    views::Revision,
+    View,
+};
+
+/// A view group of up to `N` views, stored inline.
+///
+/// `HeaplessViews` can grow and shrink at runtime like a `Vec`, without requiring an allocator.
+/// The views are kept in insertion order. Pushing or removing a view changes the [`Revision`] of
+/// the group.
+pub struct HeaplessViews<T, const N: usize>
+where
+    T: View,
+{
+    views: [Option<T>; N],
+    len: usize,
+    revision: Revision,
+}
+
+impl<T, const N: usize> HeaplessViews<T, N>
+where
+    T: View,
+{
+    /// Creates an empty view group.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            views: [(); N].map(|_| None),
+            len: 0,
+            revision: Revision::ZERO,
+        }
+    }
+
+    /// Appends a view to the end of the group.
+    ///
+    /// Returns the view back if the group is full.
+    #[inline]
+    pub fn push(&mut self, view: T) -> Result<(), T> {
+        match self.views.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some(view);
+                self.len += 1;
+                self.revision = self.revision.next();
+                Ok(())
+            }
+            None => Err(view),
+        }
+    }
+
+    /// Removes and returns the view at `idx`, shifting the views after it to the left.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Option<T> {
+        if idx >= self.len {
+            return None;
+        }
+
+        let view = self.views[idx].take();
+        self.views[idx..self.len].rotate_left(1);
+        self.len -= 1;
+        self.revision = self
+            .revision
+            .combine(view.as_ref().map_or(Revision::ZERO, View::revision))
+            .next();
+
+        view
+    }
+
+    /// Returns the number of views in the group.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the group contains no views.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of views the group can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns a reference to the view at `idx`, or `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.views[..self.len].get(idx).and_then(Option::as_ref)
+    }
+
+    /// Returns a mutable reference to the view at `idx`, or `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.views[..self.len].get_mut(idx).and_then(Option::as_mut)
+    }
+
+    fn view(&self, idx: usize) -> &T {
+        match self.get(idx) {
+            Some(view) => view,
+            None => panic!("index {} out of bounds for {} views", idx, self.len),
+        }
+    }
+
+    fn view_mut(&mut self, idx: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(idx) {
+            Some(view) => view,
+            None => panic!("index {} out of bounds for {} views", idx, len),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for HeaplessViews<T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ViewGroup for HeaplessViews<T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.view(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.view_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.view(idx).bounds()
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.view_mut(idx).translate_impl(by)
+    }
+}
+
+impl<T, const N: usize> View for HeaplessViews<T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.revision.combine(ViewGroupHelper::revision(self))
+    }
+}
+
+impl<S, T, const N: usize> Restyle<S> for HeaplessViews<T, N>
+where
+    T: Restyle<S> + View,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        for view in self.views[..self.len].iter_mut().flatten() {
+            view.restyle(style);
+        }
+    }
+}
+
+impl<C, T, const N: usize> Drawable for HeaplessViews<T, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        for view in self.views[..self.len].iter().flatten() {
+            view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, T, const N: usize> DrawableViewGroup<C> for HeaplessViews<T, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn draw_child<D>(&self, idx: usize, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view(idx).draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn push_and_arrange() {
+        let mut views: HeaplessViews<Rectangle, 4> = HeaplessViews::new();
+        for height in [2, 4, 3] {
+            views
+                .push(Rectangle::new(Point::new(7, 7), Size::new(5, height)))
+                .unwrap();
+        }
+        assert_eq!(3, views.len());
+
+        let views = LinearLayout::vertical(views).arrange().into_inner();
+
+        assert_eq!(Some(Point::new(0, 0)), views.get(0).map(|r| r.top_left));
+        assert_eq!(Some(Point::new(0, 2)), views.get(1).map(|r| r.top_left));
+        assert_eq!(Some(Point::new(0, 6)), views.get(2).map(|r| r.top_left));
+        assert_eq!(None, views.get(3));
+    }
+
+    #[test]
+    fn push_fails_when_full() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+        let mut views: HeaplessViews<Rectangle, 1> = HeaplessViews::default();
+
+        assert_eq!(Ok(()), views.push(rect));
+        assert_eq!(Err(rect), views.push(rect));
+        assert_eq!(1, views.capacity());
+    }
+
+    #[test]
+    fn remove_keeps_order() {
+        let mut views: HeaplessViews<Rectangle, 3> = HeaplessViews::new();
+        for x in 0..3 {
+            views
+                .push(Rectangle::new(Point::new(x, 0), Size::new(1, 1)))
+                .unwrap();
+        }
+
+        assert_eq!(Some(Point::new(1, 0)), views.remove(1).map(|r| r.top_left));
+        assert_eq!(None, views.remove(2));
+        assert_eq!(2, views.len());
+        assert_eq!(Point::new(2, 0), views.bounds_of(1).top_left);
+
+        views.remove(0);
+        views.remove(0);
+        assert!(views.is_empty());
+    }
+
+    #[test]
+    fn removing_untracked_view_changes_revision() {
+        let mut views: HeaplessViews<Rectangle, 3> = HeaplessViews::new();
+        for x in 0..3 {
+            views
+                .push(Rectangle::new(Point::new(x, 0), Size::new(1, 1)))
+                .unwrap();
+        }
+
+        let drawn = View::revision(&views);
+        views.remove(1);
+        assert_ne!(drawn, View::revision(&views));
+
+        let removed = View::revision(&views);
+        assert_eq!(None, views.remove(2));
+        assert_eq!(removed, View::revision(&views));
+    }
+}
@@ -1,14 +1,29 @@
 //! ViewGroup definition and implementation for common types.
 
-use embedded_graphics::{prelude::Point, primitives::Rectangle};
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+};
 
-use crate::{prelude::RectExt, View};
+use crate::{
+    layout::Axis,
+    theme::Restyle,
+    utils::{rect_helper::union_non_empty, rounding::split},
+    views::Revision,
+    View,
+};
 
+mod heapless_views;
 mod object_chain;
+mod positions;
 mod views;
 
+pub use heapless_views::HeaplessViews;
 pub use views::Views;
 
+pub(crate) use positions::{inner_margins, Items, Placeable, PositionBuffer};
+
 /// A set of operations required to implement [`View`] containers.
 pub trait ViewGroup: View {
     /// Returns the number of [`View`] objects in this view group.
@@ -29,6 +44,401 @@ pub trait ViewGroup: View {
     fn translate_child(&mut self, idx: usize, by: Point) {
         self.at_mut(idx).translate_impl(by)
     }
+
+    /// Translates every [`View`] by its own offset.
+    ///
+    /// The view at position `idx` is moved by `offsets[idx]`. Extra offsets are ignored, and views
+    /// without a corresponding offset are not moved.
+    #[inline]
+    fn translate_each(&mut self, offsets: &[Point]) {
+        for (idx, by) in offsets.iter().enumerate().take(self.len()) {
+            self.translate_child(idx, *by);
+        }
+    }
+
+    /// Spaces the views between the first and the last one evenly along `axis`.
+    ///
+    /// The first and the last view are not moved. The other views are placed in index order so
+    /// that the gaps between neighbouring views are equal, following the crate's [rounding rules]:
+    /// if the free space can't be split evenly, the first gaps are a pixel larger. The views are
+    /// not moved along the cross axis. View groups with fewer than three views are left unchanged.
+    ///
+    /// [rounding rules]: crate::utils::rounding
+    #[inline]
+    fn distribute_evenly(&mut self, axis: Axis) {
+        let count = self.len();
+        if count < 3 {
+            return;
+        }
+
+        let start = |bounds: Rectangle| match axis {
+            Axis::Horizontal => bounds.top_left.x,
+            Axis::Vertical => bounds.top_left.y,
+        };
+        let extent = |bounds: Rectangle| axis.primary_size(bounds.size) as i32;
+
+        let first = self.bounds_of(0);
+        let first_end = start(first) + extent(first);
+        let inner = (1..count - 1)
+            .map(|idx| extent(self.bounds_of(idx)))
+            .sum::<i32>();
+        let free = start(self.bounds_of(count - 1)) - first_end - inner;
+        let gaps = count as i32 - 1;
+
+        let mut position = first_end;
+        for idx in 1..count - 1 {
+            let bounds = self.bounds_of(idx);
+            let (_, gap) = split(free, gaps, idx as i32 - 1);
+            position += gap;
+
+            let by = position - start(bounds);
+            self.translate_child(
+                idx,
+                match axis {
+                    Axis::Horizontal => Point::new(by, 0),
+                    Axis::Vertical => Point::new(0, by),
+                },
+            );
+            position += extent(bounds);
+        }
+    }
+
+    /// Convert `point` from the coordinate space of the parent to the local coordinate space of
+    /// the view group, where the top left corner of the view group's bounding box is the origin.
+    #[inline]
+    fn to_local(&self, point: Point) -> Point {
+        point - self.bounds().top_left
+    }
+
+    /// Convert `point` from the local coordinate space of the view group to the coordinate space
+    /// of its parent.
+    ///
+    /// This is the inverse of [`to_local`](ViewGroup::to_local).
+    #[inline]
+    fn to_parent(&self, point: Point) -> Point {
+        point + self.bounds().top_left
+    }
+
+    /// Returns the areas that changed since `previous`, matching views by their [`id`].
+    ///
+    /// For every identified view whose bounds changed, the union of its previous and current
+    /// bounds is returned. Views that only exist in one of the view groups return their bounds in
+    /// that group. Views without an identifier are ignored.
+    ///
+    /// [`id`]: View::id
+    #[inline]
+    fn diff_by_id<'a>(&'a self, previous: &'a Self) -> DirtyRects<'a, Self>
+    where
+        Self: Sized,
+    {
+        DirtyRects {
+            current: self,
+            previous,
+            idx: 0,
+            removed: false,
+        }
+    }
+
+    /// Returns the index of the nearest [`View`] in the given direction from the one at `from`.
+    ///
+    /// Only views whose centers are inside the 90° cone pointing in `direction` are considered,
+    /// and the one with the closest center wins. Returns `None` if there is no such view.
+    #[inline]
+    fn navigate(&self, from: usize, direction: Direction) -> Option<usize> {
+        if from >= self.len() {
+            return None;
+        }
+
+        let origin = self.bounds_of(from).center();
+
+        let mut nearest: Option<(usize, i64)> = None;
+        for idx in (0..self.len()).filter(|&idx| idx != from) {
+            let delta = self.bounds_of(idx).center() - origin;
+            let (along, across) = match direction {
+                Direction::Up => (-delta.y, delta.x),
+                Direction::Down => (delta.y, delta.x),
+                Direction::Left => (-delta.x, delta.y),
+                Direction::Right => (delta.x, delta.y),
+            };
+
+            if along <= 0 || across.abs() > along {
+                continue;
+            }
+
+            let distance = i64::from(along).pow(2) + i64::from(across).pow(2);
+            if nearest.map_or(true, |(_, nearest)| distance < nearest) {
+                nearest = Some((idx, distance));
+            }
+        }
+
+        nearest.map(|(idx, _)| idx)
+    }
+
+    /// Returns the indices of the views in reading order: top to bottom, then left to right.
+    ///
+    /// The views are sorted by the top edge of their bounding box, then by the left edge. Views
+    /// that start at the same point keep their order in the view group.
+    #[inline]
+    fn reading_order(&self) -> ReadingOrder<'_, Self>
+    where
+        Self: Sized,
+    {
+        ReadingOrder {
+            group: self,
+            previous: None,
+            remaining: self.len(),
+        }
+    }
+}
+
+/// Error returned by [`DrawableViewGroup::try_draw_elements`] and
+/// [`DrawableViewGroup::try_draw_elements_checked`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DrawError<E> {
+    /// The draw target returned an error while drawing a view.
+    Target {
+        /// The index of the view that was being drawn.
+        index: usize,
+
+        /// The error returned by the draw target.
+        error: E,
+    },
+
+    /// A view is not fully inside the bounding box of the draw target. Nothing was drawn.
+    OutOfBounds {
+        /// The index of the first view that is out of bounds.
+        index: usize,
+
+        /// The bounds of the view.
+        bounds: Rectangle,
+    },
+}
+
+impl<E> DrawError<E> {
+    /// Returns the index of the view that caused the error.
+    #[inline]
+    pub fn index(&self) -> usize {
+        match self {
+            DrawError::Target { index, .. } | DrawError::OutOfBounds { index, .. } => *index,
+        }
+    }
+}
+
+/// A [`ViewGroup`] whose members can be drawn one by one.
+pub trait DrawableViewGroup<C>: ViewGroup
+where
+    C: PixelColor,
+{
+    /// Draws the [`View`] object at position `idx`.
+    fn draw_child<D>(&self, idx: usize, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>;
+
+    /// Draws every [`View`] object in ascending order of their [`z_index`](View::z_index).
+    ///
+    /// Views with the same z-index are drawn in the order of their indices.
+    #[inline]
+    fn draw_z_ordered<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut current = match (0..self.len()).map(|idx| self.at(idx).z_index()).min() {
+            Some(z_index) => z_index,
+            None => return Ok(()),
+        };
+
+        // Draw one z-index at a time, so we don't need to allocate space for sorting.
+        loop {
+            let mut next: Option<i32> = None;
+            for idx in 0..self.len() {
+                let z_index = self.at(idx).z_index();
+                if z_index == current {
+                    self.draw_child(idx, display)?;
+                } else if z_index > current && next.map_or(true, |next| z_index < next) {
+                    next = Some(z_index);
+                }
+            }
+
+            match next {
+                Some(z_index) => current = z_index,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Draws every [`View`] object moved by an offset computed from its index and bounds.
+    ///
+    /// The views are drawn in the order of their indices, each one moved by the offset returned by
+    /// `offset`. Only the drawing is moved, the views themselves keep their positions. This is
+    /// useful for effects that place views along a curve, e.g. a carousel.
+    #[inline]
+    fn draw_transformed<D, F>(&self, display: &mut D, mut offset: F) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        F: FnMut(usize, Rectangle) -> Point,
+    {
+        for idx in 0..self.len() {
+            let by = offset(idx, self.bounds_of(idx));
+            self.draw_child(idx, &mut display.translated(by))?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws every [`View`] object in the order of their indices, reporting which one failed.
+    ///
+    /// Drawing stops at the first error. The views before the failing one have been drawn, and
+    /// the failing view may have been drawn partially.
+    #[inline]
+    fn try_draw_elements<D>(&self, display: &mut D) -> Result<(), DrawError<D::Error>>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for index in 0..self.len() {
+            self.draw_child(index, display)
+                .map_err(|error| DrawError::Target { index, error })?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every [`View`] object is inside the draw target before drawing them.
+    ///
+    /// If a non-empty view is not fully inside the bounding box of `display`, nothing is drawn and
+    /// [`DrawError::OutOfBounds`] is returned for the first such view. Otherwise, this is the
+    /// same as [`try_draw_elements`](DrawableViewGroup::try_draw_elements).
+    #[inline]
+    fn try_draw_elements_checked<D>(&self, display: &mut D) -> Result<(), DrawError<D::Error>>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let target = display.bounding_box();
+        for index in 0..self.len() {
+            let bounds = self.bounds_of(index);
+            if !bounds.is_zero_sized() && target.intersection(&bounds) != bounds {
+                return Err(DrawError::OutOfBounds { index, bounds });
+            }
+        }
+
+        self.try_draw_elements(display)
+    }
+}
+
+/// Iterator over the areas that changed between two frames of a [`ViewGroup`].
+///
+/// See [`ViewGroup::diff_by_id`].
+pub struct DirtyRects<'a, VG> {
+    current: &'a VG,
+    previous: &'a VG,
+    idx: usize,
+    removed: bool,
+}
+
+impl<VG> Iterator for DirtyRects<'_, VG>
+where
+    VG: ViewGroup,
+{
+    type Item = Rectangle;
+
+    #[inline]
+    fn next(&mut self) -> Option<Rectangle> {
+        // First, compare the current views to the previous ones, then look for removed views.
+        loop {
+            let (from, to) = if self.removed {
+                (self.previous, self.current)
+            } else {
+                (self.current, self.previous)
+            };
+
+            if self.idx >= from.len() {
+                if self.removed {
+                    return None;
+                }
+                self.removed = true;
+                self.idx = 0;
+                continue;
+            }
+
+            let idx = self.idx;
+            self.idx += 1;
+
+            let id = match from.at(idx).id() {
+                Some(id) => id,
+                None => continue,
+            };
+            let bounds = from.bounds_of(idx);
+            let other = (0..to.len())
+                .find(|&other| to.at(other).id() == Some(id))
+                .map(|other| to.bounds_of(other));
+
+            match other {
+                None => return Some(bounds),
+                Some(other) if !self.removed && other != bounds => {
+                    return Some(union_non_empty(other, bounds))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+/// Iterator over the indices of the views of a [`ViewGroup`] in reading order.
+///
+/// See [`ViewGroup::reading_order`].
+pub struct ReadingOrder<'a, VG> {
+    group: &'a VG,
+    previous: Option<(i32, i32, usize)>,
+    remaining: usize,
+}
+
+impl<VG> Iterator for ReadingOrder<'_, VG>
+where
+    VG: ViewGroup,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        // Selecting the next smallest key each time doesn't need a buffer to sort into.
+        let key = |idx: usize| {
+            let top_left = self.group.bounds_of(idx).top_left;
+            (top_left.y, top_left.x, idx)
+        };
+
+        let next = (0..self.group.len())
+            .map(key)
+            .filter(|&key| self.previous.map_or(true, |previous| key > previous))
+            .min()?;
+
+        self.previous = Some(next);
+        self.remaining -= 1;
+        Some(next.2)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<VG> ExactSizeIterator for ReadingOrder<'_, VG> where VG: ViewGroup {}
+
+/// Directions for navigating between the views of a [`ViewGroup`].
+///
+/// See [`ViewGroup::navigate`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Towards the top of the display.
+    Up,
+
+    /// Towards the bottom of the display.
+    Down,
+
+    /// Towards the left side of the display.
+    Left,
+
+    /// Towards the right side of the display.
+    Right,
 }
 
 /// A [`ViewGroup`] that contains no [`View`] objects.
@@ -46,6 +456,8 @@ impl View for EmptyViewGroup {
     }
 }
 
+impl<S> Restyle<S> for EmptyViewGroup {}
+
 impl ViewGroup for EmptyViewGroup {
     fn len(&self) -> usize {
         0
@@ -72,7 +484,23 @@ impl ViewGroupHelper {
         }
     }
 
+    /// Calls `f` with the index and a mutable reference of every [`View`] object in a view group.
+    ///
+    /// This is most useful with [`Chain`] objects, whose members are otherwise only reachable by
+    /// walking the `parent` fields by hand.
+    ///
+    /// [`Chain`]: crate::object_chain::Chain
+    #[inline]
+    pub fn for_each_view(vg: &mut impl ViewGroup, mut f: impl FnMut(usize, &mut dyn View)) {
+        for i in 0..ViewGroup::len(vg) {
+            f(i, vg.at_mut(i));
+        }
+    }
+
     /// Returns the smallest bounding box that envelopes all [`View`] objects in a view group.
+    ///
+    /// Empty views (see [`View::is_zero_sized`]) are ignored. If every view is empty, the result is
+    /// the bounding box of the first view.
     #[inline]
     pub fn bounds(vg: &impl ViewGroup) -> Rectangle {
         if ViewGroup::len(vg) == 0 {
@@ -82,9 +510,358 @@ impl ViewGroupHelper {
         let mut rect = vg.bounds_of(0);
 
         for i in 1..vg.len() {
-            rect = rect.enveloping(&vg.bounds_of(i));
+            rect = union_non_empty(rect, vg.bounds_of(i));
         }
 
         rect
     }
+
+    /// Returns the combined [`Revision`] of every [`View`] object in a view group.
+    ///
+    /// [`Revision`]: crate::views::Revision
+    #[inline]
+    pub fn revision(vg: &impl ViewGroup) -> Revision {
+        (0..ViewGroup::len(vg)).fold(Revision::ZERO, |revision, i| {
+            revision.combine(vg.at(i).revision())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::{Pixel, Primitive, Size},
+        primitives::{PrimitiveStyle, Styled},
+    };
+
+    /// A draw target that fails on the `fail_at`th pixel.
+    struct FailingDisplay {
+        drawn: usize,
+        fail_at: usize,
+    }
+
+    impl embedded_graphics::geometry::OriginDimensions for FailingDisplay {
+        fn size(&self) -> Size {
+            Size::new(16, 16)
+        }
+    }
+
+    impl DrawTarget for FailingDisplay {
+        type Color = BinaryColor;
+        type Error = usize;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for _ in pixels {
+                if self.drawn == self.fail_at {
+                    return Err(self.drawn);
+                }
+                self.drawn += 1;
+            }
+
+            Ok(())
+        }
+    }
+
+    fn squares() -> [Styled<Rectangle, PrimitiveStyle<BinaryColor>>; 3] {
+        let square = Rectangle::new(Point::zero(), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        [
+            square,
+            square.translate(Point::new(3, 0)),
+            square.translate(Point::new(6, 0)),
+        ]
+    }
+
+    #[test]
+    fn try_draw_elements_reports_failing_view() {
+        let mut squares = squares();
+        let group = Views::new(&mut squares);
+
+        let mut display = FailingDisplay {
+            drawn: 0,
+            fail_at: 6,
+        };
+        assert_eq!(
+            Err(DrawError::Target { index: 1, error: 6 }),
+            group.try_draw_elements(&mut display)
+        );
+
+        let mut display = FailingDisplay {
+            drawn: 0,
+            fail_at: 12,
+        };
+        assert_eq!(Ok(()), group.try_draw_elements(&mut display));
+        assert_eq!(12, display.drawn);
+    }
+
+    #[test]
+    fn try_draw_elements_checked_fails_before_drawing() {
+        let mut squares = squares();
+        squares[2].translate_mut(Point::new(9, 0));
+        let group = Views::new(&mut squares);
+
+        let mut display = FailingDisplay {
+            drawn: 0,
+            fail_at: 100,
+        };
+        let error = group.try_draw_elements_checked(&mut display).unwrap_err();
+
+        assert_eq!(2, error.index());
+        assert_eq!(
+            DrawError::OutOfBounds {
+                index: 2,
+                bounds: Rectangle::new(Point::new(15, 0), Size::new(2, 2))
+            },
+            error
+        );
+        assert_eq!(0, display.drawn);
+    }
+
+    #[test]
+    fn distribute_evenly_between_fixed_ends() {
+        let rect =
+            |x: i32, y: i32, width: u32| Rectangle::new(Point::new(x, y), Size::new(width, 4));
+        let mut views = [rect(0, 0, 4), rect(5, 3, 6), rect(9, -2, 2), rect(30, 1, 4)];
+
+        Views::new(&mut views).distribute_evenly(Axis::Horizontal);
+
+        // 30 - 4 - 6 - 2 = 18 free pixels, split into 3 gaps of 6
+        assert_eq!(
+            [
+                rect(0, 0, 4),
+                rect(10, 3, 6),
+                rect(22, -2, 2),
+                rect(30, 1, 4)
+            ],
+            views
+        );
+    }
+
+    #[test]
+    fn distribute_evenly_rounding_and_small_groups() {
+        let rect = |y: i32| Rectangle::new(Point::new(3, y), Size::new(2, 2));
+
+        // 11 - 2 - 2 = 7 free pixels, the first gap is larger
+        let mut views = [rect(0), rect(9), rect(11)];
+        Views::new(&mut views).distribute_evenly(Axis::Vertical);
+        assert_eq!([rect(0), rect(6), rect(11)], views);
+
+        let mut views = [rect(0), rect(9)];
+        Views::new(&mut views).distribute_evenly(Axis::Vertical);
+        assert_eq!([rect(0), rect(9)], views);
+    }
+
+    #[test]
+    fn navigate_grid() {
+        let cell = Rectangle::new(Point::zero(), Size::new(10, 10));
+        let mut cells = [
+            cell,
+            cell.translate(Point::new(12, 0)),
+            cell.translate(Point::new(0, 12)),
+            cell.translate(Point::new(12, 12)),
+        ];
+        let grid = Views::new(&mut cells);
+
+        assert_eq!(Some(1), grid.navigate(0, Direction::Right));
+        assert_eq!(Some(2), grid.navigate(0, Direction::Down));
+        assert_eq!(Some(3), grid.navigate(1, Direction::Down));
+        assert_eq!(Some(3), grid.navigate(2, Direction::Right));
+        assert_eq!(Some(0), grid.navigate(2, Direction::Up));
+
+        assert_eq!(None, grid.navigate(1, Direction::Right));
+        assert_eq!(None, grid.navigate(0, Direction::Up));
+        assert_eq!(None, grid.navigate(4, Direction::Up));
+    }
+
+    #[test]
+    fn bounds_ignore_empty_views() {
+        let content = Rectangle::new(Point::new(100, 40), Size::new(10, 10));
+        let hidden = Rectangle::new(Point::zero(), Size::zero());
+        let line = Rectangle::new(Point::new(-5, 0), Size::new(0, 60));
+
+        let chain = Chain::new(hidden).append(content).append(line);
+        assert_eq!(content, chain.bounds());
+
+        let mut views = [content, hidden, line];
+        assert_eq!(content, Views::new(&mut views).bounds());
+    }
+
+    #[test]
+    fn bounds_of_empty_views_is_first_view() {
+        let first = Rectangle::new(Point::new(5, 5), Size::zero());
+        let second = Rectangle::new(Point::new(50, 0), Size::new(0, 10));
+
+        let chain = Chain::new(first).append(second);
+        assert_eq!(first, chain.bounds());
+
+        let mut views = [first, second];
+        assert_eq!(first, Views::new(&mut views).bounds());
+    }
+
+    #[test]
+    fn for_each_view_updates_chain() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let positions = [Point::new(1, 2), Point::new(3, 4), Point::new(5, 6)];
+
+        let mut chain = Chain::new(rect).append(rect).append(rect);
+
+        ViewGroupHelper::for_each_view(&mut chain, |idx, view| {
+            view.translate_impl(positions[idx] - view.bounds().top_left)
+        });
+
+        for (idx, position) in positions.iter().enumerate() {
+            assert_eq!(*position, chain.bounds_of(idx).top_left);
+        }
+    }
+
+    #[test]
+    fn translate_each_moves_views_by_own_offset() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let offsets = [Point::new(1, 2), Point::new(-3, 4), Point::new(5, -6)];
+
+        let mut chain = Chain::new(rect).append(rect).append(rect);
+        chain.translate_each(&offsets);
+
+        for (idx, offset) in offsets.iter().enumerate() {
+            assert_eq!(*offset, chain.bounds_of(idx).top_left);
+        }
+
+        // Missing offsets leave views in place, extra offsets are ignored
+        let mut chain = Chain::new(rect).append(rect).append(rect);
+        chain.translate_each(&offsets[..1]);
+        assert_eq!(Point::new(1, 2), chain.bounds_of(0).top_left);
+        assert_eq!(Point::zero(), chain.bounds_of(2).top_left);
+
+        let mut chain = Chain::new(rect);
+        chain.translate_each(&offsets);
+        assert_eq!(Point::new(1, 2), chain.object.top_left);
+    }
+
+    #[test]
+    fn local_coordinates_round_trip() {
+        let rect = Rectangle::new(Point::new(10, 20), Size::new(5, 5));
+        let group = Chain::new(rect).append(rect.translate(Point::new(3, -4)));
+
+        let point = Point::new(12, 17);
+        let local = group.to_local(point);
+
+        assert_eq!(Point::new(2, 1), local);
+        assert_eq!(point, group.to_parent(local));
+        assert_eq!(
+            Point::new(-7, 3),
+            group.to_local(group.to_parent(Point::new(-7, 3)))
+        );
+    }
+
+    #[test]
+    fn diff_by_id_reports_moved_views() {
+        use crate::views::Identified;
+
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let frame = || {
+            Chain::new(Identified::new(rect, 1))
+                .append(Identified::new(rect.translate(Point::new(10, 0)), 2))
+                .append(Identified::new(rect.translate(Point::new(20, 0)), 3))
+        };
+
+        let previous = frame();
+        let mut current = frame();
+        current.at_mut(1).translate_impl(Point::new(0, 3));
+
+        let mut dirty = current.diff_by_id(&previous);
+        assert_eq!(
+            Some(Rectangle::new(Point::new(10, 0), Size::new(5, 8))),
+            dirty.next()
+        );
+        assert_eq!(None, dirty.next());
+        assert_eq!(0, previous.diff_by_id(&previous).count());
+    }
+
+    #[test]
+    fn diff_by_id_reports_added_and_removed_views() {
+        use crate::views::Identified;
+
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let mut previous = [Identified::new(rect, 1), Identified::new(rect, 2)];
+        let mut current = [
+            Identified::new(rect, 1),
+            Identified::new(rect.translate(Point::new(7, 7)), 3),
+        ];
+        let previous = Views::new(&mut previous);
+        let current = Views::new(&mut current);
+
+        let mut dirty = current.diff_by_id(&previous);
+        assert_eq!(Some(rect.translate(Point::new(7, 7))), dirty.next());
+        assert_eq!(Some(rect), dirty.next());
+        assert_eq!(None, dirty.next());
+    }
+
+    #[test]
+    fn reading_order_of_scattered_views() {
+        let rect = |x, y| Rectangle::new(Point::new(x, y), Size::new(4, 4));
+        let mut views = [
+            rect(30, 10),
+            rect(0, 20),
+            rect(5, 0),
+            rect(30, 10),
+            rect(-5, 10),
+            rect(12, 0),
+            rect(2, 20),
+        ];
+        let group = Views::new(&mut views);
+
+        let order = group.reading_order();
+        assert_eq!(7, order.len());
+        // The views at (30, 10) share a position and keep their order
+        assert_eq!(
+            vec![2, 5, 4, 0, 3, 1, 6],
+            group.reading_order().collect::<Vec<_>>()
+        );
+
+        let mut empty: [Rectangle; 0] = [];
+        assert_eq!(None, Views::new(&mut empty).reading_order().next());
+    }
+
+    #[test]
+    fn draw_transformed_keeps_positions() {
+        use embedded_graphics::{
+            mock_display::MockDisplay,
+            pixelcolor::BinaryColor,
+            prelude::Primitive,
+            primitives::{PrimitiveStyle, Styled},
+        };
+
+        let dot = Rectangle::new(Point::zero(), Size::new(1, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+        let mut dots: [Styled<Rectangle, PrimitiveStyle<BinaryColor>>; 3] = [
+            dot,
+            dot.translate(Point::new(1, 0)),
+            dot.translate(Point::new(2, 0)),
+        ];
+        let group = Views::new(&mut dots);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        group
+            .draw_transformed(&mut display, |idx, _| Point::new(0, idx as i32 * 2))
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "#  ", //
+                "   ", //
+                " # ", //
+                "   ", //
+                "  #", //
+            ])
+        );
+        assert_eq!(Point::new(2, 0), group.bounds_of(2).top_left);
+    }
 }
@@ -6,12 +6,65 @@ use embedded_graphics::{
 };
 
 use crate::{
+    align::AlignmentPosition,
     object_chain::{Chain, ChainElement, Link},
-    prelude::RectExt,
-    view_group::ViewGroup,
+    theme::Restyle,
+    utils::rect_helper::union_non_empty,
+    view_group::{DrawableViewGroup, ViewGroup},
+    views::{Pinned, Revision},
     View,
 };
 
+impl<V> Chain<V> {
+    /// Append a view that is [pinned] to `position` of the span of a layout
+    ///
+    /// [pinned]: Pinned
+    #[inline]
+    pub fn append_pinned<T: View>(
+        self,
+        view: T,
+        position: AlignmentPosition,
+    ) -> Link<Pinned<T>, Self> {
+        self.append(Pinned::new(view, position))
+    }
+}
+
+impl<S, V> Restyle<S> for Chain<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.object.restyle(style);
+    }
+}
+
+impl<S, V, VC> Restyle<S> for Link<V, VC>
+where
+    V: Restyle<S>,
+    VC: Restyle<S> + ChainElement,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.object.restyle(style);
+        self.parent.restyle(style);
+    }
+}
+
+impl<V, VC: ChainElement> Link<V, VC> {
+    /// Append a view that is [pinned] to `position` of the span of a layout
+    ///
+    /// [pinned]: Pinned
+    #[inline]
+    pub fn append_pinned<T: View>(
+        self,
+        view: T,
+        position: AlignmentPosition,
+    ) -> Link<Pinned<T>, Self> {
+        self.append(Pinned::new(view, position))
+    }
+}
+
 impl<C, V, VC> Drawable for Link<V, VC>
 where
     C: PixelColor,
@@ -40,9 +93,7 @@ where
 {
     #[inline]
     fn bounds(&self) -> Rectangle {
-        let bounds = self.object.bounds();
-
-        bounds.enveloping(&self.parent.bounds())
+        union_non_empty(self.parent.bounds(), self.object.bounds())
     }
 
     #[inline]
@@ -50,6 +101,11 @@ where
         self.object.translate_mut(by);
         self.parent.translate_mut(by);
     }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.parent.revision().combine(self.object.revision())
+    }
 }
 
 impl<C, V> Drawable for Chain<V>
@@ -83,6 +139,11 @@ where
     fn translate_impl(&mut self, by: Point) {
         self.object.translate_mut(by);
     }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.object.revision()
+    }
 }
 
 impl<V, VC> ViewGroup for Link<V, VC>
@@ -130,6 +191,15 @@ where
 
         self.parent.translate_child(index, by)
     }
+
+    #[inline]
+    fn translate_each(&mut self, offsets: &[Point]) {
+        if let Some(by) = offsets.get(ViewGroup::len(self) - 1) {
+            self.object.translate_impl(*by);
+        }
+
+        self.parent.translate_each(offsets)
+    }
 }
 
 impl<V> ViewGroup for Chain<V>
@@ -169,3 +239,102 @@ where
         self.object.translate_impl(by)
     }
 }
+
+impl<C, V, VC> DrawableViewGroup<C> for Link<V, VC>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+    VC: DrawableViewGroup<C> + ChainElement,
+{
+    #[inline]
+    fn draw_child<D>(&self, index: usize, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if index == ViewGroup::len(self) - 1 {
+            self.object.draw(display)?;
+            return Ok(());
+        }
+
+        self.parent.draw_child(index, display)
+    }
+}
+
+impl<C, V> DrawableViewGroup<C> for Chain<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn draw_child<D>(&self, index: usize, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        assert_eq!(index, 0);
+
+        self.object.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::{Circle, Rectangle},
+    };
+
+    fn rect(width: u32, height: u32) -> Rectangle {
+        Rectangle::new(Point::new(3, -2), Size::new(width, height))
+    }
+
+    fn assert_same_arrangement(expected: &impl ViewGroup, actual: &impl ViewGroup) {
+        assert_eq!(expected.len(), actual.len());
+        for idx in 0..expected.len() {
+            assert_eq!(
+                expected.bounds_of(idx),
+                actual.bounds_of(idx),
+                "view {}",
+                idx
+            );
+        }
+        assert_eq!(expected.bounds(), actual.bounds());
+    }
+
+    #[test]
+    fn prepend_arranges_like_append() {
+        let expected = LinearLayout::horizontal(
+            Chain::new(Circle::new(Point::zero(), 5))
+                .append(rect(4, 8))
+                .append(rect(7, 2)),
+        )
+        .arrange();
+
+        let prepended = LinearLayout::horizontal(
+            Chain::new(rect(4, 8))
+                .append(rect(7, 2))
+                .prepend(Circle::new(Point::zero(), 5)),
+        )
+        .arrange();
+
+        assert_same_arrangement(&expected, &prepended);
+    }
+
+    #[test]
+    fn concat_arranges_like_append() {
+        let expected = LinearLayout::vertical(
+            Chain::new(rect(4, 8))
+                .append(rect(7, 2))
+                .append(Circle::new(Point::zero(), 5))
+                .append(rect(1, 1)),
+        )
+        .arrange();
+
+        let header = Chain::new(rect(4, 8)).append(rect(7, 2));
+        let body = Chain::new(Circle::new(Point::zero(), 5)).append(rect(1, 1));
+        let concatenated = LinearLayout::vertical(header.concat(body)).arrange();
+
+        assert_same_arrangement(&expected, &concatenated);
+    }
+}
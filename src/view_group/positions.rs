@@ -0,0 +1,156 @@
+use embedded_graphics::{
+    prelude::{Dimensions, Point},
+    primitives::Rectangle,
+};
+
+use crate::{
+    align::AlignmentPosition,
+    layout::Axis,
+    utils::rect_helper::union_non_empty,
+    view_group::{EmptyViewGroup, ViewGroup},
+    View,
+};
+
+/// The items that a layout places: the views of a view group, or anything that stands in for them.
+///
+/// This is the part of a [`ViewGroup`] that layouts need to compute positions. Unlike a view group,
+/// an item doesn't need to be a [`View`], so buffers that only track positions implement this
+/// trait instead of handing out placeholder views.
+pub(crate) trait Items {
+    /// Returns the number of items.
+    fn item_count(&self) -> usize;
+
+    /// Returns the bounding box of the item at `idx`.
+    fn item_bounds(&self, idx: usize) -> Rectangle;
+
+    /// Returns the [margins](View::margins) of the item at `idx`.
+    fn item_margins(&self, _idx: usize) -> (u32, u32) {
+        (0, 0)
+    }
+
+    /// Returns the position the item at `idx` is [pinned](View::pinned) to, if any.
+    fn item_pinned(&self, _idx: usize) -> Option<AlignmentPosition> {
+        None
+    }
+
+    /// Returns the leading and trailing [margins](crate::layout::linear::spacing::HasMargins) of the item at `idx` along `axis`.
+    fn item_inner_margins(&self, _idx: usize, _axis: Axis) -> (i32, i32) {
+        (0, 0)
+    }
+
+    /// Returns the union of the bounding boxes of the items, like [`ViewGroupHelper::bounds`].
+    ///
+    /// [`ViewGroupHelper::bounds`]: crate::view_group::ViewGroupHelper::bounds
+    fn items_bounds(&self) -> Rectangle {
+        if self.item_count() == 0 {
+            return EmptyViewGroup.bounds();
+        }
+
+        (1..self.item_count()).fold(self.item_bounds(0), |bounds, idx| {
+            union_non_empty(bounds, self.item_bounds(idx))
+        })
+    }
+}
+
+/// [`Items`] that a layout can move.
+pub(crate) trait Placeable: Items {
+    /// Moves the item at `idx` by `by`.
+    fn move_item(&mut self, idx: usize, by: Point);
+}
+
+impl<VG> Items for VG
+where
+    VG: ViewGroup + ?Sized,
+{
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+
+    fn item_bounds(&self, idx: usize) -> Rectangle {
+        self.bounds_of(idx)
+    }
+
+    fn item_margins(&self, idx: usize) -> (u32, u32) {
+        self.at(idx).margins()
+    }
+
+    fn item_pinned(&self, idx: usize) -> Option<AlignmentPosition> {
+        self.at(idx).pinned()
+    }
+
+    fn item_inner_margins(&self, idx: usize, axis: Axis) -> (i32, i32) {
+        inner_margins(self.at(idx), axis)
+    }
+}
+
+/// Returns the leading and trailing [margins](crate::layout::linear::spacing::HasMargins) of `view` along `axis`.
+pub(crate) fn inner_margins(view: &dyn View, axis: Axis) -> (i32, i32) {
+    view.as_has_margins().map_or((0, 0), |margins| {
+        (margins.leading_margin(axis), margins.trailing_margin(axis))
+    })
+}
+
+impl<VG> Placeable for VG
+where
+    VG: ViewGroup + ?Sized,
+{
+    fn move_item(&mut self, idx: usize, by: Point) {
+        self.translate_child(idx, by);
+    }
+}
+
+/// Arranges the positions of immutable items instead of the items themselves.
+///
+/// The items only need to know their size. Moving an item moves its entry in `positions`, which
+/// start out at the top left corners of the items. The items are not views, so they have no
+/// margins and are not pinned.
+pub(crate) struct PositionBuffer<'a, T> {
+    items: &'a [T],
+    positions: &'a mut [Point],
+}
+
+impl<'a, T> PositionBuffer<'a, T>
+where
+    T: Dimensions,
+{
+    /// Wrap `items` and `positions`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` and `positions` have different lengths.
+    pub(crate) fn new(items: &'a [T], positions: &'a mut [Point]) -> Self {
+        assert_eq!(
+            items.len(),
+            positions.len(),
+            "the positions buffer must have one element per item"
+        );
+
+        for (position, item) in positions.iter_mut().zip(items) {
+            *position = item.bounding_box().top_left;
+        }
+
+        Self { items, positions }
+    }
+}
+
+impl<T> Items for PositionBuffer<'_, T>
+where
+    T: Dimensions,
+{
+    fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    fn item_bounds(&self, idx: usize) -> Rectangle {
+        Rectangle::new(self.positions[idx], self.items[idx].bounding_box().size)
+    }
+}
+
+impl<T> Placeable for PositionBuffer<'_, T>
+where
+    T: Dimensions,
+{
+    fn move_item(&mut self, idx: usize, by: Point) {
+        self.positions[idx] += by;
+    }
+}
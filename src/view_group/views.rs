@@ -1,4 +1,4 @@
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, Range};
 
 use embedded_graphics::{
     draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
@@ -6,7 +6,9 @@ use embedded_graphics::{
 };
 
 use crate::{
-    view_group::{ViewGroup, ViewGroupHelper},
+    theme::Restyle,
+    view_group::{DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    views::Revision,
     View,
 };
 
@@ -27,6 +29,36 @@ where
     pub fn new(views: &'a mut [T]) -> Self {
         Self { views }
     }
+
+    /// Translate the views in `range`, leaving the others in place.
+    ///
+    /// Indices outside of the slice are ignored.
+    #[inline]
+    pub fn translate_range(&mut self, range: Range<usize>, by: Point) {
+        let end = range.end.min(self.views.len());
+        let start = range.start.min(end);
+
+        for view in self.views[start..end].iter_mut() {
+            view.translate_impl(by);
+        }
+    }
+
+    /// Update every view from the element of `data` at the same index.
+    ///
+    /// If `data` and the views have different lengths, the shorter one wins: views without a
+    /// corresponding element and elements without a corresponding view are skipped.
+    ///
+    /// Returns the number of updated views.
+    #[inline]
+    pub fn zip_update<D>(&mut self, data: &[D], mut f: impl FnMut(&mut T, &D)) -> usize {
+        let mut count = 0;
+        for (view, datum) in self.views.iter_mut().zip(data) {
+            f(view, datum);
+            count += 1;
+        }
+
+        count
+    }
 }
 
 impl<T> ViewGroup for Views<'_, T>
@@ -72,6 +104,23 @@ where
     fn bounds(&self) -> Rectangle {
         ViewGroupHelper::bounds(self)
     }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        ViewGroupHelper::revision(self)
+    }
+}
+
+impl<S, T> Restyle<S> for Views<'_, T>
+where
+    T: Restyle<S> + View,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        for view in self.views.iter_mut() {
+            view.restyle(style);
+        }
+    }
 }
 
 impl<'a, T> Deref for Views<'a, T>
@@ -118,10 +167,105 @@ where
     }
 }
 
+impl<C, T> Views<'_, T>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    /// Draw the views in order and collect their outputs into an array.
+    ///
+    /// Drawing stops at the first error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of views is not `N`.
+    #[inline]
+    pub fn draw_outputs<D, const N: usize>(
+        &self,
+        display: &mut D,
+    ) -> Result<[T::Output; N], D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        assert_eq!(
+            N,
+            self.views.len(),
+            "the number of views must match the length of the output array"
+        );
+
+        let mut views = self.views.iter();
+        let mut error = None;
+        let outputs = [(); N].map(|_| {
+            let view = views.next()?;
+            if error.is_some() {
+                return None;
+            }
+
+            view.draw(display).map_err(|e| error = Some(e)).ok()
+        });
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(outputs.map(|output| output.expect("every view was drawn"))),
+        }
+    }
+}
+
+impl<C, T> DrawableViewGroup<C> for Views<'_, T>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn draw_child<D>(&self, idx: usize, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views[idx].draw(display)?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use embedded_graphics::primitives::Line;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X9, MonoTextStyle},
+        pixelcolor::BinaryColor,
+        primitives::Line,
+        text::Text,
+    };
+
+    #[test]
+    fn translate_range_moves_only_range() {
+        let mut views = [Line::new(Point::zero(), Point::new(1, 0)); 3];
+
+        let mut vg = Views::new(&mut views);
+        vg.translate_range(1..2, Point::new(0, 5));
+        vg.translate_range(2..10, Point::new(3, 0));
+        vg.translate_range(5..10, Point::new(3, 0));
+
+        assert_eq!(Point::zero(), views[0].start);
+        assert_eq!(Point::new(0, 5), views[1].start);
+        assert_eq!(Point::new(3, 0), views[2].start);
+    }
+
+    #[test]
+    fn zip_update_shorter_wins() {
+        let mut views = [Line::new(Point::zero(), Point::new(1, 0)); 3];
+        let offsets = [Point::new(0, 1), Point::new(0, 2)];
+
+        let mut vg = Views::new(&mut views);
+        let updated = vg.zip_update(&offsets, |view, offset| {
+            view.translate_mut(*offset);
+        });
+
+        assert_eq!(2, updated);
+        assert_eq!(Point::new(0, 1), views[0].start);
+        assert_eq!(Point::new(0, 2), views[1].start);
+        assert_eq!(Point::zero(), views[2].start);
+    }
 
     #[test]
     fn len_is_slice_length() {
@@ -149,4 +293,33 @@ mod test {
         // deliberate count() because Views only exposes `iter()` through `Deref`.
         assert_eq!(1, vg[1..2].iter().count());
     }
+
+    #[test]
+    fn draw_outputs_collects_an_array() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let mut labels = [
+            Text::new("a", Point::new(0, 6), style),
+            Text::new("bc", Point::new(0, 16), style),
+            Text::new("def", Point::new(0, 26), style),
+        ];
+
+        let vg = Views::new(&mut labels);
+        let mut display = MockDisplay::new();
+        let ends: [Point; 3] = vg.draw_outputs(&mut display).unwrap();
+
+        assert_eq!(
+            [Point::new(6, 6), Point::new(12, 16), Point::new(18, 26)],
+            ends
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn draw_outputs_checks_the_length() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let mut labels = [Text::new("a", Point::new(0, 6), style); 2];
+
+        let mut display = MockDisplay::new();
+        let _: [Point; 3] = Views::new(&mut labels).draw_outputs(&mut display).unwrap();
+    }
 }
@@ -0,0 +1,175 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// Show a view only on every other period of frames, e.g. for a blinking cursor.
+///
+/// The wrapped view is visible for `period` frames, then hidden for `period` frames, starting with
+/// a visible period at frame 0. The bounds of `Blink` are always the bounds of the wrapped view, so
+/// the layout doesn't change while the view is hidden.
+///
+/// `Blink` doesn't keep track of time, the application passes the current frame number to
+/// [`draw_at_frame`](Blink::draw_at_frame).
+pub struct Blink<V> {
+    view: V,
+    period: u32,
+}
+
+impl<V> Blink<V>
+where
+    V: View,
+{
+    /// Wrap `view`, toggling its visibility every `period` frames.
+    ///
+    /// A `period` of 0 keeps the view visible.
+    #[inline]
+    pub fn new(view: V, period: u32) -> Self {
+        Self { view, period }
+    }
+
+    /// Returns the number of frames the view is visible or hidden for.
+    #[inline]
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    /// Returns `true` if the view is drawn at the given frame.
+    #[inline]
+    pub fn is_visible_at(&self, frame: u32) -> bool {
+        self.period == 0 || (frame / self.period) % 2 == 0
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<C, V> Blink<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    /// Draw the wrapped view if it is visible at the given frame.
+    #[inline]
+    pub fn draw_at_frame<D>(&self, frame: u32, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.is_visible_at(frame) {
+            self.view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<V> View for Blink<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view);
+}
+
+impl<S, V> Restyle<S> for Blink<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Rectangle},
+    };
+
+    fn cursor() -> Blink<impl View + Drawable<Color = BinaryColor>> {
+        let rect = Rectangle::new(Point::new(1, 0), Size::new(1, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        Blink::new(rect, 3)
+    }
+
+    #[test]
+    fn draws_on_visible_frames() {
+        let blink = cursor();
+
+        for frame in [0, 2, 6] {
+            let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+            blink.draw_at_frame(frame, &mut display).unwrap();
+
+            assert_eq!(
+                display,
+                MockDisplay::from_pattern(&[
+                    " #", //
+                    " #", //
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn hidden_on_odd_periods() {
+        let blink = cursor();
+
+        for frame in [3, 5, 9] {
+            let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+            blink.draw_at_frame(frame, &mut display).unwrap();
+
+            assert_eq!(display, MockDisplay::new());
+        }
+        assert_eq!(
+            Rectangle::new(Point::new(1, 0), Size::new(1, 2)),
+            blink.bounds()
+        );
+    }
+
+    #[test]
+    fn zero_period_is_always_visible() {
+        let blink = Blink::new(Rectangle::new(Point::zero(), Size::new(1, 1)), 0);
+
+        assert!(blink.is_visible_at(0));
+        assert!(blink.is_visible_at(1));
+    }
+}
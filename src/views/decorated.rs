@@ -0,0 +1,337 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::AnchorPoint,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    layout::linear::{LinearLayout, OrientationExt},
+    theme::Restyle,
+    utils::rect_helper::union_non_empty,
+    view_group::ViewGroup,
+    views::Revision,
+    View,
+};
+
+/// Identifies a view attached to a [`Decorated`] view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AttachmentHandle(usize);
+
+struct Attachment<V> {
+    view: V,
+    anchor: AnchorPoint,
+    offset: Point,
+}
+
+/// A view with up to `N` decorations attached to the corners or edges of its bounding box.
+///
+/// Each attached view is placed so that its `anchor` point is `offset` pixels away from the same
+/// anchor point of the content, e.g. a close glyph in the top right corner of a card. Attached
+/// views follow the content when it is translated or arranged, and are drawn on top of it.
+///
+/// By default, the attached views are part of the bounding box, see
+/// [`with_attachments_in_bounds`](Decorated::with_attachments_in_bounds).
+///
+/// If the size of the content is changed through [`content_mut`](Decorated::content_mut), call
+/// [`reanchor`](Decorated::reanchor) to move the attached views to the new bounding box.
+/// Attaching, detaching and reanchoring views changes the [`Revision`] of the decorated view.
+pub struct Decorated<T, V, const N: usize> {
+    content: T,
+    attachments: [Option<Attachment<V>>; N],
+    attachments_in_bounds: bool,
+    revision: Revision,
+}
+
+impl<T, V, const N: usize> Decorated<T, V, N>
+where
+    T: View,
+    V: View,
+{
+    /// Wrap `content` without any decorations.
+    #[inline]
+    pub fn new(content: T) -> Self {
+        Self {
+            content,
+            attachments: [(); N].map(|_| None),
+            attachments_in_bounds: true,
+            revision: Revision::ZERO,
+        }
+    }
+
+    /// Set whether the attached views are part of the bounding box.
+    #[inline]
+    #[must_use]
+    pub fn with_attachments_in_bounds(self, include: bool) -> Self {
+        Self {
+            attachments_in_bounds: include,
+            ..self
+        }
+    }
+
+    /// Attach `view` to the `anchor` point of the content, moved by `offset`.
+    ///
+    /// Returns the view back if there are already `N` views attached.
+    #[inline]
+    pub fn attach(
+        &mut self,
+        view: V,
+        anchor: AnchorPoint,
+        offset: Point,
+    ) -> Result<AttachmentHandle, V> {
+        let index = match self.attachments.iter().position(Option::is_none) {
+            Some(index) => index,
+            None => return Err(view),
+        };
+
+        let mut attachment = Attachment {
+            view,
+            anchor,
+            offset,
+        };
+        Self::place(&mut attachment, self.content.bounds());
+        self.attachments[index] = Some(attachment);
+        self.revision = self.revision.next();
+
+        Ok(AttachmentHandle(index))
+    }
+
+    /// Remove the view attached with `handle`, and return it.
+    #[inline]
+    pub fn detach(&mut self, handle: AttachmentHandle) -> Option<V> {
+        let attachment = self.attachments.get_mut(handle.0)?.take()?;
+        self.revision = self.revision.combine(attachment.view.revision()).next();
+
+        Some(attachment.view)
+    }
+
+    /// Returns a reference to the view attached with `handle`.
+    #[inline]
+    pub fn attachment(&self, handle: AttachmentHandle) -> Option<&V> {
+        self.attachments
+            .get(handle.0)?
+            .as_ref()
+            .map(|attachment| &attachment.view)
+    }
+
+    /// Returns a reference to the content.
+    #[inline]
+    pub fn content(&self) -> &T {
+        &self.content
+    }
+
+    /// Returns a mutable reference to the content.
+    #[inline]
+    pub fn content_mut(&mut self) -> &mut T {
+        &mut self.content
+    }
+
+    /// Move the attached views to the current bounding box of the content.
+    #[inline]
+    pub fn reanchor(&mut self) {
+        let bounds = self.content.bounds();
+        self.revision = self.revision.next();
+        for attachment in self.attachments.iter_mut().flatten() {
+            Self::place(attachment, bounds);
+        }
+    }
+
+    fn place(attachment: &mut Attachment<V>, bounds: Rectangle) {
+        let target = bounds.anchor_point(attachment.anchor) + attachment.offset;
+        let current = attachment.view.bounds().anchor_point(attachment.anchor);
+
+        attachment.view.translate_impl(target - current);
+    }
+}
+
+impl<LD, VG, V, const N: usize> Decorated<LinearLayout<LD, VG>, V, N>
+where
+    LD: OrientationExt,
+    VG: ViewGroup,
+    V: View,
+{
+    /// Arrange the content and move the attached views to its new bounding box.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        self.content = self.content.arrange();
+        self.reanchor();
+        self
+    }
+}
+
+impl<T, V, const N: usize> View for Decorated<T, V, N>
+where
+    T: View,
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.content.translate_impl(by);
+        self.reanchor();
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.content.bounds();
+        if !self.attachments_in_bounds {
+            return bounds;
+        }
+
+        self.attachments
+            .iter()
+            .flatten()
+            .map(|attachment| attachment.view.bounds())
+            .fold(bounds, union_non_empty)
+    }
+
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        union_non_empty(self.bounds(), self.content.hit_bounds())
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.attachments.iter().flatten().fold(
+            self.revision.combine(self.content.revision()),
+            |revision, attachment| revision.combine(attachment.view.revision()),
+        )
+    }
+
+    forward_view_hooks!(|this| this.content; except hit_bounds, as_has_margins, revision);
+}
+
+impl<S, T, V, const N: usize> Restyle<S> for Decorated<T, V, N>
+where
+    T: Restyle<S>,
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.content.restyle(style);
+        for attachment in self.attachments.iter_mut().flatten() {
+            attachment.view.restyle(style);
+        }
+    }
+}
+
+impl<C, T, V, const N: usize> Drawable for Decorated<T, V, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.content.draw(display)?;
+        for attachment in self.attachments.iter().flatten() {
+            attachment.view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    const GLYPH: Rectangle = Rectangle::new(Point::zero(), Size::new(3, 3));
+
+    #[test]
+    fn attachments_follow_translation() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+        let mut card: Decorated<_, Rectangle, 2> =
+            Decorated::new(LinearLayout::vertical(Chain::new(rect).append(rect)).arrange());
+
+        let close = card
+            .attach(GLYPH, AnchorPoint::TopRight, Point::new(-1, 1))
+            .unwrap();
+        let grip = card
+            .attach(GLYPH, AnchorPoint::BottomRight, Point::zero())
+            .unwrap();
+        assert_eq!(
+            Some(&GLYPH.translate(Point::new(6, 1))),
+            card.attachment(close)
+        );
+        assert_eq!(
+            Some(&GLYPH.translate(Point::new(7, 7))),
+            card.attachment(grip)
+        );
+
+        card.translate_mut(Point::new(5, 10));
+
+        assert_eq!(
+            Some(&GLYPH.translate(Point::new(11, 11))),
+            card.attachment(close)
+        );
+        assert_eq!(
+            Some(&GLYPH.translate(Point::new(12, 17))),
+            card.attachment(grip)
+        );
+
+        // No space for a third attachment until one is detached
+        assert_eq!(
+            Err(GLYPH),
+            card.attach(GLYPH, AnchorPoint::Center, Point::zero())
+        );
+        assert_eq!(
+            Some(GLYPH.translate(Point::new(11, 11))),
+            card.detach(close)
+        );
+        assert_eq!(None, card.attachment(close));
+        assert!(card
+            .attach(GLYPH, AnchorPoint::Center, Point::zero())
+            .is_ok());
+    }
+
+    #[test]
+    fn attachments_reanchor_on_arrange() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+        let mut card: Decorated<_, Rectangle, 1> =
+            Decorated::new(LinearLayout::horizontal(Chain::new(rect).append(rect)).arrange());
+        let grip = card
+            .attach(GLYPH, AnchorPoint::BottomRight, Point::new(1, 1))
+            .unwrap();
+        assert_eq!(
+            Some(&GLYPH.translate(Point::new(18, 3))),
+            card.attachment(grip)
+        );
+
+        card.content_mut().inner_mut().object.size = Size::new(15, 15);
+        let card = card.arrange();
+
+        let content = card.content().bounds();
+        assert_eq!(Size::new(25, 15), content.size);
+        assert_eq!(
+            card.attachment(grip)
+                .map(|glyph| glyph.anchor_point(AnchorPoint::BottomRight)),
+            Some(content.anchor_point(AnchorPoint::BottomRight) + Point::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn attachments_in_bounds_is_configurable() {
+        let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+        let mut card: Decorated<_, Rectangle, 1> = Decorated::new(rect);
+        card.attach(GLYPH, AnchorPoint::TopRight, Point::new(2, -2))
+            .unwrap();
+
+        assert_eq!(
+            Rectangle::new(Point::new(0, -2), Size::new(12, 7)),
+            card.bounds()
+        );
+
+        let card = card.with_attachments_in_bounds(false);
+        assert_eq!(rect, card.bounds());
+    }
+}
@@ -0,0 +1,177 @@
+use core::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, PixelColor, Point, Size, Transform},
+    primitives::{ContainsPoint, Rectangle},
+    Drawable,
+};
+
+use crate::theme::Restyle;
+
+/// A view that is nothing.
+///
+/// `Empty` has a position, but no size, and drawing it does nothing. It can be used as a
+/// placeholder where a view is required, e.g. in a [`Chain`] of drawable views, where `C` is the
+/// color type of the other views. Layouts don't reserve any space for empty views, but element
+/// spacing still applies to them, as if they were a slot of zero length.
+///
+/// The color type is inferred when the view is drawn along with other views. Otherwise, specify
+/// it explicitly, e.g. `Empty::<BinaryColor>::new()`.
+///
+/// [`Chain`]: crate::object_chain::Chain
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Empty<C = BinaryColor> {
+    position: Point,
+    color: PhantomData<C>,
+}
+
+impl<C> Empty<C>
+where
+    C: PixelColor,
+{
+    /// Create an empty view at the origin.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::at(Point::zero())
+    }
+
+    /// Create an empty view at `position`.
+    #[inline]
+    pub const fn at(position: Point) -> Self {
+        Self {
+            position,
+            color: PhantomData,
+        }
+    }
+}
+
+impl<S, C> Restyle<S> for Empty<C> {}
+
+impl<C> Default for Empty<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> Dimensions for Empty<C> {
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.position, Size::zero())
+    }
+}
+
+impl<C> Transform for Empty<C>
+where
+    C: Copy,
+{
+    #[inline]
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            color: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+        self
+    }
+}
+
+impl<C> ContainsPoint for Empty<C> {
+    #[inline]
+    fn contains(&self, _point: Point) -> bool {
+        false
+    }
+}
+
+impl<C> Drawable for Empty<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, _display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X9, MonoTextStyle},
+        text::Text,
+    };
+
+    #[test]
+    fn empty_takes_up_no_space() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text = Text::new("ab", Point::zero(), style);
+
+        let with_empty = LinearLayout::vertical(
+            Chain::new(text)
+                .append(Empty::at(Point::new(30, 30)))
+                .append(text),
+        )
+        .arrange();
+        let without_empty = LinearLayout::vertical(Chain::new(text).append(text)).arrange();
+
+        assert_eq!(without_empty.bounds(), with_empty.bounds());
+        assert_eq!(
+            without_empty.inner().object.bounds(),
+            with_empty.inner().object.bounds()
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        with_empty.draw(&mut display).unwrap();
+
+        let mut expected: MockDisplay<BinaryColor> = MockDisplay::new();
+        without_empty.draw(&mut expected).unwrap();
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn empty_is_a_zero_slot_with_margins() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text = Text::new("ab", Point::zero(), style);
+
+        let with_empty = LinearLayout::vertical(
+            Chain::new(text)
+                .append(Empty::<BinaryColor>::new())
+                .append(text),
+        )
+        .with_spacing(FixedMargin(1))
+        .arrange();
+
+        // Both margins around the empty view are kept
+        assert_eq!(Size::new(12, 20), with_empty.size());
+    }
+
+    #[test]
+    fn empty_is_a_zero_sized_view() {
+        let mut empty = Empty::<BinaryColor>::at(Point::new(3, 4));
+        View::translate_mut(&mut empty, Point::new(1, 1));
+
+        assert_eq!(
+            Rectangle::new(Point::new(4, 5), Size::zero()),
+            empty.bounds()
+        );
+        assert!(empty.is_zero_sized());
+        assert!(!empty.contains(Point::new(4, 5)));
+    }
+}
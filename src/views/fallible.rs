@@ -0,0 +1,214 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// A view that may have failed to be created.
+///
+/// `Fallible` wraps the `Result` of creating a view, so that it can be placed in a layout without
+/// unwrapping it first. An `Ok` view is measured, placed and drawn as usual. An `Err` acts like an
+/// [`Empty`] view: it has a position, but no size, and drawing it does nothing.
+///
+/// `View` can't be implemented for `Result` itself, because `Result` may implement the traits that
+/// already make every `Transform + Dimensions` type a `View`.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, views::Fallible};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let rect = |height| Rectangle::new(Point::zero(), Size::new(10, height));
+///
+/// let layout = LinearLayout::vertical(
+///     Chain::new(Fallible::new(Ok::<_, ()>(rect(4))))
+///         .append(Fallible::<Rectangle, _>::new(Err(())))
+///         .append(Fallible::new(Ok::<_, ()>(rect(6)))),
+/// )
+/// .arrange();
+///
+/// // The failed view doesn't take up any space
+/// assert_eq!(Size::new(10, 10), layout.size());
+/// assert_eq!(None, layout.inner().parent.object.ok_bounds());
+/// assert_eq!(Point::new(0, 4), layout.inner().object.bounds().top_left);
+/// ```
+///
+/// [`Empty`]: crate::views::Empty
+pub struct Fallible<V, E> {
+    result: Result<V, E>,
+
+    /// The position of an `Err` view.
+    position: Point,
+}
+
+impl<V, E> Fallible<V, E>
+where
+    V: View,
+{
+    /// Wrap the result of creating a view. An `Err` is placed at the origin.
+    #[inline]
+    pub fn new(result: Result<V, E>) -> Self {
+        Self {
+            result,
+            position: Point::zero(),
+        }
+    }
+
+    /// Returns the bounds of the view, or `None` if it failed to be created.
+    #[inline]
+    pub fn ok_bounds(&self) -> Option<Rectangle> {
+        self.result.as_ref().ok().map(View::bounds)
+    }
+
+    /// Returns `true` if the view was created successfully.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns a reference to the wrapped result.
+    #[inline]
+    pub fn result(&self) -> Result<&V, &E> {
+        self.result.as_ref()
+    }
+
+    /// Returns a mutable reference to the wrapped result.
+    ///
+    /// Moving an `Ok` view through this reference moves it within the layout, too.
+    #[inline]
+    pub fn result_mut(&mut self) -> Result<&mut V, &mut E> {
+        self.result.as_mut()
+    }
+
+    /// Consume the wrapper and return the wrapped result.
+    #[inline]
+    pub fn into_result(self) -> Result<V, E> {
+        self.result
+    }
+}
+
+impl<V, E> From<Result<V, E>> for Fallible<V, E>
+where
+    V: View,
+{
+    #[inline]
+    fn from(result: Result<V, E>) -> Self {
+        Self::new(result)
+    }
+}
+
+impl<V, E> View for Fallible<V, E>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        match &mut self.result {
+            Ok(view) => view.translate_impl(by),
+            Err(_) => self.position += by,
+        }
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        match &self.result {
+            Ok(view) => view.bounds(),
+            Err(_) => Rectangle::new(self.position, Size::zero()),
+        }
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        match &mut self.result {
+            Ok(view) => view.measure(constraints),
+            Err(_) => constraints.constrain(Size::zero()),
+        }
+    }
+
+    forward_view_hooks!(maybe | this | this.result.as_ref().ok());
+}
+
+impl<S, V, E> Restyle<S> for Fallible<V, E>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        if let Ok(view) = &mut self.result {
+            view.restyle(style);
+        }
+    }
+}
+
+impl<C, V, E> Drawable for Fallible<V, E>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = Option<V::Output>;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match &self.result {
+            Ok(view) => view.draw(display).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{prelude::*, views::Empty};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Rectangle, Styled},
+    };
+
+    type Item = Fallible<Styled<Rectangle, PrimitiveStyle<BinaryColor>>, &'static str>;
+
+    #[test]
+    fn only_ok_views_occupy_space() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 2)).into_styled(style);
+
+        let layout = LinearLayout::vertical(
+            Chain::new(Item::new(Err("no font")))
+                .append(Item::new(Ok(rect)))
+                .append(Item::new(Err("no text"))),
+        )
+        .with_alignment(horizontal::Center)
+        .arrange();
+
+        assert_eq!(Size::new(3, 2), layout.size());
+        let views = layout.inner();
+        assert_eq!(None, views.parent.parent.object.ok_bounds());
+        assert_eq!(Some(layout.bounds_of(1)), views.parent.object.ok_bounds());
+        assert_eq!(None, views.object.ok_bounds());
+
+        // Errors are placed like empty views
+        let empty = LinearLayout::vertical(
+            Chain::new(Empty::<BinaryColor>::new())
+                .append(rect)
+                .append(Empty::<BinaryColor>::new()),
+        )
+        .with_alignment(horizontal::Center)
+        .arrange();
+        for i in 0..3 {
+            assert_eq!(empty.bounds_of(i), layout.bounds_of(i));
+        }
+
+        let mut display = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+        display.assert_pattern(&["   ", "###", "###"]);
+    }
+}
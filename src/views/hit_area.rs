@@ -0,0 +1,224 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::{ContainsPoint, Rectangle},
+    Drawable,
+};
+
+use crate::{
+    layout::Constraints, theme::Restyle, utils::rounding::half, view_group::ViewGroup, View,
+};
+
+/// Expand the hit area of a view to a minimum size.
+///
+/// Small interactive elements are hard to hit on a touch screen. `ExpandHitArea` keeps the drawn
+/// size of the wrapped view, but reports a [`hit_bounds`] rectangle of at least the given minimum
+/// size, centered on the view. The hit area can optionally be clamped to the display bounds.
+///
+/// [`hit_bounds`]: View::hit_bounds
+pub struct ExpandHitArea<V> {
+    view: V,
+    min_size: Size,
+    clamp_to: Option<Rectangle>,
+}
+
+impl<V> ExpandHitArea<V>
+where
+    V: View,
+{
+    /// Wrap `view` so that its hit area is at least `min_size` large.
+    #[inline]
+    pub fn new(view: V, min_size: Size) -> Self {
+        Self {
+            view,
+            min_size,
+            clamp_to: None,
+        }
+    }
+
+    /// Limit the hit area to the given bounds, e.g. the bounding box of the display.
+    #[inline]
+    #[must_use]
+    pub fn clamped_to(self, bounds: Rectangle) -> Self {
+        Self {
+            clamp_to: Some(bounds),
+            ..self
+        }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for ExpandHitArea<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        let bounds = self.view.hit_bounds();
+        let size = bounds.size.component_max(self.min_size);
+        let grow = size - bounds.size;
+
+        let top_left =
+            bounds.top_left - Point::new(half(grow.width as i32), half(grow.height as i32));
+        let hit_bounds = Rectangle::new(top_left, size);
+
+        match self.clamp_to {
+            Some(clamp_to) => hit_bounds.intersection(&clamp_to),
+            None => hit_bounds,
+        }
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view; except hit_bounds);
+}
+
+impl<S, V> Restyle<S> for ExpandHitArea<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<V> ContainsPoint for ExpandHitArea<V>
+where
+    V: View,
+{
+    #[inline]
+    fn contains(&self, point: Point) -> bool {
+        self.hit_bounds().contains(point)
+    }
+}
+
+impl<C, V> Drawable for ExpandHitArea<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
+
+/// Returns the indices of the views in `view_group` whose hit area is smaller than `min_size`.
+#[inline]
+pub fn min_hit_size_violations(
+    view_group: &impl ViewGroup,
+    min_size: Size,
+) -> impl Iterator<Item = usize> + '_ {
+    (0..view_group.len()).filter(move |&idx| {
+        let size = view_group.at(idx).hit_bounds().size;
+        size.width < min_size.width || size.height < min_size.height
+    })
+}
+
+/// Panics if any view in `view_group` has a hit area smaller than `min_size`.
+///
+/// The panic message contains the index and the hit area size of the first offending view.
+#[inline]
+pub fn assert_min_hit_size(view_group: &impl ViewGroup, min_size: Size) {
+    if let Some(idx) = min_hit_size_violations(view_group, min_size).next() {
+        let size = view_group.at(idx).hit_bounds().size;
+        panic!(
+            "View {} has a hit area of {}x{}, which is smaller than {}x{}",
+            idx, size.width, size.height, min_size.width, min_size.height
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn contains_expanded_area() {
+        let button = ExpandHitArea::new(
+            Rectangle::new(Point::new(10, 10), Size::new(4, 4)),
+            Size::new(9, 9),
+        );
+
+        assert_eq!(Size::new(4, 4), button.size());
+        assert!(button.contains(Point::new(8, 8)));
+        assert!(button.contains(Point::new(16, 16)));
+        assert!(!button.contains(Point::new(7, 8)));
+        assert!(!button.contains(Point::new(16, 17)));
+    }
+
+    #[test]
+    fn hit_area_is_clamped() {
+        let display = Rectangle::new(Point::zero(), Size::new(20, 20));
+        let button = ExpandHitArea::new(
+            Rectangle::new(Point::zero(), Size::new(4, 4)),
+            Size::new(9, 9),
+        )
+        .clamped_to(display);
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(7, 7)),
+            button.hit_bounds()
+        );
+    }
+
+    #[test]
+    fn validator_finds_small_views() {
+        let small = Rectangle::new(Point::zero(), Size::new(4, 4));
+
+        let group = Chain::new(ExpandHitArea::new(small, Size::new(9, 9)))
+            .append(small)
+            .append(Rectangle::new(Point::zero(), Size::new(10, 10)));
+
+        let mut violations = min_hit_size_violations(&group, Size::new(9, 9));
+        assert_eq!(Some(1), violations.next());
+        assert_eq!(None, violations.next());
+    }
+
+    #[test]
+    #[should_panic(expected = "View 1 has a hit area of 4x4")]
+    fn assert_min_hit_size_panics() {
+        let small = Rectangle::new(Point::zero(), Size::new(4, 4));
+        let group = Chain::new(ExpandHitArea::new(small, Size::new(9, 9))).append(small);
+
+        assert_min_hit_size(&group, Size::new(9, 9));
+    }
+}
@@ -0,0 +1,108 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// Assign an identifier to a view.
+///
+/// Identifiers are used to match the views of two frames of a view group, regardless of their
+/// position in the group. See [`ViewGroup::diff_by_id`].
+///
+/// [`ViewGroup::diff_by_id`]: crate::view_group::ViewGroup::diff_by_id
+pub struct Identified<V> {
+    view: V,
+    id: u32,
+}
+
+impl<V> Identified<V>
+where
+    V: View,
+{
+    /// Wrap `view` and assign `id` to it.
+    #[inline]
+    pub fn new(view: V, id: u32) -> Self {
+        Self { view, id }
+    }
+
+    /// Returns the identifier of the view.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for Identified<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn id(&self) -> Option<u32> {
+        Some(self.id)
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view; except id);
+}
+
+impl<S, V> Restyle<S> for Identified<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Identified<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
@@ -0,0 +1,301 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    pixelcolor::{
+        Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb555,
+        Rgb565, Rgb666, Rgb888, RgbColor,
+    },
+    prelude::{Dimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable, Pixel,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// Colors that have a complement.
+///
+/// Inverting a color twice returns the original color.
+pub trait Invert: PixelColor {
+    /// Returns the complement of the color.
+    fn invert(self) -> Self;
+}
+
+impl Invert for BinaryColor {
+    #[inline]
+    fn invert(self) -> Self {
+        BinaryColor::invert(self)
+    }
+}
+
+macro_rules! impl_invert_gray {
+    ($($type:ident),+) => {
+        $(
+            impl Invert for $type {
+                #[inline]
+                fn invert(self) -> Self {
+                    $type::new(Self::WHITE.luma() - self.luma())
+                }
+            }
+        )+
+    };
+}
+
+impl_invert_gray!(Gray2, Gray4, Gray8);
+
+macro_rules! impl_invert_rgb {
+    ($($type:ident),+) => {
+        $(
+            impl Invert for $type {
+                #[inline]
+                fn invert(self) -> Self {
+                    $type::new(
+                        Self::MAX_R - self.r(),
+                        Self::MAX_G - self.g(),
+                        Self::MAX_B - self.b(),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_invert_rgb!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+
+/// Draw a view with inverted colors, e.g. to highlight the selected row of a menu.
+///
+/// Every pixel the view draws is replaced by its complement, see [`Invert`]. Only the pixels
+/// inside the bounds of the view are drawn. The view itself is not changed, and it is measured
+/// and placed like the wrapped view.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, views::Inverted};
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{PrimitiveStyle, Rectangle},
+/// # };
+/// let row = Rectangle::new(Point::zero(), Size::new(3, 1))
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::Off, 1));
+///
+/// let mut display = MockDisplay::new();
+/// Inverted::new(row).draw(&mut display).unwrap();
+///
+/// display.assert_pattern(&["###"]);
+/// ```
+pub struct Inverted<V> {
+    view: V,
+}
+
+impl<V> Inverted<V>
+where
+    V: View,
+{
+    /// Draw `view` with inverted colors.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self { view }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the adapter and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for Inverted<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view);
+}
+
+impl<S, V> Restyle<S> for Inverted<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Inverted<V>
+where
+    C: Invert,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let bounds = self.view.bounds();
+
+        self.view.draw(&mut InvertColors {
+            display: &mut display.clipped(&bounds),
+        })
+    }
+}
+
+/// Draw target adapter that inverts the color of every pixel.
+struct InvertColors<'a, D> {
+    display: &'a mut D,
+}
+
+impl<D> Dimensions for InvertColors<'_, D>
+where
+    D: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.display.bounding_box()
+    }
+}
+
+impl<D> DrawTarget for InvertColors<'_, D>
+where
+    D: DrawTarget,
+    D::Color: Invert,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.display.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(point, color.invert())),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.display
+            .fill_contiguous(area, colors.into_iter().map(Invert::invert))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.display.fill_solid(area, color.invert())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        prelude::{Primitive, Transform, WebColors},
+        primitives::{PrimitiveStyle, PrimitiveStyleBuilder},
+    };
+
+    #[test]
+    fn binary_colors_are_inverted() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+        let view = Rectangle::new(Point::new(1, 1), Size::new(4, 3)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        Inverted::new(view).draw(&mut display).unwrap();
+
+        display.assert_pattern(&["     ", " ....", " .##.", " ...."]);
+    }
+
+    /// Draws one pixel outside of its bounds on every side.
+    struct Overdraw(Rectangle);
+
+    impl View for Overdraw {
+        fn translate_impl(&mut self, by: Point) {
+            Transform::translate_mut(&mut self.0, by);
+        }
+
+        fn bounds(&self) -> Rectangle {
+            self.0
+        }
+    }
+
+    impl Drawable for Overdraw {
+        type Color = BinaryColor;
+        type Output = ();
+
+        fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+        where
+            D: DrawTarget<Color = BinaryColor>,
+        {
+            self.0
+                .offset(1)
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+                .draw(display)
+        }
+    }
+
+    #[test]
+    fn drawing_is_limited_to_the_bounds() {
+        let bounds = Rectangle::new(Point::new(2, 1), Size::new(3, 2));
+
+        let mut display = MockDisplay::new();
+        Inverted::new(Overdraw(bounds)).draw(&mut display).unwrap();
+
+        assert_eq!(bounds, display.affected_area());
+        display.assert_pattern(&["     ", "  ###", "  ###"]);
+    }
+
+    #[test]
+    fn rgb_colors_are_complemented() {
+        assert_eq!(Rgb565::WHITE, Rgb565::BLACK.invert());
+        assert_eq!(
+            Rgb565::new(31 - 4, 63 - 40, 31 - 17),
+            Rgb565::new(4, 40, 17).invert()
+        );
+        assert_eq!(Rgb565::CSS_ORANGE, Rgb565::CSS_ORANGE.invert().invert());
+        assert_eq!(Rgb888::new(0, 255, 127), Rgb888::new(255, 0, 128).invert());
+        assert_eq!(Gray4::new(3), Gray4::new(12).invert());
+
+        let view = Rectangle::new(Point::zero(), Size::new(2, 1))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::RED));
+
+        let mut display = MockDisplay::new();
+        Inverted::new(view).draw(&mut display).unwrap();
+
+        assert_eq!(Some(Rgb565::CYAN), display.get_pixel(Point::new(1, 0)));
+    }
+}
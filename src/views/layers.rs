@@ -0,0 +1,217 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{theme::Restyle, utils::rect_helper::union_non_empty, views::Revision, View};
+
+/// A fixed number of optional layers that are drawn on top of each other.
+///
+/// Layers are drawn in ascending order of their index, so layer 0 is the bottom layer, e.g. the
+/// background, and layer `N - 1` is drawn last, on top of everything else. Each layer can be
+/// arranged independently, and layers can be left empty.
+///
+/// The bounds of `Layers` are the union of the bounds of the present layers. Setting or clearing a
+/// layer changes the [`Revision`] of the layers.
+pub struct Layers<V, const N: usize> {
+    layers: [Option<V>; N],
+    revision: Revision,
+}
+
+impl<V, const N: usize> Layers<V, N>
+where
+    V: View,
+{
+    /// Create a new object with all layers empty.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            layers: [(); N].map(|_| None),
+            revision: Revision::ZERO,
+        }
+    }
+
+    /// Place `view` on the layer at `index`, and return the view it replaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    #[inline]
+    pub fn set(&mut self, index: usize, view: V) -> Option<V> {
+        let replaced = self.layers[index].replace(view);
+        self.revision = self
+            .revision
+            .combine(replaced.as_ref().map_or(Revision::ZERO, View::revision))
+            .next();
+        replaced
+    }
+
+    /// Remove the view from the layer at `index`.
+    ///
+    /// Returns the bounds of the removed view, i.e. the area that needs to be redrawn, or `None`
+    /// if the layer was empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    #[inline]
+    pub fn clear(&mut self, index: usize) -> Option<Rectangle> {
+        let view = self.layers[index].take()?;
+        self.revision = self.revision.combine(view.revision()).next();
+
+        Some(view.bounds())
+    }
+
+    /// Returns a reference to the view on the layer at `index`, if there is one.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&V> {
+        self.layers.get(index)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the view on the layer at `index`, if there is one.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut V> {
+        self.layers.get_mut(index)?.as_mut()
+    }
+}
+
+impl<V, const N: usize> Default for Layers<V, N>
+where
+    V: View,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, const N: usize> View for Layers<V, N>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        for view in self.layers.iter_mut().flatten() {
+            view.translate_impl(by);
+        }
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.layers
+            .iter()
+            .flatten()
+            .map(View::bounds)
+            .reduce(union_non_empty)
+            .unwrap_or_else(Rectangle::zero)
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.layers
+            .iter()
+            .flatten()
+            .fold(self.revision, |revision, view| {
+                revision.combine(view.revision())
+            })
+    }
+}
+
+impl<S, V, const N: usize> Restyle<S> for Layers<V, N>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        for view in self.layers.iter_mut().flatten() {
+            view.restyle(style);
+        }
+    }
+}
+
+impl<C, V, const N: usize> Drawable for Layers<V, N>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for view in self.layers.iter().flatten() {
+            view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::Rgb888,
+        prelude::{Primitive, RgbColor, Size},
+        primitives::{PrimitiveStyle, Styled},
+    };
+
+    type Layer = Styled<Rectangle, PrimitiveStyle<Rgb888>>;
+
+    fn layer(x: i32, width: u32, color: Rgb888) -> Layer {
+        Rectangle::new(Point::new(x, 0), Size::new(width, 1))
+            .into_styled(PrimitiveStyle::with_fill(color))
+    }
+
+    #[test]
+    fn layers_are_drawn_in_order() {
+        let mut layers = Layers::<Layer, 3>::new();
+        layers.set(2, layer(2, 1, Rgb888::BLUE));
+        layers.set(0, layer(0, 4, Rgb888::RED));
+        layers.set(1, layer(1, 2, Rgb888::GREEN));
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        layers.draw(&mut display).unwrap();
+
+        assert_eq!(display, MockDisplay::from_pattern(&["RGBR"]));
+    }
+
+    #[test]
+    fn bounds_of_present_layers() {
+        let mut layers = Layers::<Layer, 3>::new();
+        assert_eq!(Rectangle::zero(), layers.bounds());
+
+        layers.set(0, layer(0, 2, Rgb888::RED));
+        layers.set(2, layer(5, 3, Rgb888::BLUE));
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(8, 1)),
+            layers.bounds()
+        );
+    }
+
+    #[test]
+    fn clear_returns_dirty_area() {
+        let mut layers = Layers::<Layer, 3>::new();
+        layers.set(1, layer(0, 8, Rgb888::GREEN));
+        layers.set(2, layer(3, 2, Rgb888::BLUE));
+
+        assert_eq!(
+            Some(Rectangle::new(Point::new(3, 0), Size::new(2, 1))),
+            layers.clear(2)
+        );
+        assert_eq!(None, layers.clear(2));
+        assert!(layers.get(2).is_none());
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(8, 1)),
+            layers.bounds()
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use core::cell::RefCell;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// A view that is only constructed when it is drawn.
+///
+/// `Lazy` stands in for a view with a size that is known up front, e.g. an item of a long,
+/// scrolling list. Layouts measure and place the placeholder without constructing the view, so
+/// views that are never visible are never built. The view is constructed by calling `build` the
+/// first time the `Lazy` view is drawn, or when [`force`] is called, and it is kept until
+/// [`reset`] is called.
+///
+/// The constructed view is moved to the top left corner of the placeholder, and it is moved
+/// along with the placeholder afterwards. The bounds of a `Lazy` view are always the placeholder
+/// bounds, even if the constructed view has a different size. Until the view is constructed,
+/// the `Lazy` view has the default z-index, id, margins, pinning and revision of a [`View`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, views::Lazy};
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{PrimitiveStyle, Rectangle},
+/// # };
+/// let square = || {
+///     Rectangle::new(Point::zero(), Size::new(2, 2))
+///         .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+/// };
+///
+/// let mut items = [Lazy::new(Size::new(2, 2), square), Lazy::new(Size::new(2, 2), square)];
+/// let layout = LinearLayout::horizontal(Views::new(&mut items)).arrange();
+/// assert!(!layout.inner()[1].is_built());
+///
+/// let mut display = MockDisplay::new();
+/// layout.draw(&mut display).unwrap();
+///
+/// display.assert_pattern(&["####", "####"]);
+/// assert!(layout.inner()[1].is_built());
+/// ```
+///
+/// [`force`]: Lazy::force
+/// [`reset`]: Lazy::reset
+pub struct Lazy<V, F> {
+    position: Point,
+    size: Size,
+    measure: Option<fn(Constraints) -> Size>,
+    build: F,
+    view: RefCell<Option<V>>,
+}
+
+impl<V, F> Lazy<V, F>
+where
+    V: View,
+    F: Fn() -> V,
+{
+    /// Create a placeholder of the given `size` for the view that `build` constructs.
+    #[inline]
+    pub fn new(size: Size, build: F) -> Self {
+        Self {
+            position: Point::zero(),
+            size,
+            measure: None,
+            build,
+            view: RefCell::new(None),
+        }
+    }
+
+    /// Create a placeholder that is sized by `measure` when a layout measures it.
+    ///
+    /// `measure` must not need the view itself, so that measuring doesn't construct it. Until it
+    /// is measured, the placeholder has the size `measure` returns for unbounded constraints.
+    #[inline]
+    pub fn measured(measure: fn(Constraints) -> Size, build: F) -> Self {
+        Self {
+            size: measure(Constraints::unbounded()),
+            measure: Some(measure),
+            ..Self::new(Size::zero(), build)
+        }
+    }
+
+    /// Returns `true` if the view is constructed.
+    #[inline]
+    pub fn is_built(&self) -> bool {
+        self.view.borrow().is_some()
+    }
+
+    /// Construct the view, if it isn't constructed yet, and return a mutable reference to it.
+    #[inline]
+    pub fn force(&mut self) -> &mut V {
+        self.build_view();
+        self.view
+            .get_mut()
+            .as_mut()
+            .expect("the view was just constructed")
+    }
+
+    /// Drop the constructed view. It is constructed again when it is drawn next time.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self.view.get_mut() = None;
+    }
+
+    fn build_view(&self) {
+        let mut view = self.view.borrow_mut();
+        if view.is_none() {
+            let mut built = (self.build)();
+            built.translate_impl(self.position - built.bounds().top_left);
+            *view = Some(built);
+        }
+    }
+}
+
+impl<V, F> View for Lazy<V, F>
+where
+    V: View,
+    F: Fn() -> V,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        if let Some(view) = self.view.get_mut() {
+            view.translate_impl(by);
+        }
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.position, self.size)
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        if let Some(measure) = self.measure {
+            self.size = measure(constraints);
+        }
+
+        constraints.constrain(self.size)
+    }
+
+    forward_view_hooks!(maybe |this| this.view.borrow().as_ref(); except hit_bounds, as_has_margins);
+}
+
+/// Only the view that is already constructed is restyled, views that are constructed later use
+/// the style given by `build`.
+impl<S, V, F> Restyle<S> for Lazy<V, F>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        if let Some(view) = self.view.get_mut() {
+            view.restyle(style);
+        }
+    }
+}
+
+impl<C, V, F> Drawable for Lazy<V, F>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+    F: Fn() -> V,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.build_view();
+        match &*self.view.borrow() {
+            Some(view) => view.draw(display),
+            None => unreachable!("the view was just constructed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::spacing::FixedMargin, prelude::*};
+    use core::{cell::Cell, ops::Range};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Rectangle},
+    };
+
+    fn draw_visible<V>(views: &[V], range: Range<usize>) -> MockDisplay<BinaryColor>
+    where
+        V: Drawable<Color = BinaryColor>,
+    {
+        let mut display = MockDisplay::new();
+        for view in &views[range] {
+            view.draw(&mut display).unwrap();
+        }
+        display
+    }
+
+    #[test]
+    fn off_screen_pages_are_never_built() {
+        let built = Cell::new(0);
+        let row = || {
+            built.set(built.get() + 1);
+            Rectangle::new(Point::new(50, 50), Size::new(4, 2))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+        };
+
+        let mut rows = [(); 12].map(|_| Lazy::new(Size::new(4, 2), row));
+        let mut layout = LinearLayout::vertical(Views::new(&mut rows))
+            .with_spacing(FixedMargin(1))
+            .arrange();
+        layout.measure(Constraints::loose(Size::new(64, 64)));
+        assert_eq!(0, built.get());
+
+        // A page shows 3 rows
+        let page = Rectangle::new(Point::zero(), Size::new(4, 9));
+        draw_visible(layout.inner(), layout.visible_range(page)).assert_pattern(&[
+            "####", "####", "    ", "####", "####", "    ", "####", "####",
+        ]);
+        assert_eq!(3, built.get());
+
+        // The second page builds 3 more rows, already built rows are kept
+        layout.translate_mut(Point::new(0, -9));
+        draw_visible(layout.inner(), layout.visible_range(page));
+        assert_eq!(6, built.get());
+        layout.translate_mut(Point::new(0, 9));
+        draw_visible(layout.inner(), layout.visible_range(page));
+        assert_eq!(6, built.get());
+
+        let built_rows = layout.inner().iter().filter(|row| row.is_built()).count();
+        assert_eq!(6, built_rows);
+        assert!(layout.inner()[..6].iter().all(Lazy::is_built));
+    }
+
+    #[test]
+    fn constructed_view_follows_the_placeholder() {
+        let mut lazy = Lazy::new(Size::new(3, 3), || {
+            Rectangle::new(Point::new(-7, 4), Size::new(3, 3))
+        });
+        lazy.translate_mut(Point::new(5, 6));
+
+        assert_eq!(Point::new(5, 6), lazy.force().top_left);
+
+        lazy.translate_mut(Point::new(1, 1));
+        assert_eq!(Point::new(6, 7), lazy.force().top_left);
+
+        lazy.reset();
+        assert!(!lazy.is_built());
+        assert_eq!(Point::new(6, 7), lazy.force().top_left);
+    }
+
+    #[test]
+    fn measure_does_not_construct() {
+        let mut lazy = Lazy::measured(
+            |constraints| constraints.constrain(Size::new(20, 5)),
+            || -> Rectangle { unreachable!() },
+        );
+        assert_eq!(Size::new(20, 5), lazy.size());
+
+        assert_eq!(
+            Size::new(12, 5),
+            lazy.measure(Constraints::loose(Size::new(12, 12)))
+        );
+        assert_eq!(Size::new(12, 5), lazy.size());
+    }
+}
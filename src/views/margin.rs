@@ -0,0 +1,188 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// Keep space around a view in a [`LinearLayout`], collapsing with the margins of its neighbours.
+///
+/// The margins are only reserved along the primary axis of the layout: `before` is the space to
+/// the left of (or above) the view, `after` is the space to the right of (or below) it. Like
+/// margins in CSS, adjacent margins collapse, so two views with margins of 4 and 6 pixels are
+/// placed 6 pixels apart. The margins are added to the element spacing of the layout. The margin
+/// before the first and after the last view of the layout are not reserved.
+///
+/// The margins are not part of the bounds of the view.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, views::Margin};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let item = Rectangle::new(Point::zero(), Size::new(10, 10));
+///
+/// let layout = LinearLayout::horizontal(
+///     Chain::new(Margin::new(item, 0, 4)).append(Margin::new(item, 6, 0)),
+/// )
+/// .arrange();
+///
+/// assert_eq!(Size::new(26, 10), layout.size());
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+pub struct Margin<V> {
+    view: V,
+    before: u32,
+    after: u32,
+}
+
+impl<V> Margin<V>
+where
+    V: View,
+{
+    /// Wrap `view` and keep `before` and `after` pixels of space around it.
+    #[inline]
+    pub fn new(view: V, before: u32, after: u32) -> Self {
+        Self {
+            view,
+            before,
+            after,
+        }
+    }
+
+    /// Change the margins of the view.
+    #[inline]
+    pub fn set_margins(&mut self, before: u32, after: u32) {
+        self.before = before;
+        self.after = after;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for Margin<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn margins(&self) -> (u32, u32) {
+        (self.before, self.after)
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view; except margins);
+}
+
+impl<S, V> Restyle<S> for Margin<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Margin<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::spacing::FixedMargin, prelude::*};
+
+    fn item(before: u32, after: u32) -> Margin<Rectangle> {
+        Margin::new(
+            Rectangle::new(Point::new(30, -8), Size::new(10, 5)),
+            before,
+            after,
+        )
+    }
+
+    #[test]
+    fn adjacent_margins_collapse() {
+        let mut items = [item(0, 4), item(6, 3), item(2, 9)];
+        let layout = LinearLayout::vertical(Views::new(&mut items)).arrange();
+
+        let items = layout.into_inner();
+        assert_eq!(Point::new(0, 0), items[0].bounds().top_left);
+        // max(4, 6) = 6
+        assert_eq!(Point::new(0, 11), items[1].bounds().top_left);
+        // max(3, 2) = 3
+        assert_eq!(Point::new(0, 19), items[2].bounds().top_left);
+    }
+
+    #[test]
+    fn margins_add_to_spacing() {
+        let mut items = [item(0, 4), item(6, 0), item(0, 0)];
+
+        let layout = LinearLayout::horizontal(Views::new(&mut items))
+            .with_spacing(FixedMargin(1))
+            .arrange();
+
+        let items = layout.inner();
+        assert_eq!(17, items[1].bounds().top_left.x);
+        assert_eq!(28, items[2].bounds().top_left.x);
+    }
+
+    #[test]
+    fn margins_are_forwarded() {
+        use crate::views::{Identified, ZOrdered};
+
+        let view = ZOrdered::new(Identified::new(item(1, 2), 7), 3);
+
+        assert_eq!((1, 2), view.margins());
+        assert_eq!(Some(7), view.id());
+        assert_eq!(
+            Rectangle::new(Point::new(30, -8), Size::new(10, 5)),
+            view.bounds()
+        );
+    }
+}
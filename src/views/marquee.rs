@@ -0,0 +1,284 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{theme::Restyle, View};
+
+/// Horizontally scrolling window over a wide view.
+///
+/// `Marquee` occupies a fixed width slot, regardless of the width of the wrapped view, so it can be
+/// placed into layouts like any other view. When drawn, the wrapped view is shifted to the left by
+/// the scroll offset and clipped to the window.
+///
+/// The application advances the offset, e.g. once per frame. Without wrap-around, scrolling stops
+/// when the end of the wrapped view reaches the right edge of the window. With wrap-around, the
+/// wrapped view is repeated after a gap, and the offset wraps back to 0 after a full period.
+pub struct Marquee<V> {
+    view: V,
+    width: u32,
+    offset: u32,
+    gap: Option<u32>,
+}
+
+impl<V> Marquee<V>
+where
+    V: View,
+{
+    /// Create a new marquee with a window of the given `width`.
+    ///
+    /// The window is placed at the top left corner of `view`.
+    #[inline]
+    pub fn new(view: V, width: u32) -> Self {
+        Self {
+            view,
+            width,
+            offset: 0,
+            gap: None,
+        }
+    }
+
+    /// Repeat the wrapped view after `gap` pixels once it has scrolled past.
+    #[inline]
+    #[must_use]
+    pub fn with_wrap_around(self, gap: u32) -> Self {
+        Self {
+            gap: Some(gap),
+            ..self
+        }
+    }
+
+    /// Returns `true` if the wrapped view is wider than the window.
+    #[inline]
+    pub fn overflows(&self) -> bool {
+        self.view.size().width > self.width
+    }
+
+    /// Returns the current scroll offset, in pixels.
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the largest scroll offset.
+    ///
+    /// With wrap-around, this is the length of a full period, after which the offset wraps to 0.
+    #[inline]
+    pub fn max_offset(&self) -> u32 {
+        let content_width = self.view.size().width;
+        match self.gap {
+            Some(gap) if self.overflows() => content_width + gap,
+            _ => content_width.saturating_sub(self.width),
+        }
+    }
+
+    /// Set the scroll offset.
+    ///
+    /// The offset is limited to [`max_offset`](Marquee::max_offset), or wrapped around if
+    /// wrap-around is enabled.
+    #[inline]
+    pub fn set_offset(&mut self, offset: u32) {
+        let max_offset = self.max_offset();
+        self.offset = match self.gap {
+            Some(_) if max_offset > 0 => offset % max_offset,
+            _ => offset.min(max_offset),
+        };
+    }
+
+    /// Scroll the wrapped view by `by` pixels.
+    #[inline]
+    pub fn advance(&mut self, by: u32) {
+        self.set_offset(self.offset.saturating_add(by));
+    }
+
+    /// Scroll back to the beginning.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    ///
+    /// If the width of the view changes, call [`reset`](Marquee::reset) or
+    /// [`set_offset`](Marquee::set_offset) to keep the offset valid.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for Marquee<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.view.bounds();
+        Rectangle::new(bounds.top_left, Size::new(self.width, bounds.size.height))
+    }
+
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        // Keep the hit area that the wrapped view adds around itself around the window.
+        let view = self.view.bounds();
+        let hit = self.view.hit_bounds();
+        let bounds = self.bounds();
+        Rectangle::new(
+            bounds.top_left - (view.top_left - hit.top_left),
+            bounds.size + hit.size.saturating_sub(view.size),
+        )
+    }
+
+    forward_view_hooks!(|this| this.view; except hit_bounds, as_has_margins);
+}
+
+impl<S, V> Restyle<S> for Marquee<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Marquee<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let window = self.bounds();
+        let mut clipped = display.clipped(&window);
+
+        let offset = self.offset as i32;
+        self.view
+            .draw(&mut clipped.translated(Point::new(-offset, 0)))?;
+
+        if let Some(gap) = self.gap {
+            let period = (self.view.size().width + gap) as i32;
+            if self.overflows() && period - offset < self.width as i32 {
+                self.view
+                    .draw(&mut clipped.translated(Point::new(period - offset, 0)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Styled},
+    };
+
+    type Content = Link<
+        Styled<Rectangle, PrimitiveStyle<BinaryColor>>,
+        Chain<Styled<Rectangle, PrimitiveStyle<BinaryColor>>>,
+    >;
+
+    /// 3 pixels on, then 3 pixels off
+    fn content() -> Content {
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 1));
+        Chain::new(rect.into_styled(PrimitiveStyle::with_fill(BinaryColor::On))).append(
+            rect.translate(Point::new(3, 0))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off)),
+        )
+    }
+
+    fn draw(marquee: &Marquee<Content>) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        marquee.draw(&mut display).unwrap();
+        display
+    }
+
+    #[test]
+    fn reports_window_size() {
+        let marquee = Marquee::new(content(), 4);
+
+        assert_eq!(Size::new(4, 1), marquee.size());
+        assert_eq!(2, marquee.max_offset());
+        assert_eq!(8, marquee.with_wrap_around(2).max_offset());
+    }
+
+    #[test]
+    fn scrolls_within_window() {
+        let mut marquee = Marquee::new(content(), 4);
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&["###."]));
+
+        marquee.advance(1);
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&["##.."]));
+
+        // Scrolling stops when the end is reached
+        marquee.advance(5);
+        assert_eq!(2, marquee.offset());
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&["#..."]));
+
+        marquee.reset();
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&["###."]));
+    }
+
+    #[test]
+    fn wraps_around() {
+        let mut marquee = Marquee::new(content(), 4).with_wrap_around(2);
+
+        marquee.set_offset(5);
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&[".  #"]));
+
+        marquee.advance(2);
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&[" ###"]));
+
+        // A full period later, the marquee is back at the start
+        marquee.advance(1);
+        assert_eq!(0, marquee.offset());
+        assert_eq!(draw(&marquee), MockDisplay::from_pattern(&["###."]));
+    }
+
+    #[test]
+    fn translated_marquee_stays_in_window() {
+        let mut marquee = Marquee::new(content(), 4)
+            .with_wrap_around(2)
+            .translate(Point::new(2, 1));
+        marquee.set_offset(6);
+
+        assert_eq!(
+            draw(&marquee),
+            MockDisplay::from_pattern(&[
+                "      ", //
+                "    ##", //
+            ])
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Text},
+    transform::Transform,
+    Drawable,
+};
+
+use crate::theme::{ReplaceStyle, Restyle};
+
+/// A [`Text`] that measures itself only when its contents change.
+///
+/// Measuring a text walks through all of its characters, which can dominate the time spent on
+/// arranging a screen with many labels. `MeasuredText` caches the bounding box of the wrapped text.
+/// The cache is updated when the string or the style is changed through this wrapper, and
+/// translating the text moves the cached bounding box along with it.
+///
+/// The wrapped text is only accessible as a shared reference, so the cache can't go stale.
+pub struct MeasuredText<'a, S> {
+    text: Text<'a, S>,
+    bounds: Rectangle,
+}
+
+impl<'a, S> MeasuredText<'a, S>
+where
+    S: TextRenderer,
+{
+    /// Wrap and measure `text`.
+    #[inline]
+    pub fn new(text: Text<'a, S>) -> Self {
+        let bounds = text.bounding_box();
+        Self { text, bounds }
+    }
+
+    /// Change the displayed string and measure the text again.
+    #[inline]
+    pub fn set_text(&mut self, text: &'a str) {
+        self.text.text = text;
+        self.measure();
+    }
+
+    /// Change the character style and measure the text again.
+    #[inline]
+    pub fn set_character_style(&mut self, character_style: S) {
+        self.text.character_style = character_style;
+        self.measure();
+    }
+
+    /// Returns a reference to the wrapped text.
+    #[inline]
+    pub fn inner(&self) -> &Text<'a, S> {
+        &self.text
+    }
+
+    /// Consume the wrapper and return the wrapped text.
+    #[inline]
+    pub fn into_inner(self) -> Text<'a, S> {
+        self.text
+    }
+
+    fn measure(&mut self) {
+        self.bounds = self.text.bounding_box();
+    }
+}
+
+impl<S, SV> Restyle<S> for MeasuredText<'_, SV>
+where
+    SV: TextRenderer + ReplaceStyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        if let Some(style) = SV::replacement(style) {
+            self.set_character_style(style);
+        }
+    }
+}
+
+impl<S> Dimensions for MeasuredText<'_, S> {
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<S> Transform for MeasuredText<'_, S>
+where
+    S: Clone,
+{
+    #[inline]
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            text: self.text.translate(by),
+            bounds: self.bounds.translate(by),
+        }
+    }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.text.translate_mut(by);
+        self.bounds.translate_mut(by);
+        self
+    }
+}
+
+impl<C, S> Drawable for MeasuredText<'_, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.text.draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mono_font::{
+            ascii::{FONT_10X20, FONT_6X9, FONT_9X15},
+            MonoTextStyle,
+        },
+        pixelcolor::BinaryColor,
+        text::{Alignment, Baseline, TextStyleBuilder},
+    };
+
+    const STRINGS: [&str; 5] = ["", "Hello", "trailing  ", " ", "two\nlines"];
+
+    #[test]
+    fn same_bounds_as_text() {
+        for font in [&FONT_6X9, &FONT_9X15, &FONT_10X20] {
+            let character_style = MonoTextStyle::new(font, BinaryColor::On);
+            for (alignment, baseline) in [
+                (Alignment::Left, Baseline::Alphabetic),
+                (Alignment::Center, Baseline::Top),
+                (Alignment::Right, Baseline::Bottom),
+            ] {
+                let text_style = TextStyleBuilder::new()
+                    .alignment(alignment)
+                    .baseline(baseline)
+                    .build();
+
+                for string in STRINGS {
+                    let text = Text::with_text_style(
+                        string,
+                        Point::new(7, 11),
+                        character_style,
+                        text_style,
+                    );
+                    let measured = MeasuredText::new(text);
+
+                    assert_eq!(text.bounding_box(), measured.bounding_box());
+                    assert_eq!(
+                        text.translate(Point::new(-3, 5)).bounding_box(),
+                        measured.translate(Point::new(-3, 5)).bounding_box()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn setters_measure_again() {
+        let small = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let large = MonoTextStyle::new(&FONT_9X15, BinaryColor::On);
+
+        let mut measured = MeasuredText::new(Text::new("", Point::zero(), small));
+        Transform::translate_mut(&mut measured, Point::new(4, 4));
+
+        for string in STRINGS {
+            measured.set_text(string);
+            assert_eq!(
+                Text::new(string, Point::new(4, 4), small).bounding_box(),
+                measured.bounds()
+            );
+        }
+
+        measured.set_character_style(large);
+        assert_eq!(
+            Text::new("two\nlines", Point::new(4, 4), large).bounding_box(),
+            measured.bounds()
+        );
+        assert_eq!(Point::new(4, 4), measured.inner().position);
+    }
+
+    #[test]
+    fn arranged_like_text() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let texts = LinearLayout::vertical(
+            Chain::new(Text::new("Volume", Point::zero(), style)).append(Text::new(
+                "11",
+                Point::zero(),
+                style,
+            )),
+        )
+        .arrange();
+        let measured = LinearLayout::vertical(
+            Chain::new(MeasuredText::new(Text::new("Volume", Point::zero(), style)))
+                .append(MeasuredText::new(Text::new("11", Point::zero(), style))),
+        )
+        .arrange();
+
+        assert_eq!(texts.bounds(), measured.bounds());
+        assert_eq!(
+            texts.inner().object.position,
+            measured.inner().object.inner().position
+        );
+    }
+}
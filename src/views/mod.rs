@@ -0,0 +1,271 @@
+//! Decorator and utility views
+//!
+//! A decorator wraps a single [`View`] and changes how it is measured, hit-tested or drawn,
+//! without changing the wrapped object itself. Decorators are views themselves, so they can be
+//! aligned and placed in layouts just like the objects they wrap.
+//!
+//! This module also contains views that compose other views, like [`Layers`] and [`Decorated`].
+//!
+//! [`View`]: crate::View
+
+/// Implements the [`View`] hooks of a wrapper by forwarding them to the view it wraps.
+///
+/// Wrappers implement `translate_impl`, `bounds` and `measure` themselves, and invoke this macro
+/// inside of their `impl View` block for every other hook, so that no wrapper misses a hook:
+///
+/// - `forward_view_hooks!(|this| this.view)` forwards the hooks to `this.view`.
+/// - `forward_view_hooks!(maybe |this| expression)` forwards the hooks to the view in an
+///   `Option`, and returns the default of each hook for `None`.
+///
+/// Hooks that the wrapper implements itself are listed at the end, e.g.
+/// `forward_view_hooks!(|this| this.view; except z_index)`.
+macro_rules! forward_view_hooks {
+    (|$this:ident| $view:expr $(; except $($except:ident),+)?) => {
+        forward_view_hooks!(maybe |$this| Some(&$view) $(; except $($except),+)?);
+    };
+    (maybe |$this:ident| $view:expr $(; except $($except:ident),+)?) => {
+        forward_view_hooks!(@hook hit_bounds |$this| $view; [$($($except)+)?]);
+        forward_view_hooks!(@hook z_index |$this| $view; [$($($except)+)?]);
+        forward_view_hooks!(@hook id |$this| $view; [$($($except)+)?]);
+        forward_view_hooks!(@hook margins |$this| $view; [$($($except)+)?]);
+        forward_view_hooks!(@hook as_has_margins |$this| $view; [$($($except)+)?]);
+        forward_view_hooks!(@hook pinned |$this| $view; [$($($except)+)?]);
+        forward_view_hooks!(@hook revision |$this| $view; [$($($except)+)?]);
+    };
+
+    // Skip the hooks that the wrapper implements itself
+    (@hook hit_bounds |$this:ident| $view:expr; [hit_bounds $($rest:ident)*]) => {};
+    (@hook z_index |$this:ident| $view:expr; [z_index $($rest:ident)*]) => {};
+    (@hook id |$this:ident| $view:expr; [id $($rest:ident)*]) => {};
+    (@hook margins |$this:ident| $view:expr; [margins $($rest:ident)*]) => {};
+    (@hook as_has_margins |$this:ident| $view:expr; [as_has_margins $($rest:ident)*]) => {};
+    (@hook pinned |$this:ident| $view:expr; [pinned $($rest:ident)*]) => {};
+    (@hook revision |$this:ident| $view:expr; [revision $($rest:ident)*]) => {};
+    (@hook $hook:ident |$this:ident| $view:expr; [$other:ident $($rest:ident)*]) => {
+        forward_view_hooks!(@hook $hook |$this| $view; [$($rest)*]);
+    };
+    (@hook $hook:ident |$this:ident| $view:expr; []) => {
+        forward_view_hooks!(@forward $hook |$this| $view);
+    };
+
+    (@forward hit_bounds |$this:ident| $view:expr) => {
+        #[inline]
+        fn hit_bounds(&self) -> embedded_graphics::primitives::Rectangle {
+            let $this = self;
+            match $view {
+                Some(view) => view.hit_bounds(),
+                None => self.bounds(),
+            }
+        }
+    };
+    (@forward z_index |$this:ident| $view:expr) => {
+        #[inline]
+        fn z_index(&self) -> i32 {
+            let $this = self;
+            match $view {
+                Some(view) => view.z_index(),
+                None => 0,
+            }
+        }
+    };
+    (@forward id |$this:ident| $view:expr) => {
+        #[inline]
+        fn id(&self) -> Option<u32> {
+            let $this = self;
+            match $view {
+                Some(view) => view.id(),
+                None => None,
+            }
+        }
+    };
+    (@forward margins |$this:ident| $view:expr) => {
+        #[inline]
+        fn margins(&self) -> (u32, u32) {
+            let $this = self;
+            match $view {
+                Some(view) => view.margins(),
+                None => (0, 0),
+            }
+        }
+    };
+    (@forward as_has_margins |$this:ident| $view:expr) => {
+        #[inline]
+        fn as_has_margins(&self) -> Option<&dyn $crate::layout::linear::spacing::HasMargins> {
+            let $this = self;
+            match $view {
+                Some(view) => view.as_has_margins(),
+                None => None,
+            }
+        }
+    };
+    (@forward pinned |$this:ident| $view:expr) => {
+        #[inline]
+        fn pinned(&self) -> Option<$crate::align::AlignmentPosition> {
+            let $this = self;
+            match $view {
+                Some(view) => view.pinned(),
+                None => None,
+            }
+        }
+    };
+    (@forward revision |$this:ident| $view:expr) => {
+        #[inline]
+        fn revision(&self) -> $crate::views::Revision {
+            let $this = self;
+            match $view {
+                Some(view) => view.revision(),
+                None => $crate::views::Revision::ZERO,
+            }
+        }
+    };
+}
+
+mod blink;
+mod decorated;
+mod empty;
+mod fallible;
+mod hit_area;
+mod identified;
+mod inverted;
+mod layers;
+mod lazy;
+mod margin;
+mod marquee;
+mod measured_text;
+mod padding;
+mod pinned;
+mod rotated;
+mod tight_arc;
+mod tiled;
+mod tracked;
+mod transition;
+mod translated;
+mod z_ordered;
+
+pub use blink::Blink;
+pub use decorated::{AttachmentHandle, Decorated};
+pub use empty::Empty;
+pub use fallible::Fallible;
+pub use hit_area::{assert_min_hit_size, min_hit_size_violations, ExpandHitArea};
+pub use identified::Identified;
+pub use inverted::{Invert, Inverted};
+pub use layers::Layers;
+pub use lazy::Lazy;
+pub use margin::Margin;
+pub use marquee::Marquee;
+pub use measured_text::MeasuredText;
+pub use padding::{Padding, PaddingOverflow};
+pub use pinned::Pinned;
+pub use rotated::DrawRotated;
+pub use tight_arc::TightArc;
+pub use tiled::Tiled;
+pub use tracked::{Revision, Tracked};
+pub use transition::Transition;
+pub use translated::DrawTranslated;
+pub use z_ordered::ZOrdered;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{align::AlignmentPosition, prelude::*, utils::rotation::Rotation};
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    fn rect() -> Rectangle {
+        Rectangle::new(Point::new(10, 10), Size::new(2, 2))
+    }
+
+    #[test]
+    fn hit_bounds_through_padding() {
+        let view = Padding::new(ExpandHitArea::new(rect(), Size::new(10, 10)), 1);
+
+        assert_eq!(
+            Rectangle::new(Point::new(9, 9), Size::new(4, 4)),
+            view.bounds()
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(6, 6), Size::new(10, 10)),
+            view.hit_bounds()
+        );
+    }
+
+    #[test]
+    fn hit_bounds_through_marquee() {
+        let view = Marquee::new(ExpandHitArea::new(rect(), Size::new(6, 6)), 1);
+
+        assert_eq!(
+            Rectangle::new(Point::new(8, 8), Size::new(5, 6)),
+            view.hit_bounds()
+        );
+    }
+
+    #[test]
+    fn hit_bounds_through_rotated() {
+        let view = DrawRotated::new(
+            ExpandHitArea::new(
+                Rectangle::new(Point::new(10, 10), Size::new(4, 2)),
+                Size::new(4, 6),
+            ),
+            Rotation::Quarter,
+        );
+
+        assert_eq!(
+            Rectangle::new(Point::new(8, 10), Size::new(6, 4)),
+            view.hit_bounds()
+        );
+    }
+
+    #[test]
+    fn z_index_through_lazy() {
+        let mut view = Lazy::new(Size::new(2, 2), || ZOrdered::new(rect(), 3));
+        assert_eq!(0, view.z_index());
+
+        view.force();
+        assert_eq!(3, view.z_index());
+    }
+
+    #[test]
+    fn id_through_draw_translated() {
+        let view = Identified::new(rect(), 7);
+
+        assert_eq!(Some(7), view.draw_translated(Point::new(1, 1)).id());
+    }
+
+    #[test]
+    fn margins_through_transition() {
+        let old = Margin::new(rect(), 1, 2);
+        let new = Margin::new(rect(), 3, 4);
+        let transition = Transition::new(&old, &new, rect(), ScreenEdge::Left, 500);
+
+        assert_eq!((3, 4), transition.margins());
+    }
+
+    #[test]
+    fn pinned_through_decorated() {
+        let view = Decorated::<_, Rectangle, 1>::new(Pinned::new(rect(), AlignmentPosition::End));
+
+        assert_eq!(Some(AlignmentPosition::End), view.pinned());
+    }
+
+    #[test]
+    fn revision_through_tiled() {
+        let mut view = Tiled::new(
+            Tracked::new(rect()),
+            Size::zero(),
+            Rectangle::new(Point::zero(), Size::new(8, 8)),
+        );
+        let drawn = view.revision();
+
+        view.inner_mut().touch();
+        assert_ne!(drawn, view.revision());
+    }
+
+    #[test]
+    fn has_margins_through_blink() {
+        let view = Blink::new(Padding::new(rect(), 2), 10);
+
+        assert!(view.as_has_margins().is_some());
+    }
+}
@@ -0,0 +1,610 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{AnchorPoint, AnchorX, AnchorY},
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::{aligned_within, AlignmentPosition},
+    layout::{linear::spacing::HasMargins, Axis, Constraints},
+    theme::Restyle,
+    utils::{rect_helper::union_non_empty, rounding::half},
+    View,
+};
+
+/// Error returned by [`Padding::try_bounding_box`] if the padded box doesn't fit into the
+/// coordinate space.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PaddingOverflow;
+
+/// Add space around a view, as part of its bounds.
+///
+/// Unlike [`Margin`], padding is included in the bounds of the view, so it is taken into account
+/// by alignment and by every layout. The padded view is placed after the `left` and `top` insets,
+/// unless it is created with [`Padding::centered_each`], which keeps the view at the center of
+/// the padded box regardless of the insets, or aligned with [`Padding::with_child_alignment`].
+///
+/// The insets are the [margins](HasMargins) of the padded view, so a [`LinearLayout`] using
+/// [`CollapseWithPadding`] collapses them with the gaps between views instead of adding them.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, views::Padding};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let icon = Rectangle::new(Point::zero(), Size::new(10, 10));
+///
+/// let padded = Padding::each(1, 2, 3, 4, icon);
+/// assert_eq!(
+///     Rectangle::new(Point::new(-4, -1), Size::new(16, 14)),
+///     padded.bounds()
+/// );
+///
+/// // The icon stays where it is, the padding grows around it
+/// assert_eq!(Point::zero(), padded.inner().top_left);
+/// ```
+///
+/// [`Margin`]: crate::views::Margin
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`CollapseWithPadding`]: crate::layout::linear::spacing::CollapseWithPadding
+pub struct Padding<V> {
+    view: V,
+    top: u32,
+    right: u32,
+    bottom: u32,
+    left: u32,
+    centered: bool,
+    alignment: Option<AnchorPoint>,
+    min_size: Size,
+    measured_size: Size,
+}
+
+impl<V> Padding<V>
+where
+    V: View,
+{
+    /// Wrap `view` and add `padding` pixels of space on every side.
+    #[inline]
+    pub fn new(view: V, padding: u32) -> Self {
+        Self::each(padding, padding, padding, padding, view)
+    }
+
+    /// Wrap `view` and add the given space on each side, in CSS order.
+    #[inline]
+    pub fn each(top: u32, right: u32, bottom: u32, left: u32, view: V) -> Self {
+        Self {
+            view,
+            top,
+            right,
+            bottom,
+            left,
+            centered: false,
+            alignment: None,
+            min_size: Size::zero(),
+            measured_size: Size::zero(),
+        }
+    }
+
+    /// Wrap `view` and add the given space on each side, keeping the view at the center of the
+    /// padded box.
+    ///
+    /// The padded box has the same size as with [`Padding::each`], but the view is not shifted by
+    /// asymmetric insets: the center of the view is always the center of the padded box.
+    #[inline]
+    pub fn centered_each(top: u32, right: u32, bottom: u32, left: u32, view: V) -> Self {
+        Self {
+            centered: true,
+            ..Self::each(top, right, bottom, left, view)
+        }
+    }
+
+    /// Wrap `view` and add the space needed to move it clear of `obstacle` on one side.
+    ///
+    /// If the view overlaps `obstacle`, the inset is the smallest distance that the view has to be
+    /// moved by to clear `obstacle`, on the side that moves the view away from it, e.g. a top
+    /// inset if the view is closest to clearing `obstacle` below it. If multiple sides need the
+    /// same inset, the first one in CSS order is used. A view that doesn't overlap `obstacle` is
+    /// not padded.
+    ///
+    /// The view itself is not moved. Placing the padded box where the view was, e.g. with
+    /// [`Align::align_to`], moves the view out of `obstacle`.
+    ///
+    /// [`Align::align_to`]: crate::align::Align::align_to
+    #[inline]
+    pub fn avoiding(obstacle: Rectangle, view: V) -> Self {
+        let bounds = view.bounds();
+        let overlap = bounds.intersection(&obstacle);
+        if overlap.size.width == 0 || overlap.size.height == 0 {
+            return Self::new(view, 0);
+        }
+
+        let end = |start: i32, extent: u32| start + extent as i32;
+        let insets = [
+            end(obstacle.top_left.y, obstacle.size.height) - bounds.top_left.y,
+            end(bounds.top_left.x, bounds.size.width) - obstacle.top_left.x,
+            end(bounds.top_left.y, bounds.size.height) - obstacle.top_left.y,
+            end(obstacle.top_left.x, obstacle.size.width) - bounds.top_left.x,
+        ];
+        let side = (0..insets.len())
+            .min_by_key(|&side| insets[side])
+            .unwrap_or_default();
+
+        let inset = |n| if n == side { insets[n] as u32 } else { 0 };
+        Self::each(inset(0), inset(1), inset(2), inset(3), view)
+    }
+
+    /// Align the view within the inset box.
+    ///
+    /// The view is placed at `alignment` within the padded box minus the insets, instead of after
+    /// the `left` and `top` insets. This only makes a difference if the padded box is larger than
+    /// the view and its insets, i.e. if it has a [minimum size], or if it was [measured] with a
+    /// minimum size. The view is moved along with the padded box, so its bounds are always where
+    /// it is drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::{prelude::*, views::Padding};
+    /// # use embedded_graphics::{geometry::AnchorPoint, prelude::*, primitives::Rectangle};
+    /// let icon = Rectangle::new(Point::zero(), Size::new(4, 2));
+    ///
+    /// let padded = Padding::new(icon, 1)
+    ///     .with_min_size(Size::new(10, 10))
+    ///     .with_child_alignment(AnchorPoint::Center)
+    ///     .align_to(&Rectangle::new(Point::zero(), Size::new(1, 1)), horizontal::Left, vertical::Top);
+    ///
+    /// assert_eq!(Rectangle::new(Point::zero(), Size::new(10, 10)), padded.bounds());
+    /// assert_eq!(Point::new(3, 4), padded.child_bounds().top_left);
+    /// ```
+    ///
+    /// [minimum size]: Padding::with_min_size
+    /// [measured]: View::measure
+    #[inline]
+    #[must_use]
+    pub fn with_child_alignment(self, alignment: AnchorPoint) -> Self {
+        Self {
+            alignment: Some(alignment),
+            ..self
+        }
+    }
+
+    /// Make the padded box at least `size` large.
+    ///
+    /// Without a [child alignment], the extra space is added after the view, to the right and
+    /// at the bottom, or evenly around it if the padding is [centered].
+    ///
+    /// [child alignment]: Padding::with_child_alignment
+    /// [centered]: Padding::centered_each
+    #[inline]
+    #[must_use]
+    pub fn with_min_size(self, size: Size) -> Self {
+        Self {
+            min_size: size,
+            ..self
+        }
+    }
+
+    /// Returns the insets in CSS order: top, right, bottom and left.
+    #[inline]
+    pub fn insets(&self) -> (u32, u32, u32, u32) {
+        (self.top, self.right, self.bottom, self.left)
+    }
+
+    /// Returns the bounds of the padded view, or an error if they don't fit into the coordinate
+    /// space.
+    ///
+    /// [`bounds`] computes the same rectangle with saturating arithmetic, which silently clamps
+    /// huge insets. This method returns [`PaddingOverflow`] instead if the size of the padded box
+    /// exceeds `i32::MAX`, or if one of its edges is outside of the `i32` coordinate range.
+    ///
+    /// [`bounds`]: View::bounds
+    #[inline]
+    pub fn try_bounding_box(&self) -> Result<Rectangle, PaddingOverflow> {
+        let bounds = self.view.bounds();
+        let extent = |extent: u32, before: u32, after: u32| {
+            extent
+                .checked_add(before)?
+                .checked_add(after)
+                .filter(|&extent| i32::try_from(extent).is_ok())
+        };
+
+        let min_size = self.min_size.component_max(self.measured_size);
+        let width = extent(bounds.size.width, self.left, self.right)
+            .ok_or(PaddingOverflow)?
+            .max(min_size.width);
+        let height = extent(bounds.size.height, self.top, self.bottom)
+            .ok_or(PaddingOverflow)?
+            .max(min_size.height);
+
+        // The offset is within the padded size, so it doesn't overflow either
+        let offset = self.offset(bounds.size, Size::new(width, height));
+        let start = |position: i32, offset: i32, extent: u32| {
+            let start = position.checked_sub(offset)?;
+            start.checked_add(extent as i32).map(|_| start)
+        };
+
+        let x = start(bounds.top_left.x, offset.x, width).ok_or(PaddingOverflow)?;
+        let y = start(bounds.top_left.y, offset.y, height).ok_or(PaddingOverflow)?;
+
+        Ok(Rectangle::new(Point::new(x, y), Size::new(width, height)))
+    }
+
+    /// Returns the bounds of the wrapped view, without the padding.
+    ///
+    /// This is the content box of the padded view, e.g. to align other views to the content
+    /// instead of the padded box.
+    #[inline]
+    pub fn child_bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+
+    fn extra_size(&self) -> Size {
+        Size::new(
+            self.left.saturating_add(self.right),
+            self.top.saturating_add(self.bottom),
+        )
+    }
+
+    /// Returns the size of the padded box around a view of `size`.
+    fn padded_size(&self, size: Size) -> Size {
+        size.saturating_add(self.extra_size())
+            .component_max(self.min_size)
+            .component_max(self.measured_size)
+    }
+
+    /// Returns the position of the view of `size` within the padded box of size `padded`.
+    fn offset(&self, size: Size, padded: Size) -> Point {
+        if let Some(alignment) = self.alignment {
+            let inset = Rectangle::new(
+                Point::new(self.left as i32, self.top as i32),
+                padded.saturating_sub(self.extra_size()),
+            );
+            let (horizontal, vertical) = anchor_positions(alignment);
+
+            return aligned_within(
+                Rectangle::new(Point::zero(), size),
+                inset,
+                horizontal,
+                vertical,
+            )
+            .top_left;
+        }
+
+        if !self.centered {
+            return Point::new(self.left as i32, self.top as i32);
+        }
+
+        // Centers are rounded towards the top left, like `Rectangle::center`
+        let center = |size: u32| half(size.max(1) as i32 - 1);
+
+        Point::new(
+            center(padded.width) - center(size.width),
+            center(padded.height) - center(size.height),
+        )
+    }
+}
+
+/// Returns the positions along the horizontal and vertical axes that `anchor` stands for.
+fn anchor_positions(anchor: AnchorPoint) -> (AlignmentPosition, AlignmentPosition) {
+    let horizontal = match anchor.x() {
+        AnchorX::Left => AlignmentPosition::Start,
+        AnchorX::Center => AlignmentPosition::Center,
+        AnchorX::Right => AlignmentPosition::End,
+    };
+    let vertical = match anchor.y() {
+        AnchorY::Top => AlignmentPosition::Start,
+        AnchorY::Center => AlignmentPosition::Center,
+        AnchorY::Bottom => AlignmentPosition::End,
+    };
+
+    (horizontal, vertical)
+}
+
+impl<V> View for Padding<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.view.bounds();
+
+        let padded = self.padded_size(bounds.size);
+        let offset = self.offset(bounds.size, padded);
+
+        Rectangle::new(
+            Point::new(
+                bounds.top_left.x.saturating_sub(offset.x),
+                bounds.top_left.y.saturating_sub(offset.y),
+            ),
+            padded,
+        )
+    }
+
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        union_non_empty(self.bounds(), self.view.hit_bounds())
+    }
+
+    #[inline]
+    fn as_has_margins(&self) -> Option<&dyn HasMargins> {
+        Some(self)
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        let extra = self.extra_size();
+        let inner = Constraints::new(
+            constraints.min.saturating_sub(extra),
+            constraints.max.saturating_sub(extra),
+        );
+
+        if self.alignment.is_some() {
+            self.measured_size = constraints.min;
+        }
+
+        constraints.constrain(self.view.measure(inner).saturating_add(extra))
+    }
+
+    forward_view_hooks!(|this| this.view; except hit_bounds, as_has_margins);
+}
+
+impl<S, V> Restyle<S> for Padding<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+/// The margins are the space between the padded box and the view, so they include the extra
+/// space of a [minimum size](Padding::with_min_size).
+impl<V> HasMargins for Padding<V>
+where
+    V: View,
+{
+    #[inline]
+    fn leading_margin(&self, axis: Axis) -> i32 {
+        let (padded, child) = (self.bounds(), self.view.bounds());
+        match axis {
+            Axis::Horizontal => child.top_left.x - padded.top_left.x,
+            Axis::Vertical => child.top_left.y - padded.top_left.y,
+        }
+    }
+
+    #[inline]
+    fn trailing_margin(&self, axis: Axis) -> i32 {
+        let (padded, child) = (self.bounds(), self.view.bounds());
+        let end = |bounds: Rectangle| bounds.top_left + bounds.size;
+        match axis {
+            Axis::Horizontal => end(padded).x - end(child).x,
+            Axis::Vertical => end(padded).y - end(child).y,
+        }
+    }
+}
+
+impl<C, V> Drawable for Padding<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        geometry::AnchorPoint, mock_display::MockDisplay, pixelcolor::BinaryColor,
+        prelude::Primitive, primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn asymmetric_insets_shift_the_view() {
+        let view = Rectangle::new(Point::zero(), Size::new(5, 3));
+
+        let padded = Padding::each(1, 7, 4, 2, view).align_to(
+            &Rectangle::new(Point::new(10, 10), Size::new(1, 1)),
+            horizontal::Left,
+            vertical::Top,
+        );
+
+        assert_eq!(
+            Rectangle::new(Point::new(10, 10), Size::new(14, 8)),
+            padded.bounds()
+        );
+        assert_eq!(Point::new(12, 11), padded.inner().top_left);
+    }
+
+    #[test]
+    fn centered_insets_keep_the_center() {
+        for size in [Size::new(5, 3), Size::new(6, 4), Size::new(1, 1)] {
+            for (top, right, bottom, left) in [(1, 7, 4, 2), (0, 3, 0, 0), (5, 0, 2, 9)] {
+                let view = Rectangle::new(Point::new(-3, 8), size);
+                let padded = Padding::centered_each(top, right, bottom, left, view);
+
+                assert_eq!(padded.bounds().center(), padded.inner().center());
+                assert_eq!(
+                    Size::new(size.width + left + right, size.height + top + bottom),
+                    padded.size()
+                );
+                // The view itself is not moved
+                assert_eq!(view, *padded.inner());
+            }
+        }
+    }
+
+    #[test]
+    fn avoiding_pads_the_nearest_side() {
+        let notch = Rectangle::new(Point::new(20, 0), Size::new(24, 6));
+
+        // Overlapping the notch by 4 pixels from below
+        let label = Rectangle::new(Point::new(16, 2), Size::new(30, 8));
+        let padded = Padding::avoiding(notch, label);
+        assert_eq!((4, 0, 0, 0), padded.insets());
+
+        let padded = padded.align_to(&label, horizontal::NoAlignment, vertical::Top);
+        assert_eq!(Point::new(16, 6), padded.inner().top_left);
+        assert!(!padded.inner().intersection(&notch).bottom_right().is_some());
+
+        // Clearing the notch to the left is shorter than moving down
+        let icon = Rectangle::new(Point::new(40, 0), Size::new(8, 8));
+        assert_eq!((0, 0, 0, 4), Padding::avoiding(notch, icon).insets());
+
+        // Not overlapping, touching edges
+        let icon = Rectangle::new(Point::new(44, 0), Size::new(8, 8));
+        assert_eq!((0, 0, 0, 0), Padding::avoiding(notch, icon).insets());
+    }
+
+    #[test]
+    fn child_bounds_ignore_padding() {
+        let view = Rectangle::new(Point::new(3, 4), Size::new(5, 6));
+
+        for padded in [
+            Padding::each(1, 7, 4, 2, view),
+            Padding::centered_each(1, 7, 4, 2, view),
+        ] {
+            assert_eq!(view, padded.child_bounds());
+            assert_eq!(Size::new(14, 11), padded.size());
+            assert!(padded.bounds().contains(padded.child_bounds().top_left));
+        }
+    }
+
+    #[test]
+    fn try_bounds_reports_overflow() {
+        let view = Rectangle::new(Point::new(10, 0), Size::new(10, 10));
+
+        let padded = Padding::each(1, 2, 3, 4, view);
+        assert_eq!(Ok(padded.bounds()), padded.try_bounding_box());
+
+        // The padded box is wider than the coordinate space
+        let padded = Padding::each(0, i32::MAX as u32 - 5, 0, 0, view);
+        assert_eq!(Err(PaddingOverflow), padded.try_bounding_box());
+        assert_eq!(Size::new(i32::MAX as u32 + 5, 10), padded.size());
+
+        // The left edge of the padded box is before `i32::MIN`
+        let view = Rectangle::new(Point::new(i32::MIN + 2, 0), Size::new(10, 10));
+        let padded = Padding::each(0, 0, 0, 5, view);
+        assert_eq!(Err(PaddingOverflow), padded.try_bounding_box());
+        assert_eq!(
+            Rectangle::new(Point::new(i32::MIN, 0), Size::new(15, 10)),
+            padded.bounds()
+        );
+    }
+
+    #[test]
+    fn child_alignment_within_measured_box() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let child = Rectangle::new(Point::zero(), Size::new(3, 1)).into_styled(style);
+
+        let mut padded = Padding::new(child, 1).with_child_alignment(AnchorPoint::Center);
+        assert_eq!(
+            Size::new(7, 5),
+            padded.measure(Constraints::new(Size::new(7, 5), Size::new(20, 20)))
+        );
+        let padded = padded.align_to(
+            &Rectangle::new(Point::zero(), Size::new(1, 1)),
+            horizontal::Left,
+            vertical::Top,
+        );
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(7, 5)),
+            padded.bounds()
+        );
+        // The child is moved to where it is drawn
+        assert_eq!(
+            Rectangle::new(Point::new(2, 2), Size::new(3, 1)),
+            padded.child_bounds()
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        padded.draw(&mut display).unwrap();
+        display.assert_pattern(&["       ", "       ", "  ###  "]);
+    }
+
+    #[test]
+    fn child_alignment_with_min_size() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let child = Rectangle::new(Point::new(5, 5), Size::new(2, 2)).into_styled(style);
+
+        let padded = Padding::each(1, 0, 0, 1, child)
+            .with_min_size(Size::new(6, 4))
+            .with_child_alignment(AnchorPoint::BottomRight)
+            .align_to(
+                &Rectangle::new(Point::zero(), Size::new(1, 1)),
+                horizontal::Left,
+                vertical::Top,
+            );
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(6, 4)),
+            padded.bounds()
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(4, 2), Size::new(2, 2)),
+            padded.child_bounds()
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        padded.draw(&mut display).unwrap();
+        display.assert_pattern(&["      ", "      ", "    ##", "    ##"]);
+
+        // The child is placed after the insets without an alignment
+        let padded = Padding::each(1, 0, 0, 1, child).with_min_size(Size::new(6, 4));
+        assert_eq!(
+            Rectangle::new(Point::new(4, 4), Size::new(6, 4)),
+            padded.bounds()
+        );
+    }
+
+    #[test]
+    fn measure_includes_insets() {
+        let mut padded = Padding::new(Rectangle::new(Point::zero(), Size::new(5, 3)), 2);
+
+        assert_eq!(
+            Size::new(9, 7),
+            padded.measure(Constraints::loose(Size::new(20, 20)))
+        );
+        assert_eq!(
+            Size::new(8, 7),
+            padded.measure(Constraints::loose(Size::new(8, 20)))
+        );
+    }
+}
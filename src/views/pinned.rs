@@ -0,0 +1,141 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{align::AlignmentPosition, layout::Constraints, theme::Restyle, View};
+
+/// Pin a view to a fixed position along the primary axis of a [`LinearLayout`].
+///
+/// The pinned view is placed at `position` of the span of the layout, e.g. in its center, instead
+/// of after the previous view. The views before it flow from the start of the layout as usual, and
+/// the views after it flow on from the pinned view. The span of the layout is set by
+/// [`LinearLayout::with_span`], or by the element spacing if it defines the size of the layout.
+/// Without either, views are pinned within their combined length.
+///
+/// Use [`Chain::append_pinned`] to append a pinned view to a chain.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::prelude::*;
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let button = |width| Rectangle::new(Point::zero(), Size::new(width, 8));
+///
+/// let bar = LinearLayout::horizontal(
+///     Chain::new(button(12))
+///         .append_pinned(button(10), AlignmentPosition::Center)
+///         .append(button(8)),
+/// )
+/// .with_span(64)
+/// .arrange();
+///
+/// // The play button is centered in the bar, the next button follows it
+/// assert_eq!(Point::new(27, 0), bar.inner().parent.object.bounds().top_left);
+/// assert_eq!(Point::new(37, 0), bar.inner().object.top_left);
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`LinearLayout::with_span`]: crate::layout::linear::LinearLayout::with_span
+/// [`Chain::append_pinned`]: crate::object_chain::Chain::append_pinned
+pub struct Pinned<V> {
+    view: V,
+    position: AlignmentPosition,
+}
+
+impl<V> Pinned<V>
+where
+    V: View,
+{
+    /// Wrap `view` and pin it to `position` of the span of the layout.
+    #[inline]
+    pub fn new(view: V, position: AlignmentPosition) -> Self {
+        Self { view, position }
+    }
+
+    /// Returns where the view is pinned.
+    #[inline]
+    pub fn position(&self) -> AlignmentPosition {
+        self.position
+    }
+
+    /// Change where the view is pinned.
+    #[inline]
+    pub fn set_position(&mut self, position: AlignmentPosition) {
+        self.position = position;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for Pinned<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn pinned(&self) -> Option<AlignmentPosition> {
+        Some(self.position)
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view; except pinned);
+}
+
+impl<S, V> Restyle<S> for Pinned<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Pinned<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
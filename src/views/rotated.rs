@@ -0,0 +1,267 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{Dimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable, Pixel,
+};
+
+use crate::{theme::Restyle, utils::rotation::Rotation, View};
+
+/// Draw a view rotated by quarter turns.
+///
+/// The adapter reports the bounding box of the rotated view: for quarter turns, the width and
+/// height of the view are swapped. The rotated view keeps the top left corner of the view, and
+/// other views can be aligned to it like to any other view.
+///
+/// Only the drawing is rotated, the wrapped view keeps its own bounds. Unlike the [`Rotated`]
+/// draw target, which rotates the whole display, `DrawRotated` rotates a single view within a
+/// layout.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, utils::rotation::Rotation, views::DrawRotated};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let label = DrawRotated::new(
+///     Rectangle::new(Point::zero(), Size::new(40, 8)),
+///     Rotation::Quarter,
+/// );
+///
+/// // The rotated label is 8 pixels wide
+/// let value = Rectangle::new(Point::zero(), Size::new(10, 10))
+///     .align_to(&label, horizontal::LeftToRight, vertical::Top);
+///
+/// assert_eq!(Point::new(8, 0), value.top_left);
+/// ```
+///
+/// [`Rotated`]: crate::utils::rotation::Rotated
+pub struct DrawRotated<V> {
+    view: V,
+    rotation: Rotation,
+}
+
+impl<V> DrawRotated<V>
+where
+    V: View,
+{
+    /// Draw `view` rotated clockwise by `rotation`.
+    #[inline]
+    pub fn new(view: V, rotation: Rotation) -> Self {
+        Self { view, rotation }
+    }
+
+    /// Returns the rotation of the view.
+    #[inline]
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Change the rotation of the view.
+    #[inline]
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the adapter and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for DrawRotated<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.view.bounds();
+        Rectangle::new(bounds.top_left, self.rotation.rotate_size(bounds.size))
+    }
+
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        // Rotate the hit area around the view like its pixels.
+        let bounds = self.view.bounds();
+        let hit = self.view.hit_bounds();
+        let local = Rectangle::new(hit.top_left - bounds.top_left, hit.size);
+        self.rotation
+            .rotate_rect(local, bounds.size)
+            .translate(bounds.top_left)
+    }
+
+    forward_view_hooks!(|this| this.view; except hit_bounds, as_has_margins);
+}
+
+impl<S, V> Restyle<S> for DrawRotated<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for DrawRotated<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let bounds = self.view.bounds();
+
+        self.view.draw(&mut RotateAround {
+            display,
+            rotation: self.rotation,
+            origin: bounds.top_left,
+            size: bounds.size,
+        })
+    }
+}
+
+/// Draw target adapter that rotates the area `size` large at `origin` in place.
+struct RotateAround<'a, D> {
+    display: &'a mut D,
+    rotation: Rotation,
+    origin: Point,
+    size: Size,
+}
+
+impl<D> Dimensions for RotateAround<'_, D>
+where
+    D: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        // The area that is drawn to the bounding box of the display, rotated back.
+        let inverse = match self.rotation {
+            Rotation::Quarter => Rotation::ThreeQuarter,
+            Rotation::Half => Rotation::Half,
+            Rotation::ThreeQuarter => Rotation::Quarter,
+        };
+        let display = self.display.bounding_box();
+        let area = Rectangle::new(display.top_left - self.origin, display.size);
+
+        let logical = inverse.rotate_rect(area, self.rotation.rotate_size(self.size));
+        Rectangle::new(logical.top_left + self.origin, logical.size)
+    }
+}
+
+impl<D> DrawTarget for RotateAround<'_, D>
+where
+    D: DrawTarget,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (rotation, origin, size) = (self.rotation, self.origin, self.size);
+
+        self.display
+            .draw_iter(pixels.into_iter().map(|Pixel(point, color)| {
+                Pixel(rotation.rotate_point(point - origin, size) + origin, color)
+            }))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let local = Rectangle::new(area.top_left - self.origin, area.size);
+        let rotated = self.rotation.rotate_rect(local, self.size);
+
+        self.display.fill_solid(
+            &Rectangle::new(rotated.top_left + self.origin, rotated.size),
+            color,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{Line, PrimitiveStyle},
+    };
+
+    #[test]
+    fn align_to_right_edge_of_rotated_reference() {
+        let reference = DrawRotated::new(
+            Rectangle::new(Point::new(5, 2), Size::new(20, 6)),
+            Rotation::Quarter,
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(5, 2), Size::new(6, 20)),
+            reference.bounds()
+        );
+
+        let view = Rectangle::new(Point::zero(), Size::new(3, 3)).align_to(
+            &reference,
+            horizontal::LeftToRight,
+            vertical::Bottom,
+        );
+
+        // Uses the rotated width of 6, not the original width of 20
+        assert_eq!(Point::new(11, 19), view.top_left);
+    }
+
+    #[test]
+    fn half_turn_keeps_bounds() {
+        let rect = Rectangle::new(Point::new(5, 2), Size::new(20, 6));
+
+        assert_eq!(rect, DrawRotated::new(rect, Rotation::Half).bounds());
+    }
+
+    #[test]
+    fn draw_rotated() {
+        // An L shape in the top left corner of a 4x3 area
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let shape = Chain::new(Line::new(Point::new(1, 1), Point::new(4, 1)).into_styled(style))
+            .append(Line::new(Point::new(1, 1), Point::new(1, 3)).into_styled(style));
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        DrawRotated::new(shape, Rotation::Quarter)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "    ", //
+                " ###", //
+                "   #", //
+                "   #", //
+                "   #", //
+            ])
+        );
+    }
+}
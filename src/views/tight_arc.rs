@@ -0,0 +1,273 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Angle, Dimensions},
+    prelude::{PixelColor, Point},
+    primitives::{Arc, PrimitiveStyle, Rectangle, Styled},
+    transform::Transform,
+    Drawable,
+};
+
+use crate::{
+    theme::{ReplaceStyle, Restyle},
+    utils::trig::point_on_circle,
+};
+
+/// An arc that is measured by the part of the circle it covers.
+///
+/// The bounding box of an [`Arc`] is the bounding box of the whole circle, even if the arc only
+/// covers a small part of it. `TightArc` wraps a styled arc and reports a bounding box that only
+/// contains the end points of the arc and the extreme points of the circle that the arc sweeps
+/// over, extended by the stroke width. This lets layouts place a gauge or a spinner segment
+/// without reserving space for the full circle.
+///
+/// Angles are rounded outwards to whole degrees, so the bounding box may be slightly larger than
+/// the drawn pixels, but never smaller.
+pub struct TightArc<C>
+where
+    C: PixelColor,
+{
+    arc: Styled<Arc, PrimitiveStyle<C>>,
+}
+
+impl<C> TightArc<C>
+where
+    C: PixelColor,
+{
+    /// Wrap a styled arc.
+    #[inline]
+    pub fn new(arc: Styled<Arc, PrimitiveStyle<C>>) -> Self {
+        Self { arc }
+    }
+
+    /// Returns a reference to the wrapped arc.
+    #[inline]
+    pub fn inner(&self) -> &Styled<Arc, PrimitiveStyle<C>> {
+        &self.arc
+    }
+
+    /// Returns a mutable reference to the wrapped arc.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut Styled<Arc, PrimitiveStyle<C>> {
+        &mut self.arc
+    }
+
+    /// Consume the wrapper and return the wrapped arc.
+    #[inline]
+    pub fn into_inner(self) -> Styled<Arc, PrimitiveStyle<C>> {
+        self.arc
+    }
+}
+
+impl<S, C> Restyle<S> for TightArc<C>
+where
+    C: PixelColor,
+    PrimitiveStyle<C>: ReplaceStyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.arc.restyle(style);
+    }
+}
+
+impl<C> Dimensions for TightArc<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        let circle_bounds = self.arc.bounding_box();
+
+        let arc = &self.arc.primitive;
+        let (start, end) = sweep_degrees(arc.angle_start, arc.angle_sweep);
+        if end - start >= 360 {
+            return circle_bounds;
+        }
+
+        let center = arc.center();
+        let radius = arc.diameter / 2;
+
+        // Angles of `Arc` start at 3 o'clock, while `point_on_circle` starts at 12 o'clock.
+        let point_at = |degrees: i32| point_on_circle(center, radius, degrees + 90);
+
+        let start_point = point_at(start);
+        let (mut min, mut max) = (start_point, start_point);
+        let mut include = |point: Point| {
+            min = min.component_min(point);
+            max = max.component_max(point);
+        };
+
+        include(point_at(end));
+
+        // The extreme points of the circle that are covered by the arc.
+        let mut extreme = start.div_euclid(90) * 90;
+        while extreme <= end {
+            if extreme >= start {
+                include(point_at(extreme));
+            }
+            extreme += 90;
+        }
+
+        // Points on the circle are rounded to whole pixels, so add a pixel on top of the stroke.
+        let stroke = self.arc.style.stroke_width as i32 + 1;
+
+        Rectangle::with_corners(min, max)
+            .offset(stroke)
+            .intersection(&circle_bounds)
+    }
+}
+
+/// Returns the start and end of the sweep in whole degrees, rounded outwards, with the end
+/// not before the start.
+fn sweep_degrees(angle_start: Angle, angle_sweep: Angle) -> (i32, i32) {
+    let start = angle_start.to_degrees();
+    let end = start + angle_sweep.to_degrees();
+
+    if start <= end {
+        (floor(start), ceil(end))
+    } else {
+        (floor(end), ceil(start))
+    }
+}
+
+fn floor(value: f32) -> i32 {
+    let truncated = value as i32;
+    if (truncated as f32) > value {
+        truncated - 1
+    } else {
+        truncated
+    }
+}
+
+fn ceil(value: f32) -> i32 {
+    -floor(-value)
+}
+
+impl<C> Transform for TightArc<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            arc: self.arc.translate(by),
+        }
+    }
+
+    #[inline]
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.arc.translate_mut(by);
+        self
+    }
+}
+
+impl<C> Drawable for TightArc<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.arc.draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        geometry::AngleUnit,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::{PrimitiveStyleBuilder, StrokeAlignment},
+    };
+
+    fn arc(start: f32, sweep: f32, stroke_width: u32) -> TightArc<BinaryColor> {
+        TightArc::new(
+            Arc::new(Point::new(10, 10), 41, start.deg(), sweep.deg())
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)),
+        )
+    }
+
+    #[test]
+    fn quarter_arc_covers_a_quadrant() {
+        let arc = arc(0.0, 90.0, 1);
+        let bounds = arc.bounding_box();
+
+        // Bottom right quadrant of the circle, which is centered at (30, 30)
+        assert!(
+            bounds.size.width <= 24 && bounds.size.height <= 24,
+            "{:?}",
+            bounds
+        );
+        assert!(bounds.contains(Point::new(30, 50)));
+        assert!(bounds.contains(Point::new(50, 30)));
+        assert!(!bounds.contains(Point::new(20, 20)));
+        assert_eq!(
+            Rectangle::new(Point::new(10, 10), Size::new(41, 41)),
+            arc.inner().bounding_box()
+        );
+    }
+
+    #[test]
+    fn full_circle_is_not_shrunk() {
+        let arc = arc(45.0, -360.0, 3);
+
+        assert_eq!(arc.inner().bounding_box(), arc.bounding_box());
+    }
+
+    #[test]
+    fn drawn_pixels_are_inside_bounds() {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for (start, sweep) in [
+                (0.0, 90.0),
+                (-30.5, 45.0),
+                (100.0, -120.0),
+                (170.0, 20.0),
+                (260.0, 200.0),
+                (12.3, 4.5),
+            ] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(4)
+                    .stroke_alignment(stroke_alignment)
+                    .build();
+                let arc = TightArc::new(
+                    Arc::new(Point::new(10, 10), 40, start.deg(), sweep.deg()).into_styled(style),
+                );
+
+                let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+                arc.draw(&mut display).unwrap();
+
+                let drawn = display.affected_area();
+                assert_eq!(
+                    drawn,
+                    drawn.intersection(&arc.bounding_box()),
+                    "{}° + {}°",
+                    start,
+                    sweep
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn translate_moves_bounds() {
+        let arc = arc(180.0, 90.0, 1);
+        let moved = arc.translate(Point::new(5, -3));
+
+        assert_eq!(
+            arc.bounding_box().translate(Point::new(5, -3)),
+            moved.bounding_box()
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{theme::Restyle, View};
+
+/// Repeat a view to fill a rectangle, e.g. to draw a patterned background.
+///
+/// The first copy of the tile is drawn at the top left corner of the target rectangle, the rest
+/// are repeated to the right and downwards, `spacing` pixels apart. Copies that extend past the
+/// target rectangle are clipped. The position of the wrapped tile itself is irrelevant.
+pub struct Tiled<V> {
+    tile: V,
+    spacing: Size,
+    target: Rectangle,
+}
+
+impl<V> Tiled<V>
+where
+    V: View,
+{
+    /// Repeat `tile` to fill `target`, leaving `spacing` pixels between the copies.
+    #[inline]
+    pub fn new(tile: V, spacing: Size, target: Rectangle) -> Self {
+        Self {
+            tile,
+            spacing,
+            target,
+        }
+    }
+
+    /// Change the rectangle filled by the tiles.
+    #[inline]
+    pub fn set_target(&mut self, target: Rectangle) {
+        self.target = target;
+    }
+
+    /// Returns a reference to the wrapped tile.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.tile
+    }
+
+    /// Returns a mutable reference to the wrapped tile.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.tile
+    }
+
+    /// Consume the wrapper and return the wrapped tile.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.tile
+    }
+}
+
+impl<V> View for Tiled<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.target.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.target
+    }
+
+    forward_view_hooks!(|this| this.tile; except hit_bounds, as_has_margins);
+}
+
+impl<S, V> Restyle<S> for Tiled<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.tile.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Tiled<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let tile = self.tile.bounds();
+        if tile.is_zero_sized() || self.target.is_zero_sized() {
+            return Ok(());
+        }
+
+        let step_x = (tile.size.width + self.spacing.width) as i32;
+        let step_y = (tile.size.height + self.spacing.height) as i32;
+        let width = self.target.size.width as i32;
+        let height = self.target.size.height as i32;
+
+        let mut clipped = display.clipped(&self.target);
+        for y in (0..height).step_by(step_y as usize) {
+            for x in (0..width).step_by(step_x as usize) {
+                let offset = self.target.top_left + Point::new(x, y) - tile.top_left;
+                self.tile.draw(&mut clipped.translated(offset))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{Line, PrimitiveStyle},
+    };
+
+    #[test]
+    fn tiles_cover_target() {
+        // A 2x2 checkerboard pattern
+        let pattern = Line::new(Point::new(5, 5), Point::new(6, 6))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+        let target = Rectangle::new(Point::new(1, 1), Size::new(6, 6));
+
+        let tiled = Tiled::new(pattern, Size::zero(), target);
+
+        let mut display = MockDisplay::new();
+        tiled.draw(&mut display).unwrap();
+
+        assert_eq!(target, tiled.bounds());
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "       ", " # # # ", "  # # #", " # # # ", "  # # #", " # # # ", "  # # #",
+            ])
+        );
+    }
+
+    #[test]
+    fn partial_tiles_are_clipped() {
+        let tile = Rectangle::new(Point::zero(), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+        let target = Rectangle::new(Point::zero(), Size::new(5, 4));
+
+        let tiled = Tiled::new(tile, Size::new(1, 0), target);
+
+        let mut display = MockDisplay::new();
+        tiled.draw(&mut display).unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ##", //
+                "## ##", //
+                "## ##", //
+                "## ##", //
+            ])
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// A counter that grows whenever a view changes.
+///
+/// If the [`revision`] of a view is the same as the last time it was drawn, nothing inside it has
+/// changed. Revisions of views inside a container are aggregated with [`combine`], which adds them
+/// up. Every counter only grows, so the sum grows with a change anywhere inside the container, and
+/// comparing the revision of the root of a view tree is enough to detect a change anywhere inside
+/// it.
+///
+/// Containers add the revision of a view they remove to their own counter, so removing a view
+/// doesn't shrink the sum either. Swapping two views directly, without going through a container
+/// that bumps its revision, is not detected: the sum doesn't depend on the order of the views.
+///
+/// Views don't track their changes by default, wrap them into [`Tracked`] to opt in.
+///
+/// [`revision`]: View::revision
+/// [`combine`]: Revision::combine
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Revision(u64);
+
+impl Revision {
+    /// The revision of a view that was never changed.
+    pub const ZERO: Self = Self(0);
+
+    /// Returns the raw value of the counter.
+    #[inline]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the next revision.
+    ///
+    /// The counter saturates at `u64::MAX` instead of wrapping around, which a view doesn't reach
+    /// even if it changes a million times per second for half a million years.
+    #[inline]
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+
+    /// Aggregate the revisions of two views.
+    ///
+    /// The result is the sum of the revisions, saturating at `u64::MAX`, so it grows whenever
+    /// either revision grows.
+    #[inline]
+    #[must_use]
+    pub const fn combine(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+}
+
+/// Track the changes of a view in its [`Revision`].
+///
+/// The revision is bumped whenever the view is moved, whenever measuring it changes its bounds,
+/// and whenever it is accessed by [`inner_mut`], e.g. to change its value. Drawing the view
+/// doesn't change its revision.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, views::Tracked};
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// let item = || Tracked::new(Rectangle::new(Point::zero(), Size::new(10, 4)));
+///
+/// let mut menu = LinearLayout::vertical(Chain::new(item()).append(item())).arrange();
+/// let drawn = menu.revision();
+///
+/// menu.inner_mut().object.inner_mut().size.width = 12;
+/// assert_ne!(drawn, menu.revision());
+/// ```
+///
+/// [`inner_mut`]: Tracked::inner_mut
+pub struct Tracked<V> {
+    view: V,
+    revision: Revision,
+}
+
+impl<V> Tracked<V>
+where
+    V: View,
+{
+    /// Wrap `view` and start tracking its changes.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self {
+            view,
+            revision: Revision::ZERO,
+        }
+    }
+
+    /// Mark the view as changed.
+    #[inline]
+    pub fn touch(&mut self) {
+        self.revision = self.revision.next();
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view, and marks it as changed.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        self.touch();
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for Tracked<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        if by != Point::zero() {
+            self.touch();
+        }
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.revision.combine(self.view.revision())
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        let bounds = self.view.bounds();
+        let size = self.view.measure(constraints);
+        if self.view.bounds() != bounds {
+            self.touch();
+        }
+
+        size
+    }
+
+    forward_view_hooks!(|this| this.view; except revision);
+}
+
+impl<S, V> Restyle<S> for Tracked<V>
+where
+    V: Restyle<S> + View,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.inner_mut().restyle(style);
+    }
+}
+
+impl<C, V> Drawable for Tracked<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        prelude::*,
+        view_group::HeaplessViews,
+        views::{Margin, Padding},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Styled},
+    };
+
+    type Item = Tracked<Styled<Rectangle, PrimitiveStyle<BinaryColor>>>;
+
+    fn item(width: u32) -> Item {
+        Tracked::new(
+            Rectangle::new(Point::zero(), Size::new(width, 2))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On)),
+        )
+    }
+
+    #[test]
+    fn nested_changes_reach_the_root() {
+        let mut rows = [item(3), item(4)];
+        let mut screen = LinearLayout::horizontal(
+            Chain::new(Padding::new(item(2), 1)).append(
+                LinearLayout::vertical(Views::new(&mut rows))
+                    .with_spacing(FixedMargin(1))
+                    .arrange(),
+            ),
+        )
+        .arrange();
+
+        let drawn = screen.revision();
+        let mut display = MockDisplay::new();
+        screen.draw(&mut display).unwrap();
+        assert_eq!(drawn, screen.revision());
+
+        // Change the style of the second row of the nested layout
+        screen.inner_mut().object.inner_mut()[1]
+            .inner_mut()
+            .style
+            .fill_color = Some(BinaryColor::Off);
+        let changed = screen.revision();
+        assert_ne!(drawn, changed);
+
+        // Moving the whole screen moves every tracked view
+        screen.translate_mut(Point::new(1, 0));
+        assert_ne!(changed, screen.revision());
+    }
+
+    #[test]
+    fn untracked_views_have_no_revision() {
+        let mut layout = LinearLayout::vertical(
+            Chain::new(Rectangle::new(Point::zero(), Size::new(3, 3))).append(Margin::new(
+                Rectangle::zero(),
+                1,
+                1,
+            )),
+        )
+        .arrange();
+        let arranged = layout.revision();
+
+        layout.translate_mut(Point::new(4, 4));
+        assert_eq!(arranged, layout.revision());
+    }
+
+    #[test]
+    fn translating_by_zero_is_not_a_change() {
+        let mut tracked = item(3);
+        tracked.translate_mut(Point::zero());
+        assert_eq!(Revision::ZERO, tracked.revision());
+
+        tracked.translate_mut(Point::new(0, 1));
+        assert_ne!(Revision::ZERO, tracked.revision());
+    }
+
+    #[test]
+    fn next_saturates() {
+        let max = Revision(u64::MAX);
+
+        assert_eq!(max, max.next());
+        assert_eq!(max, max.combine(Revision::ZERO.next()));
+    }
+
+    #[test]
+    fn removing_a_view_keeps_counting_up() {
+        let mut views: HeaplessViews<Item, 2> = HeaplessViews::new();
+        let mut changed = item(3);
+        changed.touch();
+        changed.touch();
+        assert!(views.push(changed).is_ok());
+        assert!(views.push(item(4)).is_ok());
+        let drawn = views.revision();
+
+        assert!(views.remove(0).is_some());
+        assert!(views.revision() > drawn);
+    }
+}
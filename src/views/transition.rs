@@ -0,0 +1,247 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{align::ScreenEdge, math::Fraction, theme::Restyle, View};
+
+/// Slide transition between two screens.
+///
+/// The old screen slides out towards `edge` while the new screen slides in from the opposite
+/// edge, following it. Both screens are clipped to `bounds`, and the distance they travel is the
+/// size of `bounds` along the direction of the movement.
+///
+/// `progress` is a [`Fraction`], plain integers are per-mille values: at 0 only the old screen is
+/// visible, at 1000 only the new one. The screens always meet without a gap or an overlap.
+///
+/// A transition between two views is a view with the bounds of the clipping area, and it has the
+/// z-index, id, margins, pinning and revision of the new screen. Moving the transition moves both
+/// screens when they are drawn.
+///
+/// # Example
+///
+/// ```
+/// use embedded_layout::{align::ScreenEdge, views::Transition};
+/// use embedded_graphics::{
+///     mock_display::MockDisplay,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, Rectangle},
+/// };
+///
+/// let screen = Rectangle::new(Point::zero(), Size::new(4, 1));
+/// let old = screen.into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+/// let new = screen.into_styled(PrimitiveStyle::with_fill(BinaryColor::Off));
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// Transition::new(&old, &new, screen, ScreenEdge::Left, 250)
+///     .draw(&mut display)
+///     .unwrap();
+///
+/// display.assert_pattern(&["###."]);
+/// ```
+pub struct Transition<'a, O, N> {
+    old: &'a O,
+    new: &'a N,
+    bounds: Rectangle,
+    edge: ScreenEdge,
+    progress: Fraction,
+    offset: Point,
+}
+
+impl<'a, O, N> Transition<'a, O, N> {
+    /// Create a transition from `old` to `new` within `bounds`, moving the screens towards `edge`.
+    ///
+    /// `progress` is clamped to [`Fraction::ONE`].
+    #[inline]
+    pub fn new(
+        old: &'a O,
+        new: &'a N,
+        bounds: Rectangle,
+        edge: ScreenEdge,
+        progress: impl Into<Fraction>,
+    ) -> Self {
+        Self {
+            old,
+            new,
+            bounds,
+            edge,
+            progress: progress.into().min(Fraction::ONE),
+            offset: Point::zero(),
+        }
+    }
+
+    /// Change the progress of the transition.
+    ///
+    /// `progress` is clamped to [`Fraction::ONE`].
+    #[inline]
+    pub fn set_progress(&mut self, progress: impl Into<Fraction>) {
+        self.progress = progress.into().min(Fraction::ONE);
+    }
+
+    /// Returns the progress of the transition.
+    #[inline]
+    pub fn progress(&self) -> Fraction {
+        self.progress
+    }
+
+    /// Returns the offsets the old and the new screens are drawn at.
+    fn offsets(&self) -> (Point, Point) {
+        let direction = self.edge.direction();
+        let extent = if direction.x != 0 {
+            self.bounds.size.width
+        } else {
+            self.bounds.size.height
+        };
+
+        let moved = self.progress.scale(extent) as i32;
+        let remaining = extent as i32 - moved;
+
+        (
+            self.offset + direction * moved,
+            self.offset + direction * -remaining,
+        )
+    }
+}
+
+impl<O, N> View for Transition<'_, O, N>
+where
+    O: View,
+    N: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        // The screens are borrowed, so they are moved when drawn
+        self.bounds.top_left += by;
+        self.offset += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    forward_view_hooks!(|this| this.new; except hit_bounds, as_has_margins);
+}
+
+/// The screens are borrowed, so they are restyled before the transition is created.
+impl<S, O, N> Restyle<S> for Transition<'_, O, N> {}
+
+impl<C, O, N> Drawable for Transition<'_, O, N>
+where
+    C: PixelColor,
+    O: Drawable<Color = C>,
+    N: Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let (old_offset, new_offset) = self.offsets();
+        let mut clipped = display.clipped(&self.bounds);
+
+        if self.progress < Fraction::ONE {
+            self.old.draw(&mut clipped.translated(old_offset))?;
+        }
+        if self.progress > Fraction::ZERO {
+            self.new.draw(&mut clipped.translated(new_offset))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::{PrimitiveStyle, Styled},
+    };
+
+    type Screen = Styled<Rectangle, PrimitiveStyle<BinaryColor>>;
+
+    fn screens(bounds: Rectangle) -> (Screen, Screen) {
+        (
+            bounds.into_styled(PrimitiveStyle::with_fill(BinaryColor::On)),
+            bounds.into_styled(PrimitiveStyle::with_fill(BinaryColor::Off)),
+        )
+    }
+
+    fn draw(edge: ScreenEdge, bounds: Rectangle, progress: u32) -> MockDisplay<BinaryColor> {
+        let (old, new) = screens(bounds);
+
+        let mut display = MockDisplay::new();
+        Transition::new(&old, &new, bounds, edge, progress)
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn slide_left() {
+        let bounds = Rectangle::new(Point::new(1, 1), Size::new(6, 2));
+
+        let expected: [&[&str]; 3] = [
+            &["       ", " ######", " ######"],
+            &["       ", " ###...", " ###..."],
+            &["       ", " ......", " ......"],
+        ];
+        for (progress, pattern) in [0, 500, 1000].iter().zip(expected.iter()) {
+            draw(ScreenEdge::Left, bounds, *progress).assert_pattern(pattern);
+        }
+    }
+
+    #[test]
+    fn slide_down() {
+        let bounds = Rectangle::new(Point::new(1, 1), Size::new(2, 4));
+
+        let expected: [&[&str]; 3] = [
+            &["   ", " ##", " ##", " ##", " ##"],
+            &["   ", " ..", " ..", " ##", " ##"],
+            &["   ", " ..", " ..", " ..", " .."],
+        ];
+        for (progress, pattern) in [0, 500, 1000].iter().zip(expected.iter()) {
+            draw(ScreenEdge::Bottom, bounds, *progress).assert_pattern(pattern);
+        }
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(3, 1));
+        let (old, new) = screens(bounds);
+
+        let mut transition = Transition::new(&old, &new, bounds, ScreenEdge::Left, 1500);
+        assert_eq!(Fraction::ONE, transition.progress());
+
+        transition.set_progress(Fraction::new(1, 3));
+        assert_eq!(1, transition.offsets().0.x.abs());
+    }
+
+    #[test]
+    fn progress_is_monotonic() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(7, 1));
+        let (old, new) = screens(bounds);
+
+        let mut previous = 0;
+        for progress in (0..=1000).step_by(50) {
+            let (old_offset, new_offset) =
+                Transition::new(&old, &new, bounds, ScreenEdge::Right, progress).offsets();
+
+            // The screens always meet
+            assert_eq!(7, old_offset.x - new_offset.x);
+            assert!(old_offset.x >= previous);
+            previous = old_offset.x;
+        }
+        assert_eq!(7, previous);
+    }
+}
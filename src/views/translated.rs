@@ -0,0 +1,143 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{theme::Restyle, View};
+
+/// Draw a borrowed view at an offset, without moving the view itself.
+///
+/// `DrawTranslated` is useful for views that are shared between screens and can't be translated
+/// in place. The adapter reports the bounding box of the view moved by the offset, and can be
+/// translated, aligned and placed in layouts like any other view. Translating the adapter only
+/// changes its offset.
+///
+/// Create a `DrawTranslated` using [`ViewExt::draw_translated`].
+///
+/// [`ViewExt::draw_translated`]: crate::utils::view_ext::ViewExt::draw_translated
+pub struct DrawTranslated<'a, V> {
+    view: &'a V,
+    offset: Point,
+}
+
+impl<'a, V> DrawTranslated<'a, V>
+where
+    V: View,
+{
+    /// Draw `view` moved by `offset`.
+    #[inline]
+    pub fn new(view: &'a V, offset: Point) -> Self {
+        Self { view, offset }
+    }
+
+    /// Returns the offset the view is drawn at.
+    #[inline]
+    pub fn offset(&self) -> Point {
+        self.offset
+    }
+
+    /// Returns the borrowed view.
+    #[inline]
+    pub fn inner(&self) -> &'a V {
+        self.view
+    }
+}
+
+impl<V> Clone for DrawTranslated<'_, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for DrawTranslated<'_, V> {}
+
+impl<V> View for DrawTranslated<'_, V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.offset += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds().translate(self.offset)
+    }
+
+    #[inline]
+    fn hit_bounds(&self) -> Rectangle {
+        self.view.hit_bounds().translate(self.offset)
+    }
+
+    forward_view_hooks!(|this| this.view; except hit_bounds);
+}
+
+/// The view is borrowed, so it is restyled before it is drawn translated.
+impl<S, V> Restyle<S> for DrawTranslated<'_, V> {}
+
+impl<C, V> Drawable for DrawTranslated<'_, V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(&mut display.translated(self.offset))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Point, Primitive, Size},
+        primitives::{Circle, PrimitiveStyle, Rectangle},
+        Drawable,
+    };
+
+    #[test]
+    fn draws_like_translated_clone() {
+        let circle = Circle::new(Point::new(1, 2), 5)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+        let offset = Point::new(4, -1);
+
+        let mut expected = MockDisplay::new();
+        circle.translate(offset).draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        circle.draw_translated(offset).draw(&mut display).unwrap();
+
+        assert_eq!(expected, display);
+        assert_eq!(Point::new(1, 2), circle.bounds().top_left);
+    }
+
+    #[test]
+    fn adapter_can_be_aligned() {
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 4))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+        let area = Rectangle::new(Point::zero(), Size::new(8, 8));
+
+        let centered = rect.draw_translated(Point::zero()).align_to(
+            &area,
+            horizontal::Center,
+            vertical::Center,
+        );
+
+        assert_eq!(Point::new(2, 2), centered.offset());
+        assert_eq!(Point::new(2, 2), centered.bounds().top_left);
+        assert_eq!(Point::zero(), rect.bounds().top_left);
+    }
+}
@@ -0,0 +1,172 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::Constraints, theme::Restyle, View};
+
+/// Assign a z-index to a view.
+///
+/// Overlapping views of a view group are drawn in ascending order of their z-index by
+/// [`DrawableViewGroup::draw_z_ordered`], so views with a higher z-index end up on top. Views that
+/// are not wrapped in `ZOrdered` have a z-index of 0.
+///
+/// [`DrawableViewGroup::draw_z_ordered`]: crate::view_group::DrawableViewGroup::draw_z_ordered
+pub struct ZOrdered<V> {
+    view: V,
+    z_index: i32,
+}
+
+impl<V> ZOrdered<V>
+where
+    V: View,
+{
+    /// Wrap `view` and assign `z_index` to it.
+    #[inline]
+    pub fn new(view: V, z_index: i32) -> Self {
+        Self { view, z_index }
+    }
+
+    /// Change the z-index of the view.
+    #[inline]
+    pub fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consume the wrapper and return the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> View for ZOrdered<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        self.view.measure(constraints)
+    }
+
+    forward_view_hooks!(|this| this.view; except z_index);
+}
+
+impl<S, V> Restyle<S> for ZOrdered<V>
+where
+    V: Restyle<S>,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        self.view.restyle(style);
+    }
+}
+
+impl<C, V> Drawable for ZOrdered<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = V::Output;
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{prelude::*, view_group::DrawableViewGroup};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn lower_z_index_is_drawn_below() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let group = Chain::new(ZOrdered::new(
+            rect.into_styled(PrimitiveStyle::with_fill(BinaryColor::On)),
+            1,
+        ))
+        .append(ZOrdered::new(
+            rect.translate(Point::new(1, 1))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off)),
+            0,
+        ));
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        group.draw_z_ordered(&mut display).unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ", //
+                "##.", //
+                " ..", //
+            ])
+        );
+    }
+
+    #[test]
+    fn equal_z_index_keeps_index_order() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let mut views = [
+            rect.into_styled(PrimitiveStyle::with_fill(BinaryColor::On)),
+            rect.translate(Point::new(1, 1))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off)),
+        ];
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Views::new(&mut views).draw_z_ordered(&mut display).unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ", //
+                "#..", //
+                " ..", //
+            ])
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::{horizontal, vertical, Alignment},
+    theme::Restyle,
+    utils::rect_helper::union_non_empty,
+    views::Revision,
+    View,
+};
+
+/// The position of the label relative to the icon of a [`Labeled`] widget.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LabelPosition {
+    /// The label is centered below the icon.
+    Below,
+
+    /// The label is centered above the icon.
+    Above,
+
+    /// The label is vertically centered to the right of the icon.
+    Right,
+
+    /// The label is vertically centered to the left of the icon.
+    Left,
+}
+
+/// An icon with a caption, centered to each other as a unit.
+///
+/// The label is placed next to the icon, `gap` pixels away, and centered to it along the other
+/// axis. The widget keeps the top left corner of the bounding box of the two views, so it can be
+/// aligned and placed in layouts like any other view.
+///
+/// The views are laid out when the widget is created, and again after they are changed through
+/// [`icon_mut`] or [`label_mut`]. If the views change size in some other way, call [`relayout`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::{prelude::*, widgets::{LabelPosition, Labeled}};
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Circle, PrimitiveStyle},
+/// #     text::Text,
+/// # };
+/// let icon = Circle::new(Point::zero(), 15).into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+/// let label = Text::new("Wi-Fi", Point::zero(), MonoTextStyle::new(&FONT_6X9, BinaryColor::On));
+///
+/// let mut wifi = Labeled::new(icon, label, LabelPosition::Below, 2);
+/// assert_eq!(Size::new(30, 26), wifi.size());
+///
+/// // The label is centered again after it changes.
+/// wifi.label_mut(|label| label.text = "Wi-Fi: off");
+/// assert_eq!(Size::new(60, 26), wifi.size());
+/// ```
+///
+/// [`icon_mut`]: Labeled::icon_mut
+/// [`label_mut`]: Labeled::label_mut
+/// [`relayout`]: Labeled::relayout
+pub struct Labeled<I, T> {
+    icon: I,
+    label: T,
+    position: LabelPosition,
+    gap: u32,
+}
+
+impl<I, T> Labeled<I, T>
+where
+    I: View,
+    T: View,
+{
+    /// Create a new widget and lay out the icon and the label.
+    ///
+    /// The widget is placed at the top left corner of the bounding box of `icon` and `label`.
+    #[inline]
+    pub fn new(icon: I, label: T, position: LabelPosition, gap: u32) -> Self {
+        let mut widget = Self {
+            icon,
+            label,
+            position,
+            gap,
+        };
+        widget.relayout();
+
+        widget
+    }
+
+    /// Returns the position of the label relative to the icon.
+    #[inline]
+    pub fn label_position(&self) -> LabelPosition {
+        self.position
+    }
+
+    /// Change the position of the label relative to the icon and lay out the views again.
+    #[inline]
+    pub fn set_label_position(&mut self, position: LabelPosition) {
+        self.position = position;
+        self.relayout();
+    }
+
+    /// Returns the distance between the icon and the label, in pixels.
+    #[inline]
+    pub fn gap(&self) -> u32 {
+        self.gap
+    }
+
+    /// Change the distance between the icon and the label and lay out the views again.
+    #[inline]
+    pub fn set_gap(&mut self, gap: u32) {
+        self.gap = gap;
+        self.relayout();
+    }
+
+    /// Returns a reference to the icon.
+    #[inline]
+    pub fn icon(&self) -> &I {
+        &self.icon
+    }
+
+    /// Returns a reference to the label.
+    #[inline]
+    pub fn label(&self) -> &T {
+        &self.label
+    }
+
+    /// Change the icon and lay out the views again.
+    #[inline]
+    pub fn icon_mut<R>(&mut self, f: impl FnOnce(&mut I) -> R) -> R {
+        let result = f(&mut self.icon);
+        self.relayout();
+
+        result
+    }
+
+    /// Change the label and lay out the views again.
+    #[inline]
+    pub fn label_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = f(&mut self.label);
+        self.relayout();
+
+        result
+    }
+
+    /// Consume the widget and return the icon and the label.
+    #[inline]
+    pub fn into_inner(self) -> (I, T) {
+        (self.icon, self.label)
+    }
+
+    /// Lay out the icon and the label, keeping the top left corner of the widget.
+    #[inline]
+    pub fn relayout(&mut self) {
+        let origin = self.bounds().top_left;
+
+        let icon = self.icon.bounds();
+        let label = self.label.bounds();
+        let gap = self.gap as i32;
+
+        let by = match self.position {
+            LabelPosition::Below => Point::new(
+                horizontal::Center.align(label, icon),
+                vertical::TopToBottom.align_with_offset(label, icon, gap),
+            ),
+            LabelPosition::Above => Point::new(
+                horizontal::Center.align(label, icon),
+                vertical::BottomToTop.align_with_offset(label, icon, -gap),
+            ),
+            LabelPosition::Right => Point::new(
+                horizontal::LeftToRight.align_with_offset(label, icon, gap),
+                vertical::Center.align(label, icon),
+            ),
+            LabelPosition::Left => Point::new(
+                horizontal::RightToLeft.align_with_offset(label, icon, -gap),
+                vertical::Center.align(label, icon),
+            ),
+        };
+        self.label.translate_mut(by);
+
+        let by = origin - self.bounds().top_left;
+        self.translate_impl(by);
+    }
+}
+
+impl<I, T> View for Labeled<I, T>
+where
+    I: View,
+    T: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.icon.translate_impl(by);
+        self.label.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        union_non_empty(self.icon.bounds(), self.label.bounds())
+    }
+
+    #[inline]
+    fn revision(&self) -> Revision {
+        self.icon.revision().combine(self.label.revision())
+    }
+}
+
+impl<S, I, T> Restyle<S> for Labeled<I, T>
+where
+    I: Restyle<S> + View,
+    T: Restyle<S> + View,
+{
+    #[inline]
+    fn restyle(&mut self, style: &S) {
+        // The label may change its size with its style
+        self.icon_mut(|icon| icon.restyle(style));
+        self.label_mut(|label| label.restyle(style));
+    }
+}
+
+impl<C, I, T> Drawable for Labeled<I, T>
+where
+    C: PixelColor,
+    I: View + Drawable<Color = C>,
+    T: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.icon.draw(display)?;
+        self.label.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    fn labeled(position: LabelPosition) -> Labeled<Rectangle, Rectangle> {
+        Labeled::new(
+            Rectangle::new(Point::new(10, 10), Size::new(4, 4)),
+            Rectangle::new(Point::new(-20, 3), Size::new(8, 2)),
+            position,
+            1,
+        )
+    }
+
+    #[test]
+    fn label_below() {
+        let widget = labeled(LabelPosition::Below);
+
+        assert_eq!(Point::new(-18, 3), widget.icon().top_left);
+        assert_eq!(Point::new(-20, 8), widget.label().top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(-20, 3), Size::new(8, 7)),
+            widget.bounds()
+        );
+    }
+
+    #[test]
+    fn label_above() {
+        let widget = labeled(LabelPosition::Above);
+
+        assert_eq!(Point::new(-20, 3), widget.label().top_left);
+        assert_eq!(Point::new(-18, 6), widget.icon().top_left);
+    }
+
+    #[test]
+    fn label_right() {
+        let widget = labeled(LabelPosition::Right);
+
+        assert_eq!(Point::new(-20, 3), widget.icon().top_left);
+        assert_eq!(Point::new(-15, 4), widget.label().top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(-20, 3), Size::new(13, 4)),
+            widget.bounds()
+        );
+    }
+
+    #[test]
+    fn label_left() {
+        let widget = labeled(LabelPosition::Left);
+
+        assert_eq!(Point::new(-20, 4), widget.label().top_left);
+        assert_eq!(Point::new(-11, 3), widget.icon().top_left);
+    }
+
+    #[test]
+    fn relayout_after_label_widens() {
+        let mut widget = labeled(LabelPosition::Below);
+
+        widget.label_mut(|label| label.size.width = 12);
+
+        assert_eq!(Point::new(-16, 3), widget.icon().top_left);
+        assert_eq!(Point::new(-20, 8), widget.label().top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(-20, 3), Size::new(12, 7)),
+            widget.bounds()
+        );
+
+        widget.set_label_position(LabelPosition::Right);
+        assert_eq!(
+            Rectangle::new(Point::new(-20, 3), Size::new(17, 4)),
+            widget.bounds()
+        );
+    }
+
+    #[test]
+    fn draw_icon_and_label() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let widget = Labeled::new(
+            Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style),
+            Rectangle::new(Point::zero(), Size::new(3, 1)).into_styled(style),
+            LabelPosition::Below,
+            1,
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        widget.draw(&mut display).unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " # ", //
+                "   ", //
+                "###", //
+            ])
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Ready-made widgets
+//!
+//! Widgets are small, commonly needed compositions of views, built only from the public
+//! [alignment] and [`View`] APIs of this crate. They are views themselves, so they can be aligned
+//! and placed in layouts, and they double as examples of how to compose your own widgets.
+//!
+//! [alignment]: crate::align
+//! [`View`]: crate::View
+
+mod labeled;
+
+pub use labeled::{LabelPosition, Labeled};
@@ -0,0 +1,29 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+use embedded_layout::prelude::*;
+use embedded_layout_macros::ViewGroup;
+
+#[derive(ViewGroup)]
+struct Card {
+    title: Rectangle,
+    #[view_group(reference)]
+    panel: Rectangle,
+    icon: Rectangle,
+}
+
+#[test]
+fn align_all_to_reference_centers_content_on_panel() {
+    let mut card = Card {
+        title: Rectangle::new(Point::new(-10, 3), Size::new(10, 4)),
+        panel: Rectangle::new(Point::new(20, 30), Size::new(30, 20)),
+        icon: Rectangle::new(Point::new(100, 100), Size::new(6, 6)),
+    };
+
+    card.align_all_to_reference(horizontal::Center, vertical::Center);
+
+    // The reference does not move
+    assert_eq!(Point::new(20, 30), card.panel.top_left);
+
+    assert_eq!(card.panel.center(), card.title.center());
+    assert_eq!(card.panel.center(), card.icon.center());
+    assert_eq!(card.panel, card.bounds());
+}
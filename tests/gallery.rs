@@ -0,0 +1,236 @@
+//! A gallery of typical screens, built only from the public API and rendered to a `MockDisplay`.
+//!
+//! Each screen checks the bounds of its key elements and a few pixels, so changes to the API or
+//! to the layout results show up here, not only in the examples.
+
+use embedded_graphics::{
+    mock_display::MockDisplay,
+    mono_font::{ascii::FONT_6X9, MonoTextStyle},
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Circle, PrimitiveStyle, Rectangle},
+    text::Text,
+};
+use embedded_layout::{
+    layout::tiles::tiles_with_gutter,
+    prelude::*,
+    widgets::{LabelPosition, Labeled},
+};
+
+const ON: PrimitiveStyle<BinaryColor> = PrimitiveStyle::with_fill(BinaryColor::On);
+
+fn text_style(color: BinaryColor) -> MonoTextStyle<'static, BinaryColor> {
+    MonoTextStyle::new(&FONT_6X9, color)
+}
+
+fn screen(width: u32, height: u32) -> Rectangle {
+    Rectangle::new(Point::zero(), Size::new(width, height))
+}
+
+/// Asserts that every pixel drawn to `display` is inside one of `areas`.
+fn assert_drawn_within(display: &MockDisplay<BinaryColor>, areas: &[Rectangle]) {
+    let area = display.bounding_box();
+    for point in area.points() {
+        if display.get_pixel(point).is_some() {
+            assert!(
+                areas.iter().any(|area| area.contains(point)),
+                "{:?} is outside of the expected areas",
+                point
+            );
+        }
+    }
+}
+
+#[test]
+fn centered_splash() {
+    let display_area = screen(64, 32);
+
+    let logo = Circle::new(Point::zero(), 15).into_styled(ON);
+    let caption = Text::new("Boot", Point::zero(), text_style(BinaryColor::On));
+    let splash = Labeled::new(logo, caption, LabelPosition::Below, 2).align_to(
+        &display_area,
+        horizontal::Center,
+        vertical::Center,
+    );
+
+    // 24x26, centered in 64x32
+    assert_eq!(
+        Rectangle::new(Point::new(20, 3), Size::new(24, 26)),
+        splash.bounds()
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(24, 3), Size::new(15, 15)),
+        splash.icon().bounds()
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(20, 20), Size::new(24, 9)),
+        splash.label().bounds()
+    );
+    assert_eq!(display_area.center(), splash.bounds().center());
+
+    let mut display = MockDisplay::new();
+    splash.draw(&mut display).unwrap();
+
+    assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(31, 10)));
+    assert_drawn_within(&display, &[splash.bounds()]);
+}
+
+#[test]
+fn menu_with_selection() {
+    let display_area = screen(64, 32);
+    let selected = 1;
+
+    let mut items = ["Open", "Save", "Quit"]
+        .map(|label| Text::new(label, Point::zero(), text_style(BinaryColor::On)));
+    items[selected].character_style = text_style(BinaryColor::Off);
+
+    let menu = LinearLayout::vertical(Views::new(&mut items))
+        .with_spacing(FixedMargin(1))
+        .arrange()
+        .align_to(&display_area, horizontal::Left, vertical::Top)
+        .translate(Point::new(2, 1));
+
+    assert_eq!(
+        Rectangle::new(Point::new(2, 1), Size::new(24, 29)),
+        menu.bounds()
+    );
+    let item = menu.bounds_of(selected);
+    assert_eq!(Rectangle::new(Point::new(2, 11), Size::new(24, 9)), item);
+
+    // A full width bar, one pixel taller than the item on both sides
+    let highlight = Rectangle::new(Point::zero(), Size::new(64, item.size.height + 2))
+        .align_to(&display_area, horizontal::Left, vertical::NoAlignment)
+        .align_to(&item, horizontal::NoAlignment, vertical::Center);
+    assert_eq!(
+        Rectangle::new(Point::new(0, 10), Size::new(64, 11)),
+        highlight
+    );
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    highlight.into_styled(ON).draw(&mut display).unwrap();
+    menu.draw(&mut display).unwrap();
+
+    assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(0, 10)));
+    assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(63, 20)));
+    assert_eq!(None, display.get_pixel(Point::new(0, 9)));
+    assert_eq!(None, display.get_pixel(Point::new(0, 21)));
+    assert_drawn_within(&display, &[menu.bounds(), highlight]);
+}
+
+#[test]
+fn status_bar_clusters() {
+    let bar = screen(64, 9);
+    let icon = |width| Rectangle::new(Point::zero(), Size::new(width, 5)).into_styled(ON);
+    let signal = |height| Rectangle::new(Point::zero(), Size::new(3, height)).into_styled(ON);
+
+    let left = LinearLayout::horizontal(Chain::new(icon(4)).append(icon(6)))
+        .with_spacing(FixedMargin(1))
+        .arrange()
+        .align_to(&bar, horizontal::Left, vertical::Center);
+
+    let clock = Text::new("12:00", Point::zero(), text_style(BinaryColor::On)).align_to(
+        &bar,
+        horizontal::Center,
+        vertical::Center,
+    );
+
+    let right = LinearLayout::horizontal(Chain::new(signal(1)).append(signal(2)).append(signal(3)))
+        .with_spacing(FixedMargin(1))
+        .arrange()
+        .align_to(&bar, horizontal::Right, vertical::Center);
+
+    assert_eq!(
+        Rectangle::new(Point::new(0, 2), Size::new(11, 5)),
+        left.bounds()
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(5, 2), Size::new(6, 5)),
+        left.bounds_of(1)
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(17, 0), Size::new(30, 9)),
+        clock.bounds()
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(53, 3), Size::new(11, 3)),
+        right.bounds()
+    );
+    // Signal bars are bottom aligned, like in the default horizontal layout
+    assert_eq!(
+        Rectangle::new(Point::new(53, 5), Size::new(3, 1)),
+        right.bounds_of(0)
+    );
+
+    for (a, b) in [
+        (left.bounds(), clock.bounds()),
+        (clock.bounds(), right.bounds()),
+    ] {
+        assert!(a.intersection(&b).is_zero_sized());
+    }
+
+    let mut display = MockDisplay::new();
+    left.draw(&mut display).unwrap();
+    clock.draw(&mut display).unwrap();
+    right.draw(&mut display).unwrap();
+
+    assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(0, 2)));
+    assert_eq!(None, display.get_pixel(Point::new(4, 2)));
+    assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(63, 5)));
+    assert_drawn_within(&display, &[left.bounds(), clock.bounds(), right.bounds()]);
+}
+
+#[test]
+fn two_pane_split() {
+    let display_area = screen(64, 32);
+
+    let mut panes = tiles_with_gutter(display_area, 1, 2, 2);
+    let (list_pane, detail_pane) = (panes.next().unwrap(), panes.next().unwrap());
+    assert_eq!(
+        Rectangle::new(Point::new(0, 0), Size::new(31, 32)),
+        list_pane
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(33, 0), Size::new(31, 32)),
+        detail_pane
+    );
+
+    let mut rows = [Rectangle::new(Point::zero(), Size::new(20, 6)).into_styled(ON); 3];
+    let result = LinearLayout::vertical(Views::new(&mut rows))
+        .with_spacing(FixedMargin(2))
+        .arrange_into(list_pane);
+    assert!(result.fits);
+
+    let list = result
+        .layout
+        .align_to(&list_pane, horizontal::Center, vertical::Top);
+    assert_eq!(
+        Rectangle::new(Point::new(6, 0), Size::new(20, 22)),
+        list.bounds()
+    );
+    assert_eq!(
+        Rectangle::new(Point::new(6, 16), Size::new(20, 6)),
+        list.bounds_of(2)
+    );
+
+    let detail = Rectangle::new(Point::zero(), Size::new(16, 16))
+        .into_styled(ON)
+        .align_to(&detail_pane, horizontal::Center, vertical::Center);
+    assert_eq!(
+        Rectangle::new(Point::new(41, 8), Size::new(16, 16)),
+        detail.bounds()
+    );
+
+    let mut display = MockDisplay::new();
+    list.draw(&mut display).unwrap();
+    detail.draw(&mut display).unwrap();
+
+    // Nothing is drawn into the gutter
+    for y in 0..32 {
+        assert_eq!(None, display.get_pixel(Point::new(31, y)));
+        assert_eq!(None, display.get_pixel(Point::new(32, y)));
+    }
+    assert_eq!(Some(BinaryColor::On), display.get_pixel(Point::new(6, 16)));
+    assert_eq!(None, display.get_pixel(Point::new(6, 6)));
+    assert_drawn_within(&display, &[list_pane, detail_pane]);
+}
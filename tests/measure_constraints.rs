@@ -0,0 +1,80 @@
+//! Views never report a size larger than the maximum of the constraints they are measured with.
+
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X9, MonoTextStyle},
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Circle, PrimitiveStyle, Rectangle},
+    text::Text,
+};
+use embedded_layout::{
+    layout::Constraints,
+    prelude::*,
+    views::{ExpandHitArea, Identified, ZOrdered},
+};
+
+fn constraints() -> [Constraints; 6] {
+    [
+        Constraints::unbounded(),
+        Constraints::loose(Size::new(3, 3)),
+        Constraints::loose(Size::new(100, 2)),
+        Constraints::tight(Size::new(4, 4)),
+        Constraints::new(Size::new(50, 50), Size::new(60, 60)),
+        Constraints::tight(Size::zero()),
+    ]
+}
+
+fn assert_conforms(mut view: impl View) {
+    for constraints in constraints() {
+        let size = view.measure(constraints);
+
+        assert!(
+            constraints.contains(size),
+            "{:?} does not satisfy {:?}",
+            size,
+            constraints
+        );
+    }
+}
+
+#[test]
+fn primitives_conform() {
+    let style = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+
+    assert_conforms(Rectangle::new(Point::new(-3, 4), Size::new(20, 5)));
+    assert_conforms(Circle::new(Point::zero(), 11).into_styled(style));
+    assert_conforms(Text::new(
+        "Hello",
+        Point::zero(),
+        MonoTextStyle::new(&FONT_6X9, BinaryColor::On),
+    ));
+}
+
+#[test]
+fn decorators_conform() {
+    let rect = Rectangle::new(Point::new(1, 1), Size::new(20, 5));
+
+    assert_conforms(ZOrdered::new(rect, 2));
+    assert_conforms(Identified::new(rect, 2));
+    assert_conforms(ExpandHitArea::new(rect, Size::new(44, 44)));
+}
+
+#[test]
+fn layouts_conform() {
+    let rect = Rectangle::new(Point::zero(), Size::new(20, 5));
+
+    assert_conforms(LinearLayout::horizontal(Chain::new(rect).append(rect)).arrange());
+    assert_conforms(
+        LinearLayout::vertical(Chain::new(rect).append(rect))
+            .with_spacing(DistributeFill(90))
+            .arrange(),
+    );
+}
+
+#[test]
+fn unconstrained_size_is_bounding_box_size() {
+    let mut rect = Rectangle::new(Point::new(-3, 4), Size::new(20, 5));
+
+    assert_eq!(Size::new(20, 5), rect.measure(Constraints::unbounded()));
+    assert_eq!(Size::new(20, 5), rect.size);
+}
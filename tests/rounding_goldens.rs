@@ -0,0 +1,198 @@
+//! Golden values for every place where the layout engine has to round.
+//!
+//! Arranging the same views must always produce the same pixels, so the results of odd sizes and
+//! negative coordinates are pinned down here. The rules are documented in the crate's
+//! `utils::rounding` module; a failing test here means the rendered output of existing UIs
+//! changes.
+
+use embedded_graphics::{
+    geometry::AnchorPoint,
+    prelude::*,
+    primitives::{Rectangle, Triangle},
+};
+use embedded_layout::{
+    align::ShapeCenter,
+    layout::{
+        absolute::{AbsoluteLayout, Position},
+        linear::{spacing::ProportionalGap, Justify},
+        tiles::tiles_with_gutter,
+    },
+    prelude::*,
+    views::ExpandHitArea,
+};
+
+fn rect(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+#[test]
+fn center_alignment() {
+    let references = [
+        rect(0, 0, 10, 10),
+        rect(0, 0, 9, 9),
+        rect(-9, -9, 9, 9),
+        rect(-10, -10, 10, 10),
+        rect(-3, -3, 4, 4),
+    ];
+
+    let centered = |size: u32| {
+        references
+            .iter()
+            .map(|reference| {
+                rect(100, -100, size, size)
+                    .align_to(reference, horizontal::Center, vertical::Center)
+                    .top_left
+                    .x
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Both axes are aligned the same way, so only `x` is checked
+    assert_eq!(vec![3, 3, -6, -7, -3], centered(4));
+    assert_eq!(vec![3, 3, -6, -7, -3], centered(3));
+    assert_eq!(vec![-1, -1, -10, -11, -7], centered(12));
+    assert_eq!(vec![-1, -1, -10, -11, -7], centered(11));
+}
+
+#[test]
+fn triangle_centroid() {
+    let reference = Triangle::new(Point::new(0, 0), Point::new(1, 0), Point::new(0, 1));
+    let negative = Triangle::new(Point::new(0, 0), Point::new(-1, 0), Point::new(0, -1));
+
+    assert_eq!(Point::new(0, 0), reference.shape_center());
+    assert_eq!(Point::new(-1, -1), negative.shape_center());
+}
+
+fn distributed(total: u32, widths: [u32; 4]) -> [i32; 4] {
+    let mut views = widths.map(|width| rect(0, 0, width, 1));
+    let _ = LinearLayout::horizontal(Views::new(&mut views))
+        .with_spacing(DistributeFill(total))
+        .arrange();
+
+    views.map(|view| view.top_left.x)
+}
+
+#[test]
+fn distribute_fill() {
+    // 30 free pixels, 3 gaps
+    assert_eq!([0, 15, 30, 45], distributed(60, [5, 5, 5, 15]));
+    // 31 free pixels: the first gap is larger
+    assert_eq!([0, 16, 31, 46], distributed(61, [5, 5, 5, 15]));
+    // 32 free pixels
+    assert_eq!([0, 16, 32, 47], distributed(62, [5, 5, 5, 15]));
+    // Overflow by 7 pixels: the views overlap, the last gap is the smallest
+    assert_eq!([0, 3, 6, 8], distributed(23, [5, 5, 5, 15]));
+}
+
+fn justified(mode: Justify, widths: [u32; 3], total: u32) -> [i32; 3] {
+    let mut views = widths.map(|width| rect(0, 0, width, 1));
+    let _ = LinearLayout::horizontal(Views::new(&mut views))
+        .arrange()
+        .justify(total, mode);
+
+    views.map(|view| view.top_left.x)
+}
+
+#[test]
+fn justify() {
+    // 15 free pixels
+    let widths = [5, 5, 5];
+
+    assert_eq!([7, 12, 17], justified(Justify::Center, widths, 30));
+    assert_eq!([0, 13, 25], justified(Justify::SpaceBetween, widths, 30));
+    assert_eq!([2, 12, 22], justified(Justify::SpaceAround, widths, 30));
+
+    // Overflow by 3 pixels
+    assert_eq!([-2, 3, 8], justified(Justify::Center, widths, 12));
+    assert_eq!([0, 4, 7], justified(Justify::SpaceBetween, widths, 12));
+    assert_eq!([-1, 3, 7], justified(Justify::SpaceAround, widths, 12));
+}
+
+#[test]
+fn proportional_gap() {
+    let mut views = [rect(0, 0, 7, 1), rect(0, 0, 9, 1), rect(0, 0, 1, 1)];
+    let _ = LinearLayout::horizontal(Views::new(&mut views))
+        .with_spacing(ProportionalGap::new(250))
+        .arrange();
+
+    // 7 * 0.25 = 1.75 and 9 * 0.25 = 2.25 are truncated
+    assert_eq!([0, 8, 19], views.map(|view| view.top_left.x));
+}
+
+#[test]
+fn tiles() {
+    let bounds = rect(-5, -5, 23, 10);
+
+    let tiles = tiles_with_gutter(bounds, 3, 4, 1).collect::<Vec<_>>();
+    let columns = tiles[..4]
+        .iter()
+        .map(|tile| (tile.top_left.x, tile.size.width))
+        .collect::<Vec<_>>();
+    let rows = tiles
+        .iter()
+        .step_by(4)
+        .map(|tile| (tile.top_left.y, tile.size.height))
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![(-5, 5), (1, 5), (7, 5), (13, 5)], columns);
+    assert_eq!(vec![(-5, 3), (-1, 3), (3, 2)], rows);
+}
+
+#[test]
+fn absolute_per_mille() {
+    let bounds = rect(-10, -10, 21, 8);
+    let view = rect(0, 0, 4, 3);
+
+    let cases = [
+        (Position::relative(0, 0), (-10, -10)),
+        (Position::relative(500, 500), (0, -6)),
+        (Position::relative(1000, 1000), (10, -3)),
+        (Position::relative(333, 333), (-3, -8)),
+        (Position::anchored(AnchorPoint::Center, 500, 500), (-1, -7)),
+        (
+            Position::anchored(AnchorPoint::BottomRight, 1000, 1000),
+            (7, -5),
+        ),
+    ];
+
+    for (position, (x, y)) in cases {
+        let mut views = [view];
+        let positions = [position];
+        let _ = AbsoluteLayout::new(Views::new(&mut views), &positions).arrange_within(bounds);
+
+        assert_eq!(Point::new(x, y), views[0].top_left, "{:?}", position);
+    }
+}
+
+#[test]
+fn expanded_hit_area() {
+    let cases = [
+        (rect(0, 0, 4, 4), rect(-2, -2, 8, 8)),
+        (rect(0, 0, 3, 3), rect(-2, -2, 8, 8)),
+        (rect(-5, -5, 3, 4), rect(-7, -7, 8, 8)),
+        (rect(0, 0, 10, 1), rect(0, -3, 10, 8)),
+    ];
+
+    for (view, hit_bounds) in cases {
+        assert_eq!(
+            hit_bounds,
+            ExpandHitArea::new(view, Size::new(8, 8)).hit_bounds(),
+            "{:?}",
+            view
+        );
+    }
+}
+
+#[test]
+fn fractional_scroll() {
+    let mut views = [rect(0, 0, 5, 5), rect(0, 0, 5, 5)];
+    let mut layout = LinearLayout::horizontal(Views::new(&mut views)).arrange();
+
+    let mut positions = Vec::new();
+    for delta in [0.4, 0.4, 0.4, -0.7, -0.7, -0.7] {
+        layout.scroll_fractional(delta);
+        positions.push(layout.bounds().top_left.x);
+    }
+
+    assert_eq!(vec![0, 0, 1, 1, 0, 0], positions);
+}
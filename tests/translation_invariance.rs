@@ -0,0 +1,233 @@
+//! Alignment and layout operations give the same result, translated, anywhere on the plane,
+//! including far into negative coordinates where off-screen content is staged.
+//!
+//! Layouts are placed at their own position, so they are translated as a whole before arranging.
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Rectangle, Triangle},
+};
+use embedded_layout::{
+    align::{Alignment, HorizontalAlignment, VerticalAlignment},
+    layout::linear::{
+        spacing::{ClosureSpacing, ElementSpacing},
+        Justify,
+    },
+    prelude::*,
+    utils::rotation::Rotation,
+    views::{DrawTranslated, ExpandHitArea, Marquee, Tiled, ZOrdered},
+};
+
+const OFFSETS: [Point; 4] = [
+    Point::new(-1, -1),
+    Point::new(-7, 3),
+    Point::new(-10_001, -7_777),
+    Point::new(-65_537, 12_345),
+];
+
+/// Odd and even sizes, so that centering has to round.
+fn objects() -> [Rectangle; 3] {
+    [
+        Rectangle::new(Point::new(2, 3), Size::new(5, 4)),
+        Rectangle::new(Point::new(0, 0), Size::new(8, 1)),
+        Rectangle::new(Point::new(-3, 4), Size::new(1, 9)),
+    ]
+}
+
+fn reference() -> Rectangle {
+    Rectangle::new(Point::new(1, -2), Size::new(21, 16))
+}
+
+fn check_alignment(horizontal: impl HorizontalAlignment, vertical: impl VerticalAlignment) {
+    for object in objects() {
+        let expected = object.align_to(&reference(), horizontal, vertical);
+
+        for offset in OFFSETS {
+            let aligned = object.translate(offset).align_to(
+                &reference().translate(offset),
+                horizontal,
+                vertical,
+            );
+            assert_eq!(expected.translate(offset), aligned);
+        }
+    }
+}
+
+fn check_horizontal(horizontal: impl HorizontalAlignment) {
+    check_alignment(horizontal, vertical::NoAlignment);
+    check_alignment(horizontal, vertical::Top);
+    check_alignment(horizontal, vertical::Center);
+    check_alignment(horizontal, vertical::Bottom);
+    check_alignment(horizontal, vertical::TopToBottom);
+    check_alignment(horizontal, vertical::BottomToTop);
+}
+
+#[test]
+fn alignments() {
+    check_horizontal(horizontal::NoAlignment);
+    check_horizontal(horizontal::Left);
+    check_horizontal(horizontal::Center);
+    check_horizontal(horizontal::Right);
+    check_horizontal(horizontal::LeftToRight);
+    check_horizontal(horizontal::RightToLeft);
+}
+
+#[test]
+fn alignment_offsets() {
+    fn check(alignment: impl Alignment) {
+        for object in objects() {
+            let expected = alignment.align_with_offset(object, reference(), -3);
+            for offset in OFFSETS {
+                let moved = alignment.align_with_offset(
+                    object.translate(offset),
+                    reference().translate(offset),
+                    -3,
+                );
+                assert_eq!(expected, moved);
+            }
+        }
+    }
+
+    check(horizontal::Center);
+    check(horizontal::LeftToRight);
+    check(vertical::Center);
+    check(vertical::BottomToTop);
+}
+
+#[test]
+fn shape_centers_and_docking() {
+    let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 3));
+    let circle = Circle::new(Point::new(4, 4), 11);
+    let screen = Rectangle::new(Point::zero(), Size::new(64, 33));
+
+    let centered = triangle.align_centers_to(&circle);
+    let docked = [None, Some(Rotation::Quarter), Some(Rotation::Half)].map(|rotation| {
+        circle.dock_to_edge(
+            &screen,
+            ScreenEdge::Top,
+            AlignmentPosition::Center,
+            rotation,
+        )
+    });
+
+    for offset in OFFSETS {
+        let moved = triangle
+            .translate(offset)
+            .align_centers_to(&circle.translate(offset));
+        assert_eq!(centered.translate(offset), moved);
+
+        let rotations = [None, Some(Rotation::Quarter), Some(Rotation::Half)];
+        for (expected, rotation) in docked.iter().zip(rotations) {
+            let moved = circle.translate(offset).dock_to_edge(
+                &screen.translate(offset),
+                ScreenEdge::Top,
+                AlignmentPosition::Center,
+                rotation,
+            );
+            assert_eq!(expected.translate(offset), moved);
+        }
+    }
+}
+
+fn check_linear_layout(spacing: impl ElementSpacing) {
+    macro_rules! check {
+        ($layout:ident, $alignment:expr) => {{
+            let mut expected = objects();
+            let _ = LinearLayout::$layout(Views::new(&mut expected))
+                .with_alignment($alignment)
+                .with_spacing(spacing)
+                .arrange();
+
+            for offset in OFFSETS {
+                let mut views = objects();
+                let _ = LinearLayout::$layout(Views::new(&mut views))
+                    .with_alignment($alignment)
+                    .with_spacing(spacing)
+                    .translate(offset)
+                    .arrange();
+
+                assert_eq!(expected.map(|view| view.translate(offset)), views);
+            }
+        }};
+    }
+
+    check!(horizontal, vertical::Top);
+    check!(horizontal, vertical::Center);
+    check!(horizontal, vertical::Bottom);
+    check!(horizontal, vertical::TopToBottom);
+    check!(vertical, horizontal::Left);
+    check!(vertical, horizontal::Center);
+    check!(vertical, horizontal::Right);
+    check!(vertical, horizontal::RightToLeft);
+}
+
+#[test]
+fn linear_layouts() {
+    check_linear_layout(Tight);
+    check_linear_layout(FixedMargin(3));
+    check_linear_layout(FixedMargin(-4));
+    check_linear_layout(DistributeFill(31));
+    check_linear_layout(DistributeFill(7));
+    check_linear_layout(ClosureSpacing(|n| 2 - n as i32));
+}
+
+#[test]
+fn justified_layouts() {
+    for mode in [
+        Justify::Start,
+        Justify::Center,
+        Justify::End,
+        Justify::SpaceBetween,
+        Justify::SpaceAround,
+    ] {
+        let mut expected = objects();
+        let expected_bounds = LinearLayout::horizontal(Views::new(&mut expected))
+            .arrange()
+            .justify(37, mode)
+            .bounds();
+
+        for offset in OFFSETS {
+            let mut views = objects();
+            let bounds = LinearLayout::horizontal(Views::new(&mut views))
+                .translate(offset)
+                .arrange()
+                .justify(37, mode)
+                .bounds();
+
+            assert_eq!(expected.map(|view| view.translate(offset)), views);
+            assert_eq!(expected_bounds.translate(offset), bounds);
+        }
+    }
+}
+
+#[test]
+fn decorators() {
+    let view = objects()[0];
+    let clamp = reference();
+
+    let expanded = ExpandHitArea::new(view, Size::new(12, 9)).clamped_to(clamp);
+    let marquee = Marquee::new(view, 3);
+    let tiled = Tiled::new(view, Size::new(1, 1), clamp);
+    let z_ordered = ZOrdered::new(view, 2);
+    let translated = DrawTranslated::new(&view, Point::new(-5, 5));
+
+    for offset in OFFSETS {
+        let moved = ExpandHitArea::new(view.translate(offset), Size::new(12, 9))
+            .clamped_to(clamp.translate(offset));
+        assert_eq!(expanded.bounds().translate(offset), moved.bounds());
+        assert_eq!(expanded.hit_bounds().translate(offset), moved.hit_bounds());
+
+        let moved = Marquee::new(view.translate(offset), 3);
+        assert_eq!(marquee.bounds().translate(offset), moved.bounds());
+
+        let moved = Tiled::new(view, Size::new(1, 1), clamp).translate(offset);
+        assert_eq!(tiled.bounds().translate(offset), moved.bounds());
+
+        let moved = ZOrdered::new(view.translate(offset), 2);
+        assert_eq!(z_ordered.bounds().translate(offset), moved.bounds());
+
+        let shifted = view.translate(offset);
+        let moved = DrawTranslated::new(&shifted, Point::new(-5, 5));
+        assert_eq!(translated.bounds().translate(offset), moved.bounds());
+    }
+}